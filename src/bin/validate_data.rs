@@ -0,0 +1,49 @@
+// Checks every file in assets/data and in each mods/ folder, and prints file:line issues.
+//   cargo run --bin validate-data [game dir]
+// Exits with 1 if there are any errors (warnings alone still pass).
+#[path = "../data_validation.rs"]
+#[allow(dead_code)] // Loader-side helpers
+mod data_validation;
+
+// The game types the validator checks names against (none of them need raylib)
+#[path = "../structures/card_data.rs"]
+#[allow(dead_code)]
+mod card_data;
+#[path = "../structures/effect.rs"]
+#[allow(dead_code)]
+mod effect;
+#[path = "../structures/endless.rs"]
+#[allow(dead_code)]
+mod endless;
+#[path = "../structures/enemy.rs"]
+#[allow(dead_code)]
+mod enemy;
+#[path = "../structures/event.rs"]
+#[allow(dead_code)]
+mod event;
+#[path = "../structures/hand.rs"]
+#[allow(dead_code)]
+mod hand;
+#[path = "../structures/score.rs"]
+#[allow(dead_code)]
+mod score;
+
+mod structures {
+    pub(crate) use super::{card_data, effect, endless, enemy, event, hand, score};
+}
+
+use std::path::PathBuf;
+
+fn main() {
+    let root = std::env::args().nth(1).map(PathBuf::from).unwrap_or_else(|| PathBuf::from("."));
+    let report = data_validation::validate_with_mods(&root, &data_validation::mod_folders(&root));
+
+    for issue in &report.issues {
+        eprintln!("{}", issue);
+    }
+    println!("{} error(s), {} warning(s)", report.error_count(), report.warning_count());
+
+    if report.error_count() > 0 {
+        std::process::exit(1);
+    }
+}
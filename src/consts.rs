@@ -58,6 +58,8 @@ pub const SORT_BTN_HEIGHT: f32 = 40.0;
 
 pub const DECK_X: f32 = A_PANEL_X + 20.0;
 pub const DECK_Y: f32 = A_PANEL_Y - 150.0;
+pub const DISCARD_X: f32 = A_PANEL_X + 200.0;
+pub const DISCARD_Y: f32 = DECK_Y;
 
 pub const RUNE_CONTENT_OFFSET: f32 = -250.0;
 pub const RUNE_START_Y: f32 = 200.0;
@@ -77,6 +79,9 @@ pub const STATS_BTN_RECT: raylib::math::Rectangle = raylib::math::Rectangle {
     height: 50.0
 };
 
+// --- RUN ---
+pub const FINAL_ANTE: i32 = 8; // Beating this ante's boss wins the run
+
 // Unused but kept to prevent errors if referenced elsewhere
 pub const STAT_WIN_W: f32 = 700.0;
 pub const STAT_WIN_H: f32 = 450.0;
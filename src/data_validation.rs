@@ -0,0 +1,565 @@
+// Schema checks for everything in assets/data (and mod folders under mods/), run by the
+// `validate-data` binary, by the game's loader (--strict-data turns errors into a hard
+// stop) and by tests/data_tests.rs.
+//
+// Works on raw serde_json::Value to keep the line numbers: every issue points at file:line
+// of the entry (or the parse error) it's about. Names the game itself parses (effects,
+// events, suits, cards, enhancements, boss abilities) are checked with the game's own
+// types and parsers; the tables below cover what only lives in match arms elsewhere.
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::structures::card_data::{parse_card_name, Enhancement, SUIT_NAMES};
+use crate::structures::effect::Effect;
+use crate::structures::enemy::BossAbility;
+use crate::structures::event::EventData;
+
+pub const DATA_DIR: &str = "assets/data";
+pub const ICON_DIR: &str = "assets";
+pub const MODS_DIR: &str = "mods";
+pub const MOD_MANIFEST: &str = "mod.json";
+
+pub const RARITIES: [&str; 5] = ["Common", "Uncommon", "Rare", "Legendary", "Mythic"]; // logic::shop::roll_rarity
+pub const RUNE_TYPES: [&str; 4] = ["Red", "Blue", "Green", "Minor"];
+pub const UNLOCK_TYPES: [&str; 3] = ["ReachAnte", "WinFights", "DefeatBosses"];
+pub const DECK_KEYS: [&str; 5] = ["suits", "no_face_cards", "copies", "extra_cards", "enhance"]; // structures::deck::DeckSpec
+pub const CHALLENGE_RULES: [&str; 2] = ["NoShop", "AllBosses"]; // structures::challenge::ChallengeRule
+
+// Icon paths relative to assets/ (or to a mod folder). The folder under rune_icons
+// depends on the rune type (matches GameAssets::load).
+pub fn rune_icon_file(rune_type: &str, name: &str) -> String {
+    let folder = match rune_type {
+        "Red" => "combat_style",
+        "Blue" => "utility",
+        "Green" => "economy",
+        _ => "starting_bonus",
+    };
+    format!("rune_icons/{}/{}_icon.png", folder, name.to_lowercase())
+}
+
+pub fn relic_icon_file(name: &str) -> String {
+    format!("relic_icons/{}_icon.png", name.to_lowercase().replace(' ', "_"))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning, // Things the game copes with (e.g. a missing icon falls back to a plain circle)
+}
+
+#[derive(Debug, Clone)]
+pub struct Issue {
+    pub severity: Severity,
+    pub file: String,
+    pub line: usize,
+    pub entry: Option<String>, // Id of the entry the issue is in, when there is one
+    pub message: String,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let level = match self.severity { Severity::Error => "error", Severity::Warning => "warning" };
+        write!(f, "{}:{}: {}: {}", self.file, self.line, level, self.message)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Report {
+    pub issues: Vec<Issue>,
+}
+
+impl Report {
+    pub fn error_count(&self) -> usize {
+        self.issues.iter().filter(|i| i.severity == Severity::Error).count()
+    }
+
+    pub fn warning_count(&self) -> usize {
+        self.issues.iter().filter(|i| i.severity == Severity::Warning).count()
+    }
+
+    // Whether the loader should skip this entry. `file` is matched as a path suffix, so
+    // "books.json" means any books.json and "mods/x/books.json" only that mod's.
+    pub fn entry_has_errors(&self, file: &str, id: &str) -> bool {
+        self.issues.iter().any(|i| {
+            i.severity == Severity::Error && i.file.ends_with(file) && i.entry.as_deref() == Some(id)
+        })
+    }
+}
+
+// One data file: its text (for line lookups) and where it lives
+struct Source {
+    file: String,
+    text: String,
+    cursor: usize, // Line search starts here so repeated names resolve to the next entry
+}
+
+impl Source {
+    // First line at or after the cursor that contains `needle` (1-based)
+    fn find_line(&mut self, needle: &str) -> usize {
+        let found = self.text.lines().enumerate().skip(self.cursor.saturating_sub(1))
+            .find(|(_, line)| line.contains(needle))
+            .map(|(i, _)| i + 1);
+        match found {
+            Some(line) => {
+                self.cursor = line + 1;
+                line
+            }
+            None => self.cursor.max(1),
+        }
+    }
+}
+
+struct Validator {
+    root: PathBuf,
+    report: Report,
+}
+
+// Where an issue goes: the file, the entry's line and its id
+#[derive(Clone)]
+struct At {
+    file: String,
+    line: usize,
+    entry: Option<String>,
+}
+
+impl Validator {
+    fn push(&mut self, severity: Severity, at: &At, message: String) {
+        self.report.issues.push(Issue { severity, file: at.file.clone(), line: at.line, entry: at.entry.clone(), message });
+    }
+
+    fn error(&mut self, at: &At, message: String) {
+        self.push(Severity::Error, at, message);
+    }
+
+    // Reads dir/name. Mods only ship the files they need, so those pass required = false.
+    fn read(&mut self, dir: &str, name: &str, required: bool) -> Option<(Source, Value)> {
+        let file = format!("{}/{}", dir, name);
+        let path = self.root.join(&file);
+        let at = At { file: file.clone(), line: 1, entry: None };
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(_) if !required && !path.exists() => return None,
+            Err(e) => {
+                self.error(&at, format!("could not read file: {}", e));
+                return None;
+            }
+        };
+        match serde_json::from_str::<Value>(&text) {
+            Ok(value) => Some((Source { file, text, cursor: 1 }, value)),
+            Err(e) => {
+                self.error(&At { line: e.line(), ..at }, format!("invalid JSON: {}", e));
+                None
+            }
+        }
+    }
+
+    // Top-level array of objects. Yields each object with its location.
+    fn entries<'a>(&mut self, src: &mut Source, value: &'a Value) -> Vec<(At, &'a Map<String, Value>)> {
+        let Some(list) = value.as_array() else {
+            self.error(&At { file: src.file.clone(), line: 1, entry: None }, "expected a list of entries".to_string());
+            return Vec::new();
+        };
+        let mut out = Vec::new();
+        for (i, item) in list.iter().enumerate() {
+            let id = item.get("id").and_then(Value::as_str).map(str::to_string);
+            let line = match &id {
+                Some(id) => src.find_line(&format!("\"{}\"", id)),
+                None => src.find_line("{"),
+            };
+            let at = At { file: src.file.clone(), line, entry: id.clone() };
+            match item.as_object() {
+                Some(obj) => {
+                    if id.is_none() {
+                        self.error(&at, format!("entry {} has no string \"id\"", i));
+                    }
+                    out.push((at, obj));
+                }
+                None => self.error(&at, format!("entry {} is not an object", i)),
+            }
+        }
+        out
+    }
+
+    // Errors for missing required keys and for keys the game would silently ignore
+    fn keys(&mut self, at: &At, obj: &Map<String, Value>, required: &[&str], optional: &[&str]) {
+        for key in required {
+            if !obj.contains_key(*key) {
+                self.error(at, format!("missing field \"{}\"", key));
+            }
+        }
+        for key in obj.keys() {
+            if !required.contains(&key.as_str()) && !optional.contains(&key.as_str()) {
+                self.error(at, format!("unknown field \"{}\"", key));
+            }
+        }
+    }
+
+    fn string<'a>(&mut self, at: &At, obj: &'a Map<String, Value>, key: &str) -> Option<&'a str> {
+        let value = obj.get(key)?;
+        let s = value.as_str();
+        if s.is_none() {
+            self.error(at, format!("\"{}\" should be a string", key));
+        }
+        s
+    }
+
+    fn int(&mut self, at: &At, obj: &Map<String, Value>, key: &str) -> Option<i64> {
+        let value = obj.get(key)?;
+        let n = value.as_i64();
+        if n.is_none() {
+            self.error(at, format!("\"{}\" should be a whole number", key));
+        }
+        n
+    }
+
+    fn number(&mut self, at: &At, obj: &Map<String, Value>, key: &str) -> Option<f64> {
+        let value = obj.get(key)?;
+        let n = value.as_f64();
+        if n.is_none() {
+            self.error(at, format!("\"{}\" should be a number", key));
+        }
+        n
+    }
+
+    // The value if it's one of `allowed`; anything else is reported and gives None
+    fn one_of(&mut self, at: &At, obj: &Map<String, Value>, key: &str, allowed: &[&str], what: &str) -> Option<String> {
+        let s = self.string(at, obj, key)?.to_string();
+        if !allowed.contains(&s.as_str()) {
+            self.error(at, format!("unknown {} \"{}\" (expected one of: {})", what, s, allowed.join(", ")));
+            return None;
+        }
+        Some(s)
+    }
+
+    fn unique(&mut self, at: &At, seen: &mut HashSet<String>, id: Option<&String>) {
+        if let Some(id) = id && !seen.insert(id.clone()) {
+            self.error(at, format!("duplicate id \"{}\"", id));
+        }
+    }
+
+    // A mod's icon can live in the mod folder or reuse one from the base game
+    fn icon(&mut self, at: &At, icon_dir: &str, file: String) {
+        let in_dir = Path::new(icon_dir).join(&file);
+        if !self.root.join(&in_dir).exists() && !self.root.join(ICON_DIR).join(&file).exists() {
+            self.push(Severity::Warning, at, format!("missing icon {}", in_dir.display()));
+        }
+    }
+
+    fn effect_list(&mut self, at: &At, obj: &Map<String, Value>, key: &str, relic_ids: &HashSet<String>) {
+        let Some(value) = obj.get(key) else { return; };
+        match value.as_array() {
+            Some(list) => for effect in list {
+                self.effect(at, effect, relic_ids);
+            },
+            None => self.error(at, format!("\"{}\" should be a list of effects", key)),
+        }
+    }
+
+    // Parsed as the game's Effect, so serde reports unknown types / fields and
+    // Effect::validate the names inside
+    fn effect(&mut self, at: &At, value: &Value, relic_ids: &HashSet<String>) {
+        match serde_json::from_value::<Effect>(value.clone()) {
+            Ok(effect) => {
+                if let Err(e) = effect.validate() {
+                    self.error(at, e);
+                }
+                self.relic_refs(at, effect.named_relics(), relic_ids);
+            }
+            Err(e) => self.error(at, format!("bad effect: {}", e)),
+        }
+    }
+
+    fn relic_refs(&mut self, at: &At, named: Vec<&str>, relic_ids: &HashSet<String>) {
+        for relic in named.into_iter().filter(|r| !relic_ids.contains(*r)) {
+            self.error(at, format!("GainRelic refers to unknown relic \"{}\"", relic));
+        }
+    }
+}
+
+// Deck spec fields, on a decks.json entry or a challenge's "deck". Even one suit
+// without face cards leaves 10 cards, so there's no size check.
+fn deck_fields(v: &mut Validator, at: &At, deck: &Map<String, Value>) {
+    let suits: Vec<&str> = deck.get("suits").and_then(Value::as_array).map(|l| l.iter().filter_map(Value::as_str).collect()).unwrap_or_default();
+    for suit in suits.iter().filter(|s| !SUIT_NAMES.contains(*s)) {
+        v.error(at, format!("unknown suit \"{}\" in deck", suit));
+    }
+    if deck.get("no_face_cards").is_some_and(|b| !b.is_boolean()) {
+        v.error(at, "\"no_face_cards\" should be true or false".to_string());
+    }
+    v.int(at, deck, "copies");
+    if let Some(cards) = deck.get("extra_cards") {
+        for card in cards.as_array().map(Vec::as_slice).unwrap_or(&[]) {
+            if card.as_str().and_then(parse_card_name).is_none() {
+                v.error(at, format!("extra card {} should be a card name like \"Ace of Spades\"", card));
+            }
+        }
+    }
+    for rule in deck.get("enhance").and_then(Value::as_array).map(Vec::as_slice).unwrap_or(&[]) {
+        let Some(rule) = rule.as_object() else {
+            v.error(at, "enhance rule should be an object".to_string());
+            continue;
+        };
+        v.keys(at, rule, &["enhancement"], &["suit", "value"]);
+        if let Some(name) = rule.get("enhancement") && let Err(e) = serde_json::from_value::<Enhancement>(name.clone()) {
+            v.error(at, format!("bad enhancement {}: {}", name, e));
+        }
+        if rule.contains_key("suit") {
+            v.one_of(at, rule, "suit", &SUIT_NAMES, "suit");
+        }
+        if v.int(at, rule, "value").is_some_and(|n| !(2..=14).contains(&n)) {
+            v.error(at, "enhance \"value\" should be 2-14 (Ace = 14)".to_string());
+        }
+    }
+}
+
+// Validates every data file under `root`/assets/data. `root` is the game's working directory.
+#[allow(dead_code)] // Base data only; the game and the binary always include mods
+pub fn validate(root: &Path) -> Report {
+    validate_with_mods(root, &[])
+}
+
+// Base data plus the given mod folders (e.g. "mods/more_relics"), in load order. Relic
+// and rune ids carry over, so a mod's GainRelic can name a base relic or one from an
+// earlier mod.
+pub fn validate_with_mods(root: &Path, mod_dirs: &[String]) -> Report {
+    let mut v = Validator { root: root.to_path_buf(), report: Report::default() };
+    let mut relic_ids = HashSet::new();
+    let mut rune_ids = HashSet::new();
+    validate_dir(&mut v, DATA_DIR, ICON_DIR, true, &mut relic_ids, &mut rune_ids);
+
+    let mut mod_ids = HashSet::new();
+    for dir in mod_dirs {
+        validate_manifest(&mut v, dir, &mut mod_ids);
+        validate_dir(&mut v, dir, dir, false, &mut relic_ids, &mut rune_ids);
+    }
+    v.report
+}
+
+// Sub-folders of `root`/mods, sorted by name. The game's own load order (manifest
+// load_order, enabled flags) lives in structures::mods; this is every folder present.
+pub fn mod_folders(root: &Path) -> Vec<String> {
+    let Ok(dir) = fs::read_dir(root.join(MODS_DIR)) else { return Vec::new(); };
+    let mut folders: Vec<String> = dir.flatten()
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| format!("{}/{}", MODS_DIR, entry.file_name().to_string_lossy()))
+        .collect();
+    folders.sort();
+    folders
+}
+
+fn validate_manifest(v: &mut Validator, dir: &str, mod_ids: &mut HashSet<String>) {
+    let Some((_, value)) = v.read(dir, MOD_MANIFEST, true) else { return; };
+    let at = At { file: format!("{}/{}", dir, MOD_MANIFEST), line: 1, entry: None };
+    let Some(obj) = value.as_object() else {
+        v.error(&at, "manifest should be an object".to_string());
+        return;
+    };
+    v.keys(&at, obj, &["id", "name"], &["version", "author", "description", "load_order", "disable"]);
+    let id = v.string(&at, obj, "id").map(str::to_string);
+    if let Some(id) = &id && !mod_ids.insert(id.clone()) {
+        v.error(&at, format!("another mod already uses the id \"{}\"", id));
+    }
+    v.string(&at, obj, "name");
+    v.string(&at, obj, "version");
+    v.int(&at, obj, "load_order");
+    if let Some(disable) = obj.get("disable") && !disable.as_array().is_some_and(|list| list.iter().all(Value::is_string)) {
+        v.error(&at, "\"disable\" should be a list of entry ids".to_string());
+    }
+}
+
+// One folder of data files. Duplicate ids are only checked within a folder: a mod
+// reusing a base id is an override, not a mistake.
+fn validate_dir(v: &mut Validator, dir: &str, icon_dir: &str, required: bool, relic_ids: &mut HashSet<String>, rune_ids: &mut HashSet<String>) {
+    // Relics first: other files may refer to them
+    let mut seen_relics = HashSet::new();
+    if let Some((mut src, value)) = v.read(dir, "relics.json", required) {
+        for (at, obj) in v.entries(&mut src, &value) {
+            v.keys(&at, obj, &["id", "name", "description"], &["value", "rarity", "script"]);
+            v.unique(&at, &mut seen_relics, at.entry.as_ref());
+            relic_ids.extend(at.entry.clone());
+            v.int(&at, obj, "value");
+            if obj.contains_key("rarity") {
+                v.one_of(&at, obj, "rarity", &RARITIES, "rarity");
+            }
+            if let Some(name) = v.string(&at, obj, "name") {
+                v.icon(&at, icon_dir, relic_icon_file(name));
+            }
+            // Compiling needs the script engine, so the game's loader checks the script itself
+            if let Some(script) = v.string(&at, obj, "script") && !v.root.join(dir).join(script).is_file() {
+                v.error(&at, format!("script {}/{} not found", dir, script));
+            }
+        }
+    }
+
+    if let Some((mut src, value)) = v.read(dir, "runes.json", required) {
+        let mut seen = HashSet::new();
+        for (at, obj) in v.entries(&mut src, &value) {
+            v.keys(&at, obj, &["id", "name", "description", "rune_type", "cost"], &[]);
+            v.unique(&at, &mut seen, at.entry.as_ref());
+            rune_ids.extend(at.entry.clone());
+            v.int(&at, obj, "cost");
+            let rune_type = v.one_of(&at, obj, "rune_type", &RUNE_TYPES, "rune type");
+            if let (Some(rune_type), Some(name)) = (rune_type, v.string(&at, obj, "name")) {
+                v.icon(&at, icon_dir, rune_icon_file(&rune_type, name));
+            }
+        }
+    }
+
+    // Scrolls and books share one consumables table, so ids must be unique across both
+    let mut consumable_ids = HashSet::new();
+    for file in ["scrolls.json", "books.json"] {
+        let Some((mut src, value)) = v.read(dir, file, required) else { continue; };
+        for (at, obj) in v.entries(&mut src, &value) {
+            v.keys(&at, obj, &["id", "name", "rarity", "cost", "effect", "description"], &[]);
+            v.unique(&at, &mut consumable_ids, at.entry.as_ref());
+            v.one_of(&at, obj, "rarity", &RARITIES, "rarity");
+            v.int(&at, obj, "cost");
+            if let Some(effect) = obj.get("effect") {
+                v.effect(&at, effect, relic_ids);
+            }
+        }
+    }
+
+    if let Some((mut src, value)) = v.read(dir, "heirlooms.json", required) {
+        let mut seen = HashSet::new();
+        for (at, obj) in v.entries(&mut src, &value) {
+            v.keys(&at, obj, &["id", "name", "unlock", "effect"], &["description"]);
+            v.unique(&at, &mut seen, at.entry.as_ref());
+            if let Some(unlock) = obj.get("unlock").and_then(Value::as_object) {
+                if !unlock.contains_key("type") {
+                    v.error(&at, "unlock has no \"type\"".to_string());
+                }
+                match v.one_of(&at, unlock, "type", &UNLOCK_TYPES, "unlock type").as_deref() {
+                    Some("ReachAnte") => v.keys(&at, unlock, &["type", "ante"], &[]),
+                    Some(_) => v.keys(&at, unlock, &["type", "count"], &[]),
+                    None => {}
+                }
+            }
+            if let Some(effect) = obj.get("effect") {
+                v.effect(&at, effect, relic_ids);
+            }
+        }
+    }
+
+    if let Some((mut src, value)) = v.read(dir, "events.json", required) {
+        let mut seen = HashSet::new();
+        for (at, obj) in v.entries(&mut src, &value) {
+            v.unique(&at, &mut seen, at.entry.as_ref());
+            match serde_json::from_value::<EventData>(Value::Object(obj.clone())) {
+                Ok(event) => {
+                    if let Err(e) = event.validate() {
+                        v.error(&at, e);
+                    }
+                    v.relic_refs(&at, event.effects().flat_map(Effect::named_relics).collect(), relic_ids);
+                }
+                Err(e) => v.error(&at, format!("bad event: {}", e)),
+            }
+        }
+    }
+
+    if let Some((mut src, value)) = v.read(dir, "decks.json", required) {
+        let mut seen = HashSet::new();
+        let optional: Vec<&str> = ["description"].into_iter().chain(DECK_KEYS).collect();
+        for (at, obj) in v.entries(&mut src, &value) {
+            v.keys(&at, obj, &["id", "name"], &optional);
+            v.unique(&at, &mut seen, at.entry.as_ref());
+            v.string(&at, obj, "name");
+            deck_fields(v, &at, obj);
+        }
+    }
+
+    if let Some((mut src, value)) = v.read(dir, "challenges.json", required) {
+        let mut seen = HashSet::new();
+        for (at, obj) in v.entries(&mut src, &value) {
+            v.keys(&at, obj, &["id", "name"], &["description", "runes", "deck", "banned_relics", "effects", "rules"]);
+            v.unique(&at, &mut seen, at.entry.as_ref());
+            v.string(&at, obj, "name");
+            for (key, known, what) in [("runes", &*rune_ids, "rune"), ("banned_relics", &*relic_ids, "relic")] {
+                let Some(list) = obj.get(key) else { continue; };
+                let Some(list) = list.as_array().filter(|l| l.iter().all(Value::is_string)) else {
+                    v.error(&at, format!("\"{}\" should be a list of {} ids", key, what));
+                    continue;
+                };
+                for id in list.iter().filter_map(Value::as_str).filter(|id| !known.contains(*id)) {
+                    v.error(&at, format!("unknown {} id \"{}\" in {}", what, id, key));
+                }
+            }
+            if let Some(deck) = obj.get("deck") {
+                match deck.as_object() {
+                    Some(deck) => {
+                        v.keys(&at, deck, &[], &DECK_KEYS);
+                        deck_fields(v, &at, deck);
+                    }
+                    None => v.error(&at, "\"deck\" should be an object".to_string()),
+                }
+            }
+            if let Some(rules) = obj.get("rules") {
+                for rule in rules.as_array().map(Vec::as_slice).unwrap_or(&[]) {
+                    match rule.as_str() {
+                        Some(r) if CHALLENGE_RULES.contains(&r) => {}
+                        _ => v.error(&at, format!("unknown rule {} (expected one of: {})", rule, CHALLENGE_RULES.join(", "))),
+                    }
+                }
+                if !rules.is_array() {
+                    v.error(&at, "\"rules\" should be a list".to_string());
+                }
+            }
+            v.effect_list(&at, obj, "effects", relic_ids);
+        }
+    }
+
+    // Enemies are an object of tiers; ids come from the names, so names must be unique
+    if let Some((mut src, value)) = v.read(dir, "enemies.json", required) {
+        let at = At { file: src.file.clone(), line: 1, entry: None };
+        match value.as_object() {
+            Some(obj) => {
+                v.keys(&at, obj, &["tier_1", "tier_2", "tier_3", "bosses"], &["endless"]);
+                let mut seen = HashSet::new();
+                for tier in ["tier_1", "tier_2", "tier_3"] {
+                    let names = obj.get(tier).and_then(Value::as_array).map(Vec::as_slice).unwrap_or(&[]);
+                    for name in names {
+                        let Some(name) = name.as_str() else {
+                            v.error(&at, format!("{} should only hold enemy names", tier));
+                            continue;
+                        };
+                        let at = At { line: src.find_line(&format!("\"{}\"", name)), entry: Some(name.to_string()), ..at.clone() };
+                        v.unique(&at, &mut seen, Some(&name.to_string()));
+                    }
+                }
+                let bosses = obj.get("bosses").and_then(Value::as_array).map(Vec::as_slice).unwrap_or(&[]);
+                for boss in bosses {
+                    let Some(boss) = boss.as_object() else {
+                        v.error(&at, "boss should be an object".to_string());
+                        continue;
+                    };
+                    let name = boss.get("name").and_then(Value::as_str).unwrap_or("?").to_string();
+                    let at = At { line: src.find_line(&format!("\"{}\"", name)), entry: Some(name.clone()), ..at.clone() };
+                    v.keys(&at, boss, &["name", "ability"], &["param"]);
+                    v.unique(&at, &mut seen, Some(&name));
+                    let param = v.int(&at, boss, "param").unwrap_or(0) as i32;
+                    if let Some(ability) = v.string(&at, boss, "ability") && BossAbility::from_data(ability, param) == BossAbility::None {
+                        v.error(&at, format!("unknown boss ability \"{}\"", ability));
+                    }
+                }
+                if let Some(endless) = obj.get("endless") {
+                    let at = At { line: src.find_line("\"endless\""), entry: Some("endless".to_string()), ..at.clone() };
+                    match endless.as_object() {
+                        Some(curve) => {
+                            v.keys(&at, curve, &[], &["hp_growth", "hp_exponent", "stacked_ability_after"]);
+                            for key in ["hp_growth", "hp_exponent"] {
+                                if v.number(&at, curve, key).is_some_and(|n| n < 1.0) {
+                                    v.error(&at, format!("\"{}\" below 1 would make endless enemies weaker", key));
+                                }
+                            }
+                            v.int(&at, curve, "stacked_ability_after");
+                        }
+                        None => v.error(&at, "\"endless\" should be an object".to_string()),
+                    }
+                }
+            }
+            None => v.error(&at, "expected an object with tier_1..tier_3 and bosses".to_string()),
+        }
+    }
+}
@@ -1,13 +1,52 @@
 use raylib::prelude::*;
 use crate::structures::stats::BaseModifiers;
 use crate::structures::assets::GameAssets;
+use crate::logic::battle_result::{fight_rows, payout_rows, tally_progress, ResultRow};
 use crate::consts::*;
+use crate::layout;
+use crate::widgets;
+use super::deck_view::draw_deck_view;
 
 pub fn draw_battle_result(d: &mut RaylibDrawHandle, stats: &BaseModifiers, assets: &GameAssets) {
+    let font = &assets.font_main;
     let y_offset = stats.window_y_offset;
-    let rect = Rectangle::new(SCREEN_WIDTH / 2.0 - 200.0, SCREEN_HEIGHT / 2.0 - 100.0 + y_offset, 400.0, 200.0);
-    d.draw_rectangle_rounded(rect, 0.1, 4, NEU_BLACK.alpha(0.9));
-    d.draw_rectangle_rounded_lines_ex(rect, 0.1, 4, 3.0, NEU_ORANGE);
-    d.draw_text_ex(&assets.font_main, "Battle Result", Vector2::new(rect.x + 50.0, rect.y + 50.0), 40.0, 1.0, PARCHMENT);
-    d.draw_text_ex(&assets.font_main, "Click to continue", Vector2::new(rect.x + 100.0, rect.y + 120.0), 20.0, 1.0, Color::WHITE);
+    let rect = layout::battle_result_panel(y_offset);
+    widgets::panel(d, rect, NEU_ORANGE);
+    d.draw_text_ex(font, "Battle Result", Vector2::new(rect.x + 50.0, rect.y + 30.0), 40.0, 1.0, PARCHMENT);
+
+    // Both columns share one tally order: fight rows first, then the payout
+    let fight = fight_rows(stats);
+    let first_payout = fight.len();
+    draw_column(d, stats, assets, "FIGHT", layout::battle_result_column(0, y_offset), &fight, 0);
+    draw_column(d, stats, assets, "PAYOUT", layout::battle_result_column(1, y_offset), &payout_rows(stats), first_payout);
+
+    widgets::button(d, font, layout::battle_result_deck_button(y_offset), "VIEW DECK", NEU_BLUE, true);
+    widgets::button(d, font, layout::battle_result_next_button(y_offset), "NEXT", NEU_ORANGE, true);
+    if stats.actions.using_keys && !stats.viewing_deck {
+        let focus = if stats.focus_col == 1 { layout::battle_result_deck_button(y_offset) } else { layout::battle_result_next_button(y_offset) };
+        widgets::focus_ring(d, focus);
+    }
+
+    if stats.viewing_deck {
+        draw_deck_view(d, stats, assets);
+    }
+}
+
+// Label left, tallied value right; rows show up as the tally reaches them
+fn draw_column(d: &mut RaylibDrawHandle, stats: &BaseModifiers, assets: &GameAssets, title: &str, column: Rectangle, rows: &[ResultRow], first_index: usize) {
+    let font = &assets.font_main;
+    d.draw_text_ex(font, title, Vector2::new(column.x, column.y), 18.0, 1.0, Color::GRAY);
+
+    let mut y = column.y + 30.0;
+    for (i, row) in rows.iter().enumerate() {
+        let Some(progress) = tally_progress(stats, first_index + i) else { break; };
+        if y > column.y + column.height {
+            break;
+        }
+        let value = row.value.show(progress);
+        d.draw_text_ex(font, &row.label, Vector2::new(column.x, y), 22.0, 1.0, PARCHMENT);
+        let w = font.measure_text(&value, 22.0, 1.0).x;
+        d.draw_text_ex(font, &value, Vector2::new(column.x + column.width - w, y), 22.0, 1.0, row.color);
+        y += layout::BATTLE_RESULT_LINE_H;
+    }
 }
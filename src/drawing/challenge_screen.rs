@@ -0,0 +1,95 @@
+use raylib::prelude::*;
+use crate::structures::stats::BaseModifiers;
+use crate::structures::assets::GameAssets;
+use crate::structures::challenge::Challenge;
+use crate::logic::challenge::{challenge_at, challenge_list, focus_back};
+use crate::layout;
+use crate::consts::*;
+use crate::widgets;
+
+pub fn draw_challenge_select(d: &mut RaylibDrawHandle, stats: &BaseModifiers, assets: &GameAssets) {
+    let font = &assets.font_main;
+    let list = layout::mods_list_panel();
+    widgets::panel(d, list, NEU_ORANGE);
+    d.draw_text_ex(font, "CHALLENGES", Vector2::new(list.x + 40.0, list.y + 25.0), 48.0, 1.0, NEU_ORANGE);
+    let done = challenge_list(stats).iter().filter(|c| stats.profile.completed(&c.id)).count();
+    let progress = format!("Completed {} / {}", done, stats.all_challenges.len());
+    d.draw_text_ex(font, &progress, Vector2::new(list.x + 40.0, list.y + 75.0), 20.0, 1.0, Color::GRAY);
+
+    let mut rows = vec![("Standard Run".to_string(), "Your runes, the full deck, no special rules".to_string(), false)];
+    rows.extend(challenge_list(stats).iter().map(|c| (c.name.clone(), c.description.clone(), stats.profile.completed(&c.id))));
+    let focused = stats.focus_row.min(rows.len() - 1);
+
+    for (row, (name, description, completed)) in rows.iter().enumerate() {
+        let rect = layout::challenge_row(row);
+        if focused == row || widgets::interact(d, rect).hovered {
+            d.draw_rectangle_rounded(rect, 0.2, 4, NEU_BLUE.alpha(0.2));
+        }
+        d.draw_text_ex(font, name, Vector2::new(rect.x + 15.0, rect.y + 4.0), 26.0, 1.0, PARCHMENT);
+        let line = widgets::wrap_text(font, description, 18.0, rect.width - 140.0).into_iter().next().unwrap_or_default();
+        d.draw_text_ex(font, &line, Vector2::new(rect.x + 15.0, rect.y + 32.0), 18.0, 1.0, Color::GRAY);
+        if *completed {
+            let w = font.measure_text("COMPLETED", 18.0, 1.0).x;
+            d.draw_text_ex(font, "COMPLETED", Vector2::new(rect.x + rect.width - w - 15.0, rect.y + 8.0), 18.0, 1.0, NEU_GREEN);
+        }
+    }
+
+    draw_challenge_details(d, stats, challenge_at(stats, focused).as_ref(), assets);
+
+    widgets::button(d, font, layout::challenge_back_button(), "BACK", NEU_ORANGE, true);
+    if stats.actions.using_keys {
+        let focus = if stats.focus_row == focus_back(stats) { layout::challenge_back_button() } else { layout::challenge_row(focused) };
+        widgets::focus_ring(d, focus);
+    }
+}
+
+fn draw_challenge_details(d: &mut RaylibDrawHandle, stats: &BaseModifiers, challenge: Option<&Challenge>, assets: &GameAssets) {
+    let font = &assets.font_main;
+    let panel = layout::mods_details_panel();
+    widgets::panel(d, panel, NEU_BLUE);
+
+    let x = panel.x + 30.0;
+    let width = panel.width - 60.0;
+    let Some(challenge) = challenge else {
+        d.draw_text_ex(font, "Standard Run", Vector2::new(x, panel.y + 25.0), 36.0, 1.0, NEU_BLUE);
+        let text = "Pick your own runes and heirlooms and start with the full 52-card deck.";
+        widgets::label_wrapped(d, font, text, Vector2::new(x, panel.y + 80.0), width, 22.0, PARCHMENT);
+        widgets::button(d, font, layout::challenge_play_button(), "PLAY", NEU_GREEN, true);
+        return;
+    };
+
+    d.draw_text_ex(font, &challenge.name, Vector2::new(x, panel.y + 25.0), 36.0, 1.0, NEU_BLUE);
+    let mut y = panel.y + 80.0;
+    if !challenge.description.is_empty() {
+        y += widgets::label_wrapped(d, font, &challenge.description, Vector2::new(x, y), width, 22.0, PARCHMENT) + 16.0;
+    }
+
+    let rune_names: Vec<String> = challenge.runes.iter()
+        .map(|id| stats.available_runes.iter().find(|r| r.id == *id).map(|r| r.name.clone()).unwrap_or_else(|| id.clone()))
+        .collect();
+    let relic_names: Vec<String> = challenge.banned_relics.iter()
+        .map(|id| stats.all_relics.get(id).map(|r| r.data.name.clone()).unwrap_or_else(|| id.clone()))
+        .collect();
+    let rules: Vec<&str> = challenge.rules.iter().map(|r| r.description()).collect();
+    let deck = if challenge.deck.is_standard() { String::new() } else { challenge.deck.describe() };
+
+    let sections = [
+        ("Runes", rune_names.join(", ")),
+        ("Deck", deck),
+        ("Banned relics", relic_names.join(", ")),
+        ("Rules", rules.join("\n")),
+    ];
+    for (title, text) in sections {
+        if text.is_empty() {
+            continue;
+        }
+        d.draw_text_ex(font, title, Vector2::new(x, y), 22.0, 1.0, NEU_YELLOW);
+        y += 28.0;
+        y += widgets::label_wrapped(d, font, &text, Vector2::new(x + 10.0, y), width - 10.0, 20.0, Color::LIGHTGRAY) + 14.0;
+    }
+
+    if stats.profile.completed(&challenge.id) {
+        d.draw_text_ex(font, "Completed", Vector2::new(x, y), 22.0, 1.0, NEU_GREEN);
+    }
+    widgets::button(d, font, layout::challenge_play_button(), "PLAY", NEU_GREEN, true);
+}
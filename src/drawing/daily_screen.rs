@@ -0,0 +1,101 @@
+use raylib::prelude::*;
+use crate::structures::stats::BaseModifiers;
+use crate::structures::assets::GameAssets;
+use crate::structures::daily::{self, LeaderboardEntry};
+use crate::logic::daily::{shown_entries, FOCUS_BACK, FOCUS_PASTE, FOCUS_PLAY};
+use crate::layout;
+use crate::consts::*;
+use crate::widgets;
+
+pub fn draw_daily(d: &mut RaylibDrawHandle, stats: &BaseModifiers, assets: &GameAssets) {
+    let font = &assets.font_main;
+    let today = daily::today();
+    let entries = stats.daily_board.day(&today);
+    let rows = shown_entries(stats);
+
+    let list = layout::mods_list_panel();
+    widgets::panel(d, list, NEU_ORANGE);
+    d.draw_text_ex(font, "DAILY RUN", Vector2::new(list.x + 40.0, list.y + 25.0), 48.0, 1.0, NEU_ORANGE);
+    let subtitle = format!("{} (UTC). Same seed for everyone today.", today);
+    d.draw_text_ex(font, &subtitle, Vector2::new(list.x + 40.0, list.y + 75.0), 20.0, 1.0, Color::GRAY);
+
+    if entries.is_empty() {
+        let hint = "No results yet today. Play the daily, or paste a teammate's result to check it and add it here.";
+        widgets::label_wrapped(d, font, hint, Vector2::new(list.x + 40.0, layout::CHALLENGE_START_Y), list.width - 80.0, 24.0, PARCHMENT);
+    }
+
+    for (row, entry) in entries.iter().take(rows).enumerate() {
+        let rect = layout::daily_row(row);
+        if stats.focus_row == row || widgets::interact(d, rect).hovered {
+            d.draw_rectangle_rounded(rect, 0.2, 4, NEU_BLUE.alpha(0.2));
+        }
+        let result = &entry.result;
+        let name_color = if entry.imported { PARCHMENT } else { NEU_YELLOW };
+        d.draw_text_ex(font, &format!("{}. {}", row + 1, result.player), Vector2::new(rect.x + 15.0, rect.y + 7.0), 24.0, 1.0, name_color);
+        let outcome = if result.victory { "Victory".to_string() } else { format!("Ante {}", result.ante) };
+        let score = format!("{}   {}", outcome, result.score);
+        let w = font.measure_text(&score, 22.0, 1.0).x;
+        let color = if result.victory { NEU_GREEN } else { PARCHMENT };
+        d.draw_text_ex(font, &score, Vector2::new(rect.x + rect.width - w - 15.0, rect.y + 9.0), 22.0, 1.0, color);
+    }
+
+    draw_daily_details(d, entries.get(stats.focus_row).filter(|_| stats.focus_row < rows), &today, assets);
+
+    widgets::button(d, font, layout::daily_button(0), "PLAY TODAY", NEU_GREEN, true);
+    widgets::button(d, font, layout::daily_button(1), "PASTE RESULT", NEU_BLUE, true);
+    widgets::button(d, font, layout::daily_back_button(), "BACK", NEU_ORANGE, true);
+    if stats.actions.using_keys {
+        let focus = match stats.focus_row.checked_sub(rows) {
+            Some(FOCUS_PLAY) => layout::daily_button(0),
+            Some(FOCUS_PASTE) => layout::daily_button(1),
+            Some(FOCUS_BACK) => layout::daily_back_button(),
+            _ => layout::daily_row(stats.focus_row),
+        };
+        widgets::focus_ring(d, focus);
+    }
+}
+
+// The focused result, or what today's daily is about
+fn draw_daily_details(d: &mut RaylibDrawHandle, entry: Option<&LeaderboardEntry>, today: &str, assets: &GameAssets) {
+    let font = &assets.font_main;
+    let panel = layout::mods_details_panel();
+    widgets::panel(d, panel, NEU_BLUE);
+
+    let x = panel.x + 30.0;
+    let width = panel.width - 60.0;
+    let Some(entry) = entry else {
+        d.draw_text_ex(font, &format!("Daily {}", today), Vector2::new(x, panel.y + 25.0), 36.0, 1.0, NEU_BLUE);
+        let text = "The seed comes from the date, so everyone on the team gets the same map, enemies, shuffles and shops today. \
+            Pick your runes and deck as usual; heirlooms and challenges are off.\n\n\
+            At the end, Copy Result puts a line on the clipboard for chat. Paste Result replays a copied line and adds it here if the score checks out.";
+        widgets::label_wrapped(d, font, text, Vector2::new(x, panel.y + 80.0), width, 22.0, PARCHMENT);
+        return;
+    };
+
+    let result = &entry.result;
+    d.draw_text_ex(font, &result.player, Vector2::new(x, panel.y + 25.0), 36.0, 1.0, NEU_BLUE);
+    let source = if entry.imported { "pasted in, replay checked" } else { "played here" };
+    d.draw_text_ex(font, &format!("{} ({})", result.date, source), Vector2::new(x, panel.y + 70.0), 20.0, 1.0, Color::GRAY);
+
+    let outcome = if result.victory { "Victory".to_string() } else { format!("Defeat at ante {}", result.ante) };
+    let mods: Vec<String> = result.mods.iter().map(|m| format!("{} v{}", m.id, m.version)).collect();
+    let none = |list: &[String]| if list.is_empty() { "None".to_string() } else { list.join(", ") };
+    let sections = [
+        ("Damage", format!("{} ({})", result.score, outcome)),
+        ("Deck", result.deck.clone()),
+        ("Runes", none(&result.runes)),
+        ("Relics", none(&result.relics)),
+        ("Mods", none(&mods)),
+        ("Decisions", result.log.len().to_string()),
+    ];
+    let mut y = panel.y + 110.0;
+    let bottom = layout::daily_button(0).y - 20.0;
+    for (title, text) in sections {
+        if y > bottom - 40.0 {
+            break;
+        }
+        d.draw_text_ex(font, title, Vector2::new(x, y), 22.0, 1.0, NEU_YELLOW);
+        y += 28.0;
+        y += widgets::label_wrapped(d, font, &text, Vector2::new(x + 10.0, y), width - 10.0, 20.0, Color::LIGHTGRAY) + 12.0;
+    }
+}
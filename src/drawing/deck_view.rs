@@ -0,0 +1,43 @@
+use raylib::prelude::*;
+use crate::structures::stats::BaseModifiers;
+use crate::structures::assets::GameAssets;
+use crate::structures::card::{Card, SUIT_NAMES};
+use crate::consts::*;
+use crate::layout;
+use crate::widgets;
+use super::game_screen::draw_single_card;
+
+// Overlay with every card the run owns, one row per suit, low to high
+pub fn draw_deck_view(d: &mut RaylibDrawHandle, stats: &BaseModifiers, assets: &GameAssets) {
+    let font = &assets.font_main;
+    let panel = layout::deck_view_panel();
+    d.draw_rectangle_rec(Rectangle::new(0.0, 0.0, SCREEN_WIDTH, SCREEN_HEIGHT), NEU_BLACK.alpha(0.7));
+    widgets::panel(d, panel, NEU_BLUE);
+    let title = format!("DECK ({} cards)", stats.owned_deck.len());
+    d.draw_text_ex(font, &title, Vector2::new(panel.x + 30.0, panel.y + 20.0), 32.0, 1.0, NEU_BLUE);
+
+    let rows: Vec<Vec<&Card>> = (0..SUIT_NAMES.len() as i32)
+        .map(|suit| {
+            let mut cards: Vec<&Card> = stats.owned_deck.iter().filter(|c| c.suit == suit).collect();
+            cards.sort_by_key(|c| c.value);
+            cards
+        })
+        .collect();
+    let cols = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+
+    for (row, cards) in rows.iter().enumerate() {
+        let label_pos = layout::deck_view_card(row, 0, cols);
+        d.draw_text_ex(font, &format!("{} ({})", SUIT_NAMES[row], cards.len()), Vector2::new(panel.x + 30.0, label_pos.y - 12.0), 18.0, 1.0, Color::GRAY);
+        for (col, card) in cards.iter().enumerate() {
+            let rect = layout::deck_view_card(row, col, cols);
+            let mut shown = (*card).clone();
+            shown.current_pos = Vector2::new(rect.x + rect.width / 2.0, rect.y + rect.height / 2.0);
+            shown.scale = Vector2::new(rect.width / CARD_WIDTH, rect.height / CARD_HEIGHT);
+            shown.rotation = 0.0;
+            shown.is_hovered = false;
+            draw_single_card(d, &shown, assets, &stats.settings);
+        }
+    }
+
+    widgets::button(d, font, layout::deck_view_close_button(), "CLOSE", NEU_BLUE, true);
+}
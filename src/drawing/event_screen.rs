@@ -0,0 +1,39 @@
+use raylib::prelude::*;
+use crate::structures::stats::BaseModifiers;
+use crate::structures::assets::GameAssets;
+use crate::logic::event::can_choose;
+use crate::consts::*;
+use crate::layout;
+use crate::widgets;
+
+pub fn draw_event(d: &mut RaylibDrawHandle, stats: &BaseModifiers, assets: &GameAssets) {
+    let font = &assets.font_main;
+    let Some(event) = &stats.current_event else { return; };
+    let panel = layout::event_panel();
+    widgets::panel(d, panel, PARCHMENT);
+
+    d.draw_text_ex(font, &event.title, Vector2::new(panel.x + 60.0, panel.y + 40.0), 44.0, 1.0, NEU_ORANGE);
+    d.draw_text_ex(font, &format!("HP {}/{}   ${}", stats.current_hp, stats.max_hp, stats.money), Vector2::new(panel.x + 60.0, panel.y + 100.0), 22.0, 1.0, Color::GRAY);
+    widgets::label_wrapped(d, font, &event.text, Vector2::new(panel.x + 60.0, panel.y + 150.0), panel.width - 120.0, 26.0, PARCHMENT);
+
+    if let Some(result) = &stats.event_result {
+        let mut y = layout::event_choice_button(0).y;
+        for line in result {
+            y += widgets::label_wrapped(d, font, line, Vector2::new(panel.x + 60.0, y), panel.width - 120.0, 28.0, NEU_YELLOW) + 8.0;
+        }
+        let rect = layout::event_continue_button();
+        widgets::button(d, font, rect, "CONTINUE", NEU_ORANGE, true);
+        if stats.actions.using_keys {
+            widgets::focus_ring(d, rect);
+        }
+        return;
+    }
+
+    for (i, choice) in event.choices.iter().enumerate() {
+        let rect = layout::event_choice_button(i);
+        widgets::button(d, font, rect, &choice.label, NEU_BLUE, can_choose(stats, choice));
+        if stats.actions.using_keys && stats.focus_row == i {
+            widgets::focus_ring(d, rect);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use raylib::prelude::*;
+use crate::structures::stats::BaseModifiers;
+use crate::structures::assets::GameAssets;
+use crate::structures::map::NodeKind;
+use crate::layout::{self, MAP_NODE_RADIUS};
+use crate::consts::*;
+use crate::widgets;
+
+fn node_color(kind: NodeKind) -> Color {
+    match kind {
+        NodeKind::Combat => NEU_BLUE,
+        NodeKind::Elite => NEU_RED,
+        NodeKind::Rest => NEU_GREEN,
+        NodeKind::Treasure => NEU_YELLOW,
+        NodeKind::Event => PARCHMENT,
+        NodeKind::Shop => NEU_ORANGE,
+        NodeKind::Boss => Color::new(150, 40, 160, 255),
+    }
+}
+
+fn node_description(kind: NodeKind) -> &'static str {
+    match kind {
+        NodeKind::Combat => "Fight a monster from this depth.",
+        NodeKind::Elite => "A tougher foe: 1.5x HP and damage, better payout.",
+        NodeKind::Rest => "Recover 30% of your Max HP.",
+        NodeKind::Treasure => "Take a relic for free.",
+        NodeKind::Event => "Something strange happens...",
+        NodeKind::Shop => "Spend your gold.",
+        NodeKind::Boss => "The master of this floor. Beat it to descend.",
+    }
+}
+
+pub fn draw_map(d: &mut RaylibDrawHandle, stats: &BaseModifiers, assets: &GameAssets) {
+    let font = &assets.font_main;
+    let map = &stats.run_map;
+    let available = map.available();
+
+    let ante = if stats.endless { format!("ANTE {} - ENDLESS", stats.ante) } else { format!("ANTE {} / {}", stats.ante, FINAL_ANTE) };
+    d.draw_text_ex(font, &ante, Vector2::new(40.0, 30.0), 48.0, 1.0, if stats.endless { NEU_RED } else { NEU_ORANGE });
+    d.draw_text_ex(font, &format!("HP {}/{}   ${}", stats.current_hp, stats.max_hp, stats.money), Vector2::new(40.0, 85.0), 26.0, 1.0, PARCHMENT);
+    d.draw_text_ex(font, &format!("Seed {:016X}", stats.run_seed), Vector2::new(40.0, SCREEN_HEIGHT - 40.0), 18.0, 1.0, Color::GRAY);
+
+    // Paths first so nodes sit on top. The route already walked is drawn solid.
+    for (i, node) in map.nodes.iter().enumerate() {
+        let from = layout::map_node_center(node.row, node.col, node.row_width);
+        for &next in &node.next {
+            let to_node = &map.nodes[next];
+            let to = layout::map_node_center(to_node.row, to_node.col, to_node.row_width);
+            let walked = node.visited && to_node.visited;
+            let open = map.current == Some(i) && available.contains(&next);
+            let color = if walked { NEU_ORANGE } else if open { PARCHMENT } else { Color::GRAY.alpha(0.4) };
+            d.draw_line_ex(from, to, if walked || open { 4.0 } else { 2.0 }, color);
+        }
+    }
+
+    let mut hovered = None;
+    for (i, node) in map.nodes.iter().enumerate() {
+        let center = layout::map_node_center(node.row, node.col, node.row_width);
+        let color = node_color(node.kind);
+        let reachable = available.contains(&i);
+        let faded = if reachable || node.visited { color } else { color.alpha(0.35) };
+
+        let state = widgets::icon_button(d, font, center, MAP_NODE_RADIUS, None, node.kind.label(), faded, map.current == Some(i));
+        if reachable {
+            // Pulse the nodes you can pick
+            let pulse = ((d.get_time() * 4.0).sin() * 0.5 + 0.5) as f32;
+            d.draw_circle_lines(center.x as i32, center.y as i32, MAP_NODE_RADIUS + 6.0 + pulse * 4.0, color);
+        }
+        if node.visited {
+            d.draw_circle(center.x as i32, center.y as i32, 6.0, NEU_ORANGE);
+        }
+        if state.hovered {
+            hovered = Some((center, node.kind));
+        }
+    }
+
+    if stats.actions.using_keys && let Some(&index) = available.get(stats.focus_col) {
+        let node = &map.nodes[index];
+        let center = layout::map_node_center(node.row, node.col, node.row_width);
+        widgets::focus_ring_circle(d, center, MAP_NODE_RADIUS);
+        hovered = hovered.or(Some((center, node.kind)));
+    }
+
+    for ft in &stats.floating_texts {
+        let alpha = (ft.life / ft.max_life).clamp(0.0, 1.0);
+        d.draw_text_ex(font, &ft.text, ft.pos, ft.size as f32, 1.0, ft.color.alpha(alpha));
+    }
+
+    if let Some((center, kind)) = hovered {
+        widgets::tooltip(d, font, Vector2::new(center.x + MAP_NODE_RADIUS, center.y), kind.label(), node_description(kind), node_color(kind));
+    }
+}
@@ -1,8 +1,13 @@
 use raylib::prelude::*;
 use crate::structures::assets::GameAssets;
+use crate::structures::stats::BaseModifiers;
+use crate::logic::menu::MENU_ITEMS;
+use crate::structures::save::RunSave;
+use crate::layout;
 use crate::consts::*;
+use crate::widgets;
 
-pub fn draw_menu(d: &mut RaylibDrawHandle, assets: &GameAssets) {
+pub fn draw_menu(d: &mut RaylibDrawHandle, stats: &BaseModifiers, assets: &GameAssets) {
     let center_x = SCREEN_WIDTH / 2.0;
     let center_y = SCREEN_HEIGHT / 2.0;
 
@@ -14,23 +19,20 @@ pub fn draw_menu(d: &mut RaylibDrawHandle, assets: &GameAssets) {
     d.draw_text_ex(
         &assets.font_main,
         title,
-        Vector2::new(center_x - title_dim.x / 2.0, center_y - 150.0),
+        Vector2::new(center_x - title_dim.x / 2.0, center_y - 250.0),
         title_size,
         1.0,
         PARCHMENT
     );
 
-    // Subtitle
-    let sub = "Click to Start";
-    let sub_size = 40.0;
-    let sub_dim = assets.font_main.measure_text(sub, sub_size, 1.0);
-
-    d.draw_text_ex(
-        &assets.font_main,
-        sub,
-        Vector2::new(center_x - sub_dim.x / 2.0, center_y + 100.0),
-        sub_size,
-        1.0,
-        Color::GRAY
-    );
+    // Continue only lights up with a run to go back to
+    let can_continue = RunSave::exists();
+    for (i, label) in MENU_ITEMS.iter().enumerate() {
+        let primary = if can_continue { 0 } else { 1 };
+        let color = if i == primary { NEU_ORANGE } else { NEU_BLUE };
+        widgets::button(d, &assets.font_main, layout::menu_button(i), label, color, i != 0 || can_continue);
+    }
+    if stats.actions.using_keys {
+        widgets::focus_ring(d, layout::menu_button(stats.focus_row));
+    }
 }
@@ -4,6 +4,8 @@ use crate::structures::card::Card;
 use crate::structures::assets::GameAssets;
 use crate::structures::state::{GameState, AnimationState};
 use crate::consts::*;
+use crate::layout::Viewport;
+use crate::widgets;
 
 // Module declarations
 mod game_screen;
@@ -12,7 +14,15 @@ mod menu_screen;
 mod rune_select_screen;
 mod stats_menu_screen;
 mod battle_result_screen;
-pub mod ui_elements;
+mod settings_screen;
+mod tooltips;
+mod map_screen;
+mod event_screen;
+mod mods_screen;
+mod deck_view;
+mod run_end_screen;
+mod challenge_screen;
+mod daily_screen;
 
 // Re-exporting functions to be used by main
 use game_screen::*;
@@ -21,31 +31,35 @@ use menu_screen::*;
 use rune_select_screen::*;
 use stats_menu_screen::*;
 use battle_result_screen::*;
+use settings_screen::*;
+use tooltips::*;
+use map_screen::*;
+use event_screen::*;
+use mods_screen::*;
+use run_end_screen::*;
+use challenge_screen::*;
+use daily_screen::*;
 
 
 
 pub fn draw_scene(d: &mut RaylibDrawHandle, stats: &BaseModifiers, hand: &[Card], state: &GameState, assets: &GameAssets, anim: &AnimationState) {
-    let camera = Camera2D {
-        target: Vector2::new(0.0, 0.0),
-        offset: stats.shake_offset,
-        rotation: stats.shake_rotation,
-        zoom: 1.0,
-    };
+    // Everything is drawn on the 1600x900 canvas; the viewport scales it into the window
+    // and anything outside the canvas stays black (letterbox bars).
+    let viewport = Viewport::current(d);
+    let camera = viewport.camera();
+    let shake_camera = viewport.shake_camera(stats.shake_offset, stats.shake_rotation);
+
+    d.clear_background(Color::BLACK);
 
     match state {
         GameState::Playing => {
-            let src = Rectangle::new(0.0, 0.0, assets.tex_background.width as f32, assets.tex_background.height as f32);
-            let dest = Rectangle::new(0.0, 0.0, SCREEN_WIDTH, SCREEN_HEIGHT);
-            d.draw_texture_pro(&assets.tex_background, src, dest, Vector2::zero(), 0.0, Color::WHITE);
-
             {
-                let mut d_cam = d.begin_mode2D(camera);
-                draw_enemy_monitor(&mut d_cam, stats, assets);
-                draw_player_panel(&mut d_cam, stats, assets);
-                draw_action_panel(&mut d_cam, stats, anim, assets, hand);
-                draw_sort_buttons(&mut d_cam, assets);
-                draw_relics(&mut d_cam, stats, assets);
-                draw_game_area(&mut d_cam, hand, assets, stats);
+                let mut d_vp = d.begin_mode2D(camera);
+                draw_background(&mut d_vp, assets);
+            }
+            {
+                let mut d_cam = d.begin_mode2D(shake_camera);
+                draw_table(&mut d_cam, stats, anim, assets, hand);
 
                 // FIX: Scoring Popups now use Custom Font
                 for ft in &stats.floating_texts {
@@ -65,62 +79,146 @@ pub fn draw_scene(d: &mut RaylibDrawHandle, stats: &BaseModifiers, hand: &[Card]
                     d_cam.draw_rectangle_pro(rec, origin, p.rotation * 57.29, color);
                 }
             }
+            // Tooltips stay still while the table shakes
+            let mut d_vp = d.begin_mode2D(camera);
+            draw_inspect_panel(&mut d_vp, stats, hand, assets);
+            draw_tooltips(&mut d_vp, stats, hand, assets);
+        },
+        GameState::ChallengeSelect => {
+            let mut d_vp = d.begin_mode2D(camera);
+            d_vp.draw_rectangle_rec(viewport.canvas_rect(), NEU_BG);
+            draw_challenge_select(&mut d_vp, stats, assets);
         },
         GameState::RuneSelect => {
-            d.clear_background(NEU_BG);
-            draw_rune_select(d, stats, assets);
+            let mut d_vp = d.begin_mode2D(camera);
+            d_vp.draw_rectangle_rec(viewport.canvas_rect(), NEU_BG);
+            draw_rune_select(&mut d_vp, stats, assets);
         },
         GameState::Shop => {
             // Draw the playing state as a background
-            let src = Rectangle::new(0.0, 0.0, assets.tex_background.width as f32, assets.tex_background.height as f32);
-            let dest = Rectangle::new(0.0, 0.0, SCREEN_WIDTH, SCREEN_HEIGHT);
-            d.draw_texture_pro(&assets.tex_background, src, dest, Vector2::zero(), 0.0, Color::WHITE);
             {
-                let mut d_cam = d.begin_mode2D(camera);
-                draw_enemy_monitor(&mut d_cam, stats, assets);
-                draw_player_panel(&mut d_cam, stats, assets);
-                draw_action_panel(&mut d_cam, stats, anim, assets, hand);
-                draw_sort_buttons(&mut d_cam, assets);
-                draw_relics(&mut d_cam, stats, assets);
-                draw_game_area(&mut d_cam, hand, assets, stats);
+                let mut d_vp = d.begin_mode2D(camera);
+                draw_background(&mut d_vp, assets);
             }
+            {
+                let mut d_cam = d.begin_mode2D(shake_camera);
+                draw_table(&mut d_cam, stats, anim, assets, hand);
+            }
+            let mut d_vp = d.begin_mode2D(camera);
             // Draw a semi-transparent overlay
-            d.draw_rectangle(0, 0, SCREEN_WIDTH as i32, SCREEN_HEIGHT as i32, NEU_BLACK.alpha(0.85));
+            d_vp.draw_rectangle_rec(viewport.canvas_rect(), NEU_BLACK.alpha(0.85));
             // Draw the shop UI
-            draw_shop(d, stats, assets);
+            draw_shop(&mut d_vp, stats, assets);
         },
         GameState::StatsMenu => {
             {
-                let mut d_cam = d.begin_mode2D(camera);
+                let mut d_cam = d.begin_mode2D(shake_camera);
                 draw_player_panel(&mut d_cam, stats, assets);
             }
-            d.draw_rectangle(0, 0, SCREEN_WIDTH as i32, SCREEN_HEIGHT as i32, NEU_BLACK.alpha(0.85));
-            draw_stats_menu(d, stats, assets);
+            let mut d_vp = d.begin_mode2D(camera);
+            d_vp.draw_rectangle_rec(viewport.canvas_rect(), NEU_BLACK.alpha(0.85));
+            draw_stats_menu(&mut d_vp, stats, assets);
         },
         GameState::BattleResult => {
             // Draw the playing state as a background
-            let src = Rectangle::new(0.0, 0.0, assets.tex_background.width as f32, assets.tex_background.height as f32);
-            let dest = Rectangle::new(0.0, 0.0, SCREEN_WIDTH, SCREEN_HEIGHT);
-            d.draw_texture_pro(&assets.tex_background, src, dest, Vector2::zero(), 0.0, Color::WHITE);
             {
-                let mut d_cam = d.begin_mode2D(camera);
-                draw_enemy_monitor(&mut d_cam, stats, assets);
-                draw_player_panel(&mut d_cam, stats, assets);
-                draw_action_panel(&mut d_cam, stats, anim, assets, hand);
-                draw_sort_buttons(&mut d_cam, assets);
-                draw_relics(&mut d_cam, stats, assets);
-                draw_game_area(&mut d_cam, hand, assets, stats);
+                let mut d_vp = d.begin_mode2D(camera);
+                draw_background(&mut d_vp, assets);
+            }
+            {
+                let mut d_cam = d.begin_mode2D(shake_camera);
+                draw_table(&mut d_cam, stats, anim, assets, hand);
             }
+            let mut d_vp = d.begin_mode2D(camera);
             // Draw a semi-transparent overlay
-            d.draw_rectangle(0, 0, SCREEN_WIDTH as i32, SCREEN_HEIGHT as i32, NEU_BLACK.alpha(0.85));
+            d_vp.draw_rectangle_rec(viewport.canvas_rect(), NEU_BLACK.alpha(0.85));
             // Draw the battle result UI
-            draw_battle_result(d, stats, assets);
+            draw_battle_result(&mut d_vp, stats, assets);
+        },
+        GameState::Map => {
+            let mut d_vp = d.begin_mode2D(camera);
+            d_vp.draw_rectangle_rec(viewport.canvas_rect(), NEU_BG);
+            draw_map(&mut d_vp, stats, assets);
+        },
+        GameState::Event => {
+            let mut d_vp = d.begin_mode2D(camera);
+            d_vp.draw_rectangle_rec(viewport.canvas_rect(), NEU_BG);
+            draw_event(&mut d_vp, stats, assets);
         },
         GameState::Menu => {
-            d.clear_background(NEU_BG);
-            draw_menu(d, assets); // Assuming you want main menu to use it too if not already
+            let mut d_vp = d.begin_mode2D(camera);
+            d_vp.draw_rectangle_rec(viewport.canvas_rect(), NEU_BG);
+            draw_menu(&mut d_vp, stats, assets); // Assuming you want main menu to use it too if not already
+        },
+        GameState::Settings => {
+            let mut d_vp = d.begin_mode2D(camera);
+            d_vp.draw_rectangle_rec(viewport.canvas_rect(), NEU_BG);
+            draw_settings(&mut d_vp, stats, assets);
         },
-        _ => d.clear_background(NEU_BG),
+        GameState::Controls => {
+            let mut d_vp = d.begin_mode2D(camera);
+            d_vp.draw_rectangle_rec(viewport.canvas_rect(), NEU_BG);
+            draw_controls(&mut d_vp, stats, assets);
+        },
+        GameState::Mods => {
+            let mut d_vp = d.begin_mode2D(camera);
+            d_vp.draw_rectangle_rec(viewport.canvas_rect(), NEU_BG);
+            draw_mods(&mut d_vp, stats, assets);
+        },
+        GameState::RunEnd => {
+            let mut d_vp = d.begin_mode2D(camera);
+            d_vp.draw_rectangle_rec(viewport.canvas_rect(), NEU_BG);
+            draw_run_end(&mut d_vp, stats, assets);
+        },
+        GameState::Daily => {
+            let mut d_vp = d.begin_mode2D(camera);
+            d_vp.draw_rectangle_rec(viewport.canvas_rect(), NEU_BG);
+            draw_daily(&mut d_vp, stats, assets);
+        },
+        _ => {
+            let mut d_vp = d.begin_mode2D(camera);
+            d_vp.draw_rectangle_rec(viewport.canvas_rect(), NEU_BG);
+        },
+    }
+    {
+        let mut d_vp = d.begin_mode2D(camera);
+        draw_toasts(&mut d_vp, stats, assets);
+    }
+    if stats.settings.debug_logging {
+        d.draw_fps(10, 10);
     }
-    d.draw_fps(10, 10);
+}
+
+// Newest toast at the bottom-right, older ones stacked above it, fading out at the end
+fn draw_toasts(d: &mut RaylibDrawHandle, stats: &BaseModifiers, assets: &GameAssets) {
+    let width = 620.0;
+    let mut bottom = SCREEN_HEIGHT - 20.0;
+    for toast in stats.toasts.iter().rev() {
+        let lines = widgets::wrap_text(&assets.font_main, &toast.text, 18.0, width - 30.0).len();
+        let height = lines as f32 * 18.0 * 1.2 + 24.0;
+        let rect = Rectangle::new(SCREEN_WIDTH - width - 20.0, bottom - height, width, height);
+        let alpha = toast.life.min(1.0);
+        d.draw_rectangle_rounded(rect, 0.1, 4, NEU_BLACK.alpha(0.9 * alpha));
+        d.draw_rectangle_rounded_lines_ex(rect, 0.1, 4, 2.0, toast.color.alpha(alpha));
+        widgets::label_wrapped(d, &assets.font_main, &toast.text, Vector2::new(rect.x + 15.0, rect.y + 12.0), width - 30.0, 18.0, PARCHMENT.alpha(alpha));
+        bottom -= height + 10.0;
+    }
+}
+
+fn draw_background(d: &mut RaylibDrawHandle, assets: &GameAssets) {
+    let src = Rectangle::new(0.0, 0.0, assets.tex_background.width as f32, assets.tex_background.height as f32);
+    let dest = Rectangle::new(0.0, 0.0, SCREEN_WIDTH, SCREEN_HEIGHT);
+    d.draw_texture_pro(&assets.tex_background, src, dest, Vector2::zero(), 0.0, Color::WHITE);
+}
+
+// The board shared by Playing and the overlays drawn on top of it
+fn draw_table(d: &mut RaylibDrawHandle, stats: &BaseModifiers, anim: &AnimationState, assets: &GameAssets, hand: &[Card]) {
+    draw_enemy_monitor(d, stats, assets);
+    draw_player_panel(d, stats, assets);
+    draw_action_panel(d, stats, anim, assets, hand);
+    draw_sort_buttons(d, assets);
+    draw_relics(d, stats, assets);
+    draw_consumables(d, stats, assets);
+    draw_equipped_runes(d, stats, assets);
+    draw_game_area(d, hand, assets, stats);
 }
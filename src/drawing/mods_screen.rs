@@ -0,0 +1,105 @@
+use raylib::prelude::*;
+use crate::structures::stats::BaseModifiers;
+use crate::structures::assets::GameAssets;
+use crate::structures::mods::ModInfo;
+use crate::logic::mods::focus_back;
+use crate::data_validation::MODS_DIR;
+use crate::layout;
+use crate::consts::*;
+use crate::widgets;
+
+pub fn draw_mods(d: &mut RaylibDrawHandle, stats: &BaseModifiers, assets: &GameAssets) {
+    let font = &assets.font_main;
+    let list = layout::mods_list_panel();
+    widgets::panel(d, list, NEU_ORANGE);
+    d.draw_text_ex(font, "MODS", Vector2::new(list.x + 40.0, list.y + 25.0), 48.0, 1.0, NEU_ORANGE);
+    d.draw_text_ex(font, "Load order (top loads first, later mods win)", Vector2::new(list.x + 40.0, list.y + 75.0), 20.0, 1.0, Color::GRAY);
+
+    if assets.data.mods.is_empty() {
+        let hint = format!("No mods found. Put each data pack in its own folder under {}/ with a mod.json.", MODS_DIR);
+        widgets::label_wrapped(d, font, &hint, Vector2::new(list.x + 40.0, layout::MODS_START_Y), list.width - 80.0, 24.0, PARCHMENT);
+    }
+
+    for (row, info) in assets.data.mods.iter().enumerate() {
+        let rect = layout::mods_row(row);
+        if stats.focus_row == row || widgets::interact(d, rect).hovered {
+            d.draw_rectangle_rounded(rect, 0.2, 4, NEU_BLUE.alpha(0.2));
+        }
+        let name_color = if !info.errors.is_empty() { NEU_RED } else if info.is_active() { PARCHMENT } else { Color::GRAY };
+        d.draw_text_ex(font, &format!("{}. {}", row + 1, info.name()), Vector2::new(rect.x + 15.0, rect.y + 4.0), 26.0, 1.0, name_color);
+        d.draw_text_ex(font, &summary(info), Vector2::new(rect.x + 15.0, rect.y + 32.0), 18.0, 1.0, Color::GRAY);
+
+        let (label, color) = if info.enabled { ("ON", NEU_GREEN) } else { ("OFF", NEU_RED) };
+        widgets::button(d, font, layout::mods_toggle(row), label, color, info.manifest.is_some());
+    }
+
+    if let Some(info) = assets.data.mods.get(stats.focus_row) {
+        draw_mod_details(d, info, assets);
+    }
+
+    widgets::button(d, font, layout::mods_back_button(), "BACK", NEU_ORANGE, true);
+    if stats.actions.using_keys {
+        let focus = if stats.focus_row == focus_back(assets) { layout::mods_back_button() } else { layout::mods_row(stats.focus_row) };
+        widgets::focus_ring(d, focus);
+    }
+}
+
+// One line under the mod's name: what it did to the game data
+fn summary(info: &ModInfo) -> String {
+    if !info.is_active() {
+        return format!("{} (not loaded)", info.folder);
+    }
+    let mut parts = vec![format!("{} new", info.added.len()), format!("{} overridden", info.overridden.len())];
+    if !info.disabled.is_empty() {
+        parts.push(format!("{} disabled", info.disabled.len()));
+    }
+    if !info.conflicts.is_empty() {
+        parts.push(format!("{} conflicts", info.conflicts.len()));
+    }
+    if !info.errors.is_empty() {
+        parts.push(format!("{} errors", info.errors.len()));
+    }
+    parts.join(", ")
+}
+
+fn draw_mod_details(d: &mut RaylibDrawHandle, info: &ModInfo, assets: &GameAssets) {
+    let font = &assets.font_main;
+    let panel = layout::mods_details_panel();
+    widgets::panel(d, panel, NEU_BLUE);
+
+    let x = panel.x + 30.0;
+    let width = panel.width - 60.0;
+    let bottom = panel.y + panel.height - 30.0;
+    d.draw_text_ex(font, &info.name(), Vector2::new(x, panel.y + 25.0), 36.0, 1.0, NEU_BLUE);
+    let mut y = panel.y + 70.0;
+
+    if let Some(manifest) = &info.manifest {
+        let byline = if manifest.author.is_empty() {
+            format!("{} v{}", manifest.id, manifest.version)
+        } else {
+            format!("{} v{} by {}", manifest.id, manifest.version, manifest.author)
+        };
+        d.draw_text_ex(font, &byline, Vector2::new(x, y), 20.0, 1.0, Color::GRAY);
+        y += 32.0;
+        if !manifest.description.is_empty() {
+            y += widgets::label_wrapped(d, font, &manifest.description, Vector2::new(x, y), width, 22.0, PARCHMENT) + 12.0;
+        }
+    }
+
+    // Messages one per line, ids as a comma list
+    let sections = [
+        ("Errors", &info.errors, NEU_RED, "\n"),
+        ("Conflicts", &info.conflicts, NEU_YELLOW, "\n"),
+        ("Overrides", &info.overridden, PARCHMENT, ", "),
+        ("Disables", &info.disabled, PARCHMENT, ", "),
+        ("Adds", &info.added, PARCHMENT, ", "),
+    ];
+    for (title, lines, color, separator) in sections {
+        if lines.is_empty() || y > bottom - 40.0 {
+            continue;
+        }
+        d.draw_text_ex(font, &format!("{} ({})", title, lines.len()), Vector2::new(x, y), 22.0, 1.0, color);
+        y += 28.0;
+        y += widgets::label_wrapped(d, font, &lines.join(separator), Vector2::new(x + 10.0, y), width - 10.0, 18.0, Color::LIGHTGRAY) + 14.0;
+    }
+}
@@ -0,0 +1,90 @@
+use raylib::prelude::*;
+use crate::structures::stats::BaseModifiers;
+use crate::structures::assets::GameAssets;
+use crate::logic::run_end::run_end_items;
+use crate::structures::endless;
+use crate::consts::*;
+use crate::layout;
+use crate::widgets;
+
+pub fn draw_run_end(d: &mut RaylibDrawHandle, stats: &BaseModifiers, assets: &GameAssets) {
+    let font = &assets.font_main;
+    let panel = layout::run_end_panel();
+    let Some(summary) = &stats.run_summary else { return; };
+
+    let (title, subtitle, color) = if summary.victory {
+        ("VICTORY", format!("{} falls. The night is yours.", summary.enemy), NEU_GREEN)
+    } else if summary.endless {
+        ("ENDLESS OVER", format!("Defeated by {} in ante {}", summary.enemy, summary.ante), NEU_RED)
+    } else {
+        ("DEFEAT", format!("Defeated by {}", summary.enemy), NEU_RED)
+    };
+    widgets::panel(d, panel, color);
+    let dim = font.measure_text(title, 72.0, 1.0);
+    d.draw_text_ex(font, title, Vector2::new(panel.x + panel.width / 2.0 - dim.x / 2.0, panel.y + 30.0), 72.0, 1.0, color);
+    let dim = font.measure_text(&subtitle, 24.0, 1.0);
+    d.draw_text_ex(font, &subtitle, Vector2::new(panel.x + panel.width / 2.0 - dim.x / 2.0, panel.y + 110.0), 24.0, 1.0, PARCHMENT);
+
+    let best_hand = match summary.best_hand {
+        Some((rank, total)) => format!("{} ({})", rank.name(), total),
+        None => "-".to_string(),
+    };
+    let ante = if summary.endless {
+        format!("{} (endless +{})", summary.ante, endless::depth(summary.ante, FINAL_ANTE))
+    } else {
+        format!("{} / {}", summary.ante, FINAL_ANTE)
+    };
+    let total_score = if summary.new_high_score {
+        format!("{} (new best!)", summary.total_score)
+    } else if summary.endless {
+        format!("{} (best {})", summary.total_score, stats.profile.endless_high_score)
+    } else {
+        summary.total_score.to_string()
+    };
+    let mut rows = vec![
+        ("Seed", format!("{:016X}", summary.seed)),
+        ("Ante", ante),
+        ("Rounds", summary.round.to_string()),
+        ("Level", summary.level.to_string()),
+        ("Enemies defeated", summary.enemies_defeated.to_string()),
+        ("Total damage", total_score),
+        ("Best hand", best_hand),
+        ("Money", format!("${}", summary.money)),
+    ];
+    if let Some(challenge) = &summary.challenge {
+        let text = if summary.victory { format!("{} (completed)", challenge) } else { challenge.clone() };
+        rows.insert(1, ("Challenge", text));
+    }
+    if let Some(result) = &summary.daily {
+        let text = match summary.daily_rank {
+            Some(rank) => format!("{} (#{} on the leaderboard)", result.date, rank),
+            None => result.date.clone(),
+        };
+        rows.insert(1, ("Daily", text));
+    }
+    let (left, right) = (panel.x + 80.0, panel.x + panel.width - 80.0);
+    let mut y = panel.y + 170.0;
+    for (label, value) in rows {
+        d.draw_text_ex(font, label, Vector2::new(left, y), 24.0, 1.0, Color::GRAY);
+        let w = font.measure_text(&value, 24.0, 1.0).x;
+        d.draw_text_ex(font, &value, Vector2::new(right - w, y), 24.0, 1.0, PARCHMENT);
+        y += 34.0;
+    }
+
+    let relics = if summary.relics.is_empty() { "No relics".to_string() } else { summary.relics.join(", ") };
+    d.draw_text_ex(font, "Relics", Vector2::new(left, y + 6.0), 24.0, 1.0, Color::GRAY);
+    widgets::label_wrapped(d, font, &relics, Vector2::new(left, y + 38.0), right - left, 20.0, NEU_ORANGE);
+
+    let items = run_end_items(stats);
+    for (i, label) in items.iter().enumerate() {
+        let color = match *label {
+            "Endless" | "Copy Result" => NEU_GREEN,
+            "New Run" => NEU_ORANGE,
+            _ => NEU_BLUE,
+        };
+        widgets::button(d, font, layout::run_end_button(i, items.len()), label, color, true);
+    }
+    if stats.actions.using_keys {
+        widgets::focus_ring(d, layout::run_end_button(stats.focus_col, items.len()));
+    }
+}
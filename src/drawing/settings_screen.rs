@@ -0,0 +1,87 @@
+use raylib::prelude::*;
+use crate::structures::stats::BaseModifiers;
+use crate::structures::assets::GameAssets;
+use crate::logic::settings::{self as settings_logic, value_text, SETTINGS_ROWS};
+use crate::logic::controls;
+use crate::input::ALL_ACTIONS;
+use crate::layout::{self, SETTINGS_ROW_H, SETTINGS_START_Y};
+use crate::consts::*;
+use crate::widgets;
+
+pub fn draw_settings(d: &mut RaylibDrawHandle, stats: &BaseModifiers, assets: &GameAssets) {
+    let center_x = SCREEN_WIDTH / 2.0;
+    let panel = layout::settings_panel(SETTINGS_ROWS.len());
+    let panel_x = panel.x;
+
+    widgets::panel(d, panel, NEU_ORANGE);
+    d.draw_text_ex(&assets.font_main, "SETTINGS", Vector2::new(center_x - 90.0, 85.0), 48.0, 1.0, NEU_ORANGE);
+
+    for (row, label) in SETTINGS_ROWS.iter().enumerate() {
+        let y = SETTINGS_START_Y + row as f32 * SETTINGS_ROW_H;
+        d.draw_text_ex(&assets.font_main, label, Vector2::new(panel_x + 40.0, y + 8.0), 26.0, 1.0, PARCHMENT);
+
+        let (left, right) = layout::settings_arrows(row);
+        widgets::button(d, &assets.font_main, left, "<", NEU_BLUE, true);
+        widgets::button(d, &assets.font_main, right, ">", NEU_BLUE, true);
+
+        let value = value_text(&stats.settings, row);
+        let dim = assets.font_main.measure_text(&value, 26.0, 1.0);
+        let value_center = (left.x + left.width + right.x) / 2.0;
+        d.draw_text_ex(&assets.font_main, &value, Vector2::new(value_center - dim.x / 2.0, y + 8.0), 26.0, 1.0, NEU_YELLOW);
+    }
+
+    widgets::button(d, &assets.font_main, layout::settings_controls_button(), "CONTROLS", NEU_BLUE, true);
+    widgets::button(d, &assets.font_main, layout::settings_back_button(), "BACK", NEU_ORANGE, true);
+
+    if stats.actions.using_keys {
+        let focus = match stats.focus_row {
+            settings_logic::FOCUS_CONTROLS => layout::settings_controls_button(),
+            settings_logic::FOCUS_BACK => layout::settings_back_button(),
+            row => layout::settings_row(row),
+        };
+        widgets::focus_ring(d, focus);
+    }
+}
+
+pub fn draw_controls(d: &mut RaylibDrawHandle, stats: &BaseModifiers, assets: &GameAssets) {
+    let center_x = SCREEN_WIDTH / 2.0;
+    let panel = layout::settings_panel(SETTINGS_ROWS.len());
+    let bindings = &stats.settings.bindings;
+
+    widgets::panel(d, panel, NEU_BLUE);
+    d.draw_text_ex(&assets.font_main, "CONTROLS", Vector2::new(center_x - 95.0, 85.0), 48.0, 1.0, NEU_BLUE);
+    d.draw_text_ex(&assets.font_main, "KEYBOARD", Vector2::new(panel.x + 380.0, 130.0), 20.0, 1.0, Color::GRAY);
+    d.draw_text_ex(&assets.font_main, "GAMEPAD", Vector2::new(panel.x + 600.0, 130.0), 20.0, 1.0, Color::GRAY);
+
+    for (row, action) in ALL_ACTIONS.iter().enumerate() {
+        let rect = layout::controls_row(row);
+        let waiting = stats.rebinding == Some(*action);
+        if waiting || widgets::interact(d, rect).hovered {
+            d.draw_rectangle_rounded(rect, 0.2, 4, NEU_BLUE.alpha(0.2));
+        }
+
+        let text_y = rect.y + 8.0;
+        d.draw_text_ex(&assets.font_main, action.label(), Vector2::new(rect.x + 20.0, text_y), 24.0, 1.0, PARCHMENT);
+        if waiting {
+            d.draw_text_ex(&assets.font_main, "Press a key or button...", Vector2::new(rect.x + 360.0, text_y), 24.0, 1.0, NEU_ORANGE);
+        } else {
+            d.draw_text_ex(&assets.font_main, &bindings.key_names(*action), Vector2::new(rect.x + 360.0, text_y), 24.0, 1.0, NEU_YELLOW);
+            d.draw_text_ex(&assets.font_main, &bindings.pad_names(*action), Vector2::new(rect.x + 580.0, text_y), 24.0, 1.0, NEU_YELLOW);
+        }
+    }
+
+    let hint = if stats.rebinding.is_some() { "Press a key or button to bind it; Back or right-click cancels" } else { "Click a row (or press Confirm) to rebind it" };
+    d.draw_text_ex(&assets.font_main, hint, Vector2::new(panel.x + 40.0, panel.y + panel.height - 32.0), 20.0, 1.0, Color::GRAY);
+
+    widgets::button(d, &assets.font_main, layout::controls_reset_button(), "RESET", NEU_RED, true);
+    widgets::button(d, &assets.font_main, layout::controls_back_button(), "BACK", NEU_ORANGE, true);
+
+    if stats.actions.using_keys && stats.rebinding.is_none() {
+        let focus = match stats.focus_row {
+            controls::FOCUS_RESET => layout::controls_reset_button(),
+            controls::FOCUS_BACK => layout::controls_back_button(),
+            row => layout::controls_row(row),
+        };
+        widgets::focus_ring(d, focus);
+    }
+}
@@ -0,0 +1,124 @@
+use raylib::prelude::*;
+use crate::structures::stats::BaseModifiers;
+use crate::structures::card::Card;
+use crate::structures::assets::GameAssets;
+use crate::structures::score::format_mult;
+use crate::consts::*;
+use crate::layout;
+use crate::widgets;
+
+// Hover tooltips for the fight screen. Only one shows at a time (first hit wins).
+pub fn draw_tooltips(d: &mut RaylibDrawHandle, stats: &BaseModifiers, hand: &[Card], assets: &GameAssets) {
+    let font = &assets.font_main;
+    // Nothing pops up while something is being dragged around
+    if stats.dragging_relic.is_some() || hand.iter().any(|c| c.is_dragging) {
+        return;
+    }
+
+    for (i, relic) in stats.equipped_relics.iter().enumerate() {
+        let rect = layout::relic_slot(i);
+        if widgets::interact(d, rect).hovered {
+            let mut body = relic.data.description.clone();
+            if let Some(live) = relic.live_text() {
+                body = format!("{}\n{}", body, live);
+            }
+            widgets::tooltip(d, font, Vector2::new(rect.x, rect.y + rect.height), &relic.data.name, &body, NEU_ORANGE);
+            return;
+        }
+    }
+
+    for (i, item) in stats.consumables.iter().enumerate() {
+        let rect = layout::consumable_slot(i);
+        if widgets::interact(d, rect).hovered {
+            widgets::tooltip(d, font, Vector2::new(rect.x - 260.0, rect.y + rect.height), &item.name, &item.description, NEU_GREEN);
+            return;
+        }
+    }
+
+    for (i, rune) in stats.equipped_runes.iter().enumerate() {
+        let center = layout::equipped_rune_center(i);
+        if widgets::interact_circle(d, center, layout::EQUIPPED_RUNE_RADIUS).hovered {
+            widgets::tooltip(d, font, center, &rune.name, &rune.description, PARCHMENT);
+            return;
+        }
+    }
+
+    if !stats.active_abilities.is_empty() {
+        let tag = layout::boss_ability_tag();
+        if widgets::interact(d, tag).hovered {
+            let title = format!("{} ({})", stats.enemy_name, stats.ability_label());
+            let body = stats.active_abilities.iter().map(|a| a.description()).collect::<Vec<_>>().join("\n");
+            widgets::tooltip(d, font, Vector2::new(tag.x, tag.y + tag.height), &title, &body, NEU_RED);
+            return;
+        }
+    }
+
+    // Enhanced / sealed cards only; plain cards have nothing to explain
+    if let Some(card) = hand.iter().find(|c| c.is_hovered) {
+        let rect = layout::card_rect(card);
+        match (card.enhancement, card.seal) {
+            (Some(enh), Some(seal)) => {
+                let body = format!("{}\n{}: {}", enh.description(), seal.name(), seal.description());
+                widgets::tooltip(d, font, Vector2::new(rect.x, rect.y), enh.name(), &body, NEU_BLUE);
+            }
+            (Some(enh), None) => widgets::tooltip(d, font, Vector2::new(rect.x, rect.y), enh.name(), enh.description(), NEU_BLUE),
+            (None, Some(seal)) => widgets::tooltip(d, font, Vector2::new(rect.x, rect.y), seal.name(), seal.description(), seal.color()),
+            (None, None) => {}
+        }
+    }
+}
+
+// Right-click inspect panel: everything that modifies the card when it scores
+pub fn draw_inspect_panel(d: &mut RaylibDrawHandle, stats: &BaseModifiers, hand: &[Card], assets: &GameAssets) {
+    let Some(card) = stats.inspected_card.and_then(|id| hand.iter().find(|c| c.id == id)) else { return; };
+    let font = &assets.font_main;
+    let panel = layout::inspect_panel();
+
+    widgets::panel(d, panel, NEU_BLUE);
+    d.draw_text_ex(font, &card.display_name(), Vector2::new(panel.x + 20.0, panel.y + 15.0), 30.0, 1.0, PARCHMENT);
+    d.draw_text_ex(font, "MODIFIER STACK", Vector2::new(panel.x + 20.0, panel.y + 55.0), 18.0, 1.0, Color::GRAY);
+
+    let mut y = panel.y + 85.0;
+    for (label, value, color) in card_modifier_stack(card, stats) {
+        d.draw_text_ex(font, &label, Vector2::new(panel.x + 20.0, y), 20.0, 1.0, Color::WHITE);
+        let w = font.measure_text(&value, 20.0, 1.0).x;
+        d.draw_text_ex(font, &value, Vector2::new(panel.x + panel.width - 20.0 - w, y), 20.0, 1.0, color);
+        y += 28.0;
+    }
+
+    d.draw_text_ex(font, "Right-click to close", Vector2::new(panel.x + 20.0, panel.y + panel.height - 35.0), 18.0, 1.0, Color::GRAY);
+}
+
+// (label, value, colour) rows in the order they apply during scoring
+fn card_modifier_stack(card: &Card, stats: &BaseModifiers) -> Vec<(String, String, Color)> {
+    let mut rows = vec![("Base Chips".to_string(), format!("+{}", card.value), NEU_BLUE)];
+
+    if let Some(enh) = card.enhancement {
+        if enh.chips() != 0 {
+            rows.push((enh.name().to_string(), format!("+{} Chips", enh.chips()), NEU_BLUE));
+        }
+        if enh.mult() != 0.0 {
+            rows.push((enh.name().to_string(), format!("+{} Mult", format_mult(enh.mult())), NEU_RED));
+        }
+        if let Some(x) = enh.x_mult() {
+            rows.push((enh.name().to_string(), format!("X{} Mult", format_mult(x)), NEU_RED));
+        }
+    }
+
+    if let Some(seal) = card.seal {
+        rows.push((seal.name().to_string(), format!("Retrigger x{}", seal.retriggers()), seal.color()));
+    }
+
+    if stats.silenced_suit() == Some(card.suit) {
+        rows.push(("Boss: Silenced".to_string(), "No Flushes".to_string(), NEU_RED));
+    }
+
+    // Relics apply once per hand, after every card, left to right
+    for relic in &stats.equipped_relics {
+        if let Some(effect) = relic.effect_text() {
+            rows.push((relic.data.name.clone(), effect, NEU_ORANGE));
+        }
+    }
+
+    rows
+}
@@ -0,0 +1,199 @@
+// Keyboard / gamepad input map.
+//
+// Once per frame `poll()` turns key and pad presses into `Action`s using the bindings
+// stored in settings.json. Screens check `stats.actions.pressed(..)` right next to the
+// matching `widgets::clicked(..)`, so mouse, keyboard and pad all run the same code.
+use raylib::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Action {
+    Up,
+    Down,
+    Left,
+    Right,
+    Confirm, // Activate the focused widget / toggle the card under the cursor
+    Back,
+    Play,
+    Discard,
+    SortRank,
+    SortSuit,
+    Inspect,
+    Stats,
+}
+
+pub const ALL_ACTIONS: [Action; 12] = [
+    Action::Up, Action::Down, Action::Left, Action::Right,
+    Action::Confirm, Action::Back,
+    Action::Play, Action::Discard, Action::SortRank, Action::SortSuit,
+    Action::Inspect, Action::Stats,
+];
+
+impl Action {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Action::Up => "Up",
+            Action::Down => "Down",
+            Action::Left => "Left",
+            Action::Right => "Right",
+            Action::Confirm => "Select / Confirm",
+            Action::Back => "Back / Menu",
+            Action::Play => "Play Hand",
+            Action::Discard => "Discard",
+            Action::SortRank => "Sort by Rank",
+            Action::SortSuit => "Sort by Suit",
+            Action::Inspect => "Inspect Card",
+            Action::Stats => "Upgrade Stats",
+        }
+    }
+}
+
+// --- BINDINGS (persisted in settings.json) ---
+// Keys and pad buttons are stored by name ("P", "DPAD_LEFT") so the file stays hand-editable.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InputBindings {
+    pub keys: BTreeMap<Action, Vec<String>>,
+    pub pad: BTreeMap<Action, Vec<String>>,
+}
+
+impl Default for InputBindings {
+    fn default() -> Self {
+        let keys: [(Action, &[&str]); 12] = [
+            (Action::Up, &["UP"]),
+            (Action::Down, &["DOWN"]),
+            (Action::Left, &["LEFT"]),
+            (Action::Right, &["RIGHT"]),
+            (Action::Confirm, &["ENTER", "SPACE"]),
+            (Action::Back, &["ESCAPE"]),
+            (Action::Play, &["P"]),
+            (Action::Discard, &["D"]),
+            (Action::SortRank, &["R"]),
+            (Action::SortSuit, &["S"]),
+            (Action::Inspect, &["I"]),
+            (Action::Stats, &["U"]),
+        ];
+        let pad: [(Action, &[&str]); 12] = [
+            (Action::Up, &["DPAD_UP"]),
+            (Action::Down, &["DPAD_DOWN"]),
+            (Action::Left, &["DPAD_LEFT"]),
+            (Action::Right, &["DPAD_RIGHT"]),
+            (Action::Confirm, &["A"]),
+            (Action::Back, &["B", "START"]),
+            (Action::Play, &["Y"]),
+            (Action::Discard, &["X"]),
+            (Action::SortRank, &["LB"]),
+            (Action::SortSuit, &["RB"]),
+            (Action::Inspect, &["SELECT"]),
+            (Action::Stats, &["LT"]),
+        ];
+        let to_map = |list: &[(Action, &[&str])]| -> BTreeMap<Action, Vec<String>> {
+            list.iter().map(|(a, names)| (*a, names.iter().map(|n| n.to_string()).collect())).collect()
+        };
+        Self { keys: to_map(&keys), pad: to_map(&pad) }
+    }
+}
+
+impl InputBindings {
+    pub fn key_names(&self, action: Action) -> String {
+        self.keys.get(&action).map(|v| v.join(", ")).unwrap_or_default()
+    }
+
+    pub fn pad_names(&self, action: Action) -> String {
+        self.pad.get(&action).map(|v| v.join(", ")).unwrap_or_default()
+    }
+}
+
+const KEY_NAMES: [(&str, KeyboardKey); 46] = [
+    ("A", KeyboardKey::KEY_A), ("B", KeyboardKey::KEY_B), ("C", KeyboardKey::KEY_C), ("D", KeyboardKey::KEY_D),
+    ("E", KeyboardKey::KEY_E), ("F", KeyboardKey::KEY_F), ("G", KeyboardKey::KEY_G), ("H", KeyboardKey::KEY_H),
+    ("I", KeyboardKey::KEY_I), ("J", KeyboardKey::KEY_J), ("K", KeyboardKey::KEY_K), ("L", KeyboardKey::KEY_L),
+    ("M", KeyboardKey::KEY_M), ("N", KeyboardKey::KEY_N), ("O", KeyboardKey::KEY_O), ("P", KeyboardKey::KEY_P),
+    ("Q", KeyboardKey::KEY_Q), ("R", KeyboardKey::KEY_R), ("S", KeyboardKey::KEY_S), ("T", KeyboardKey::KEY_T),
+    ("U", KeyboardKey::KEY_U), ("V", KeyboardKey::KEY_V), ("W", KeyboardKey::KEY_W), ("X", KeyboardKey::KEY_X),
+    ("Y", KeyboardKey::KEY_Y), ("Z", KeyboardKey::KEY_Z),
+    ("0", KeyboardKey::KEY_ZERO), ("1", KeyboardKey::KEY_ONE), ("2", KeyboardKey::KEY_TWO), ("3", KeyboardKey::KEY_THREE),
+    ("4", KeyboardKey::KEY_FOUR), ("5", KeyboardKey::KEY_FIVE), ("6", KeyboardKey::KEY_SIX), ("7", KeyboardKey::KEY_SEVEN),
+    ("8", KeyboardKey::KEY_EIGHT), ("9", KeyboardKey::KEY_NINE),
+    ("UP", KeyboardKey::KEY_UP), ("DOWN", KeyboardKey::KEY_DOWN), ("LEFT", KeyboardKey::KEY_LEFT), ("RIGHT", KeyboardKey::KEY_RIGHT),
+    ("SPACE", KeyboardKey::KEY_SPACE), ("ENTER", KeyboardKey::KEY_ENTER), ("ESCAPE", KeyboardKey::KEY_ESCAPE),
+    ("TAB", KeyboardKey::KEY_TAB), ("BACKSPACE", KeyboardKey::KEY_BACKSPACE), ("LEFT_SHIFT", KeyboardKey::KEY_LEFT_SHIFT),
+];
+
+// Xbox-style names; raylib's "face" buttons map the same way on other pads
+const PAD_NAMES: [(&str, GamepadButton); 14] = [
+    ("DPAD_UP", GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_UP),
+    ("DPAD_DOWN", GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_DOWN),
+    ("DPAD_LEFT", GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_LEFT),
+    ("DPAD_RIGHT", GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_RIGHT),
+    ("A", GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_DOWN),
+    ("B", GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_RIGHT),
+    ("X", GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_LEFT),
+    ("Y", GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_UP),
+    ("LB", GamepadButton::GAMEPAD_BUTTON_LEFT_TRIGGER_1),
+    ("RB", GamepadButton::GAMEPAD_BUTTON_RIGHT_TRIGGER_1),
+    ("LT", GamepadButton::GAMEPAD_BUTTON_LEFT_TRIGGER_2),
+    ("RT", GamepadButton::GAMEPAD_BUTTON_RIGHT_TRIGGER_2),
+    ("SELECT", GamepadButton::GAMEPAD_BUTTON_MIDDLE_LEFT),
+    ("START", GamepadButton::GAMEPAD_BUTTON_MIDDLE_RIGHT),
+];
+
+const GAMEPAD: i32 = 0; // First connected pad only
+
+fn key_pressed(rl: &RaylibHandle, name: &str) -> bool {
+    KEY_NAMES.iter().any(|(n, key)| *n == name && rl.is_key_pressed(*key))
+}
+
+fn pad_pressed(rl: &RaylibHandle, name: &str) -> bool {
+    PAD_NAMES.iter().any(|(n, button)| *n == name && rl.is_gamepad_button_pressed(GAMEPAD, *button))
+}
+
+// First bindable key / pad button pressed this frame (used by the rebinding screen)
+pub fn pressed_key_name(rl: &RaylibHandle) -> Option<&'static str> {
+    KEY_NAMES.iter().find(|(_, key)| rl.is_key_pressed(*key)).map(|(n, _)| *n)
+}
+
+pub fn pressed_pad_name(rl: &RaylibHandle) -> Option<&'static str> {
+    if !rl.is_gamepad_available(GAMEPAD) { return None; }
+    PAD_NAMES.iter().find(|(_, button)| rl.is_gamepad_button_pressed(GAMEPAD, *button)).map(|(n, _)| *n)
+}
+
+// --- PER-FRAME STATE ---
+#[derive(Debug, Clone, Default)]
+pub struct ActionState {
+    pressed: Vec<Action>,
+    pub using_keys: bool, // Last input came from keys/pad, so screens draw a focus ring
+}
+
+impl ActionState {
+    pub fn pressed(&self, action: Action) -> bool {
+        self.pressed.contains(&action)
+    }
+
+    // Moves `index` through 0..count with the given prev/next actions, wrapping around
+    pub fn step(&self, index: &mut usize, count: usize, prev: Action, next: Action) {
+        if count == 0 { return; }
+        if self.pressed(prev) {
+            *index = (*index + count - 1) % count;
+        } else if self.pressed(next) {
+            *index = (*index + 1) % count;
+        }
+        *index = (*index).min(count - 1);
+    }
+}
+
+pub fn poll(rl: &RaylibHandle, bindings: &InputBindings, previous: &ActionState) -> ActionState {
+    let pad = rl.is_gamepad_available(GAMEPAD);
+    let pressed: Vec<Action> = ALL_ACTIONS.iter().copied().filter(|action| {
+        let by_key = bindings.keys.get(action).is_some_and(|names| names.iter().any(|n| key_pressed(rl, n)));
+        let by_pad = pad && bindings.pad.get(action).is_some_and(|names| names.iter().any(|n| pad_pressed(rl, n)));
+        by_key || by_pad
+    }).collect();
+
+    // Any mouse activity hands control back to the pointer
+    let mouse_used = rl.get_mouse_delta().length() > 0.0 || rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT);
+    let using_keys = if !pressed.is_empty() { true } else if mouse_used { false } else { previous.using_keys };
+
+    ActionState { pressed, using_keys }
+}
@@ -0,0 +1,497 @@
+// Single source of truth for screen geometry.
+//
+// The game is laid out on a fixed 1600x900 virtual canvas (see consts.rs). `Viewport`
+// scales that canvas into whatever window size we actually have, letterboxing the
+// leftover space, and maps the mouse back into canvas coordinates. Every rect below
+// is in canvas space and is used by BOTH the logic (hit-testing) and the drawing code,
+// so a hitbox can never drift away from what's on screen.
+use raylib::prelude::*;
+use crate::consts::*;
+use crate::structures::card::Card;
+use crate::structures::stats::RuneType;
+
+// --- VIEWPORT (virtual canvas -> window) ---
+#[derive(Debug, Clone, Copy)]
+pub struct Viewport {
+    pub scale: f32,
+    pub offset: Vector2, // Top-left of the canvas inside the window (letterbox bars)
+}
+
+impl Viewport {
+    pub fn from_window(width: i32, height: i32) -> Self {
+        let (w, h) = (width.max(1) as f32, height.max(1) as f32);
+        let scale = (w / SCREEN_WIDTH).min(h / SCREEN_HEIGHT);
+        let offset = Vector2::new((w - SCREEN_WIDTH * scale) / 2.0, (h - SCREEN_HEIGHT * scale) / 2.0);
+        Self { scale, offset }
+    }
+
+    pub fn current(rl: &RaylibHandle) -> Self {
+        Self::from_window(rl.get_screen_width(), rl.get_screen_height())
+    }
+
+    // Makes get_mouse_position() report canvas coordinates for the whole frame
+    pub fn apply_mouse_transform(&self, rl: &mut RaylibHandle) {
+        rl.set_mouse_offset(Vector2::new(-self.offset.x, -self.offset.y));
+        rl.set_mouse_scale(1.0 / self.scale, 1.0 / self.scale);
+    }
+
+    // Camera for static UI drawn in canvas space
+    pub fn camera(&self) -> Camera2D {
+        self.shake_camera(Vector2::zero(), 0.0)
+    }
+
+    // Same as camera() but with screen-shake applied on top
+    pub fn shake_camera(&self, shake_offset: Vector2, shake_rotation: f32) -> Camera2D {
+        Camera2D {
+            target: Vector2::zero(),
+            offset: self.offset + shake_offset * self.scale,
+            rotation: shake_rotation,
+            zoom: self.scale,
+        }
+    }
+
+    // Canvas rect -> window pixels (for scissor clipping)
+    pub fn to_screen(&self, rect: Rectangle) -> Rectangle {
+        Rectangle::new(
+            self.offset.x + rect.x * self.scale,
+            self.offset.y + rect.y * self.scale,
+            rect.width * self.scale,
+            rect.height * self.scale,
+        )
+    }
+
+    pub fn canvas_rect(&self) -> Rectangle {
+        Rectangle::new(0.0, 0.0, SCREEN_WIDTH, SCREEN_HEIGHT)
+    }
+}
+
+// --- GAME SCREEN ---
+pub fn player_panel() -> Rectangle {
+    Rectangle::new(P_PANEL_X, P_PANEL_Y, P_PANEL_W, P_PANEL_H)
+}
+
+pub fn action_panel() -> Rectangle {
+    Rectangle::new(A_PANEL_X, A_PANEL_Y, A_PANEL_W, A_PANEL_H)
+}
+
+pub fn play_button() -> Rectangle {
+    Rectangle::new(PLAY_BTN_POS.x, PLAY_BTN_POS.y, BTN_WIDTH, BTN_HEIGHT)
+}
+
+pub fn discard_button() -> Rectangle {
+    Rectangle::new(DISC_BTN_POS.x, DISC_BTN_POS.y, BTN_WIDTH, BTN_HEIGHT)
+}
+
+pub fn sort_rank_button() -> Rectangle {
+    Rectangle::new(SORT_RANK_POS.x, SORT_RANK_POS.y, SORT_BTN_WIDTH, SORT_BTN_HEIGHT)
+}
+
+pub fn sort_suit_button() -> Rectangle {
+    Rectangle::new(SORT_SUIT_POS.x, SORT_SUIT_POS.y, SORT_BTN_WIDTH, SORT_BTN_HEIGHT)
+}
+
+pub fn stats_button() -> Rectangle {
+    STATS_BTN_RECT
+}
+
+pub fn score_box() -> Rectangle {
+    let panel = action_panel();
+    let h = panel.height - (SCORE_BOX_Y - panel.y) - 15.0;
+    Rectangle::new(panel.x + 15.0, SCORE_BOX_Y, panel.width - 30.0, h)
+}
+
+pub fn chips_box() -> Rectangle {
+    Rectangle::new(A_PANEL_X + 25.0, SCORE_BOX_Y + 20.0, 110.0, 70.0)
+}
+
+pub fn mult_box() -> Rectangle {
+    let chips = chips_box();
+    Rectangle::new(chips.x + chips.width + 40.0, chips.y, chips.width, chips.height)
+}
+
+pub fn enemy_monitor() -> Rectangle {
+    let (w, h) = (260.0, 160.0);
+    Rectangle::new(ENEMY_CENTER_X - w / 2.0, ENEMY_Y, w, h)
+}
+
+pub fn relic_slot(index: usize) -> Rectangle {
+    Rectangle::new(RELIC_START_X + index as f32 * RELIC_SPACING, RELIC_START_Y, RELIC_SIZE, RELIC_SIZE)
+}
+
+// Relic slot a dragged relic would land in when dropped at canvas x
+pub fn relic_drop_index(x: f32, count: usize) -> usize {
+    let slot = ((x - RELIC_START_X - RELIC_SIZE / 2.0) / RELIC_SPACING).round();
+    (slot.max(0.0) as usize).min(count.saturating_sub(1))
+}
+
+// Equipped runes sit in a row under the relics during a fight
+pub const EQUIPPED_RUNE_RADIUS: f32 = 18.0;
+
+pub fn equipped_rune_center(index: usize) -> Vector2 {
+    let x = RELIC_START_X + EQUIPPED_RUNE_RADIUS + index as f32 * (EQUIPPED_RUNE_RADIUS * 2.0 + 12.0);
+    Vector2::new(x, RELIC_START_Y + RELIC_SIZE + 15.0 + EQUIPPED_RUNE_RADIUS)
+}
+
+// Consumables fill in from the top-right corner, mirroring the relic row
+pub fn consumable_slot(index: usize) -> Rectangle {
+    let x = SCREEN_WIDTH - RELIC_START_X - RELIC_SIZE - index as f32 * RELIC_SPACING;
+    Rectangle::new(x, RELIC_START_Y, RELIC_SIZE, RELIC_SIZE)
+}
+
+// The ability tag inside the enemy monitor (hover target for the boss tooltip)
+pub fn boss_ability_tag() -> Rectangle {
+    let monitor = enemy_monitor();
+    Rectangle::new(monitor.x + 15.0, monitor.y + 55.0, 120.0, 30.0)
+}
+
+pub fn inspect_panel() -> Rectangle {
+    Rectangle::new(P_PANEL_X, 170.0, 340.0, 400.0)
+}
+
+pub fn deck_pos() -> Vector2 {
+    Vector2::new(DECK_X, DECK_Y)
+}
+
+pub fn discard_pile_pos() -> Vector2 {
+    Vector2::new(DISCARD_X, DISCARD_Y)
+}
+
+// Where played card `index` of `count` lands in the fan above the hand
+pub fn play_slot(index: usize, count: usize) -> Vector2 {
+    let spacing = CARD_WIDTH * 1.2 + 10.0;
+    let start_x = SCREEN_WIDTH / 2.0 - ((count as f32 - 1.0) * spacing) / 2.0;
+    Vector2::new(start_x + index as f32 * spacing, PLAY_AREA_Y)
+}
+
+// Resting position of card `index` in a hand of `count` cards
+pub fn hand_slot(index: usize, count: usize) -> Vector2 {
+    let center_x = SCREEN_WIDTH / 2.0 + 50.0;
+    let spacing = 90.0;
+    let start_x = center_x - ((count as f32 - 1.0) * spacing) / 2.0;
+    Vector2::new(start_x + index as f32 * spacing, SCREEN_HEIGHT - 120.0)
+}
+
+// Hand slot a dragged card would land in when dropped at canvas x
+pub fn hand_drop_index(x: f32, count: usize) -> usize {
+    (0..count)
+        .min_by(|&a, &b| (hand_slot(a, count).x - x).abs().total_cmp(&(hand_slot(b, count).x - x).abs()))
+        .unwrap_or(0)
+}
+
+// Cards are drawn centred on current_pos
+pub fn card_rect(card: &Card) -> Rectangle {
+    let width = CARD_WIDTH * card.scale.x;
+    let height = CARD_HEIGHT * card.scale.y;
+    Rectangle::new(card.current_pos.x - width / 2.0, card.current_pos.y - height / 2.0, width, height)
+}
+
+// --- MENU ---
+pub const MENU_BTN_W: f32 = 300.0;
+pub const MENU_BTN_H: f32 = 60.0;
+
+pub fn menu_button(index: usize) -> Rectangle {
+    let y = MENU_BTN_START_Y + 150.0 + index as f32 * (MENU_BTN_H + MENU_BTN_GAP);
+    Rectangle::new(SCREEN_WIDTH / 2.0 - MENU_BTN_W / 2.0, y, MENU_BTN_W, MENU_BTN_H)
+}
+
+// --- RUN MAP ---
+// Rows run bottom (first fight) to top (boss)
+pub const MAP_NODE_RADIUS: f32 = 30.0;
+
+pub fn map_node_center(row: usize, col: usize, row_width: usize) -> Vector2 {
+    let spacing_x = 200.0;
+    let x = SCREEN_WIDTH / 2.0 + (col as f32 - (row_width as f32 - 1.0) / 2.0) * spacing_x;
+    Vector2::new(x, SCREEN_HEIGHT - 110.0 - row as f32 * 115.0)
+}
+
+// --- SETTINGS ---
+pub const SETTINGS_PANEL_W: f32 = 800.0;
+pub const SETTINGS_ROW_H: f32 = 50.0;
+pub const SETTINGS_START_Y: f32 = 170.0;
+
+pub fn settings_panel(rows: usize) -> Rectangle {
+    let h = rows as f32 * SETTINGS_ROW_H + 140.0;
+    Rectangle::new(SCREEN_WIDTH / 2.0 - SETTINGS_PANEL_W / 2.0, 60.0, SETTINGS_PANEL_W, h)
+}
+
+pub fn settings_arrows(row: usize) -> (Rectangle, Rectangle) {
+    let y = SETTINGS_START_Y + row as f32 * SETTINGS_ROW_H;
+    let right_edge = SCREEN_WIDTH / 2.0 + SETTINGS_PANEL_W / 2.0 - 40.0;
+    let left = Rectangle::new(right_edge - 260.0, y, 40.0, 40.0);
+    let right = Rectangle::new(right_edge - 40.0, y, 40.0, 40.0);
+    (left, right)
+}
+
+// Focus highlight for a whole settings row (label + arrows)
+pub fn settings_row(row: usize) -> Rectangle {
+    let y = SETTINGS_START_Y + row as f32 * SETTINGS_ROW_H;
+    Rectangle::new(SCREEN_WIDTH / 2.0 - SETTINGS_PANEL_W / 2.0 + 20.0, y - 3.0, SETTINGS_PANEL_W - 40.0, SETTINGS_ROW_H - 4.0)
+}
+
+pub fn settings_controls_button() -> Rectangle {
+    Rectangle::new(SCREEN_WIDTH / 2.0 - 220.0, SCREEN_HEIGHT - 130.0, 200.0, 60.0)
+}
+
+pub fn settings_back_button() -> Rectangle {
+    Rectangle::new(SCREEN_WIDTH / 2.0 + 20.0, SCREEN_HEIGHT - 130.0, 200.0, 60.0)
+}
+
+// --- CONTROLS (rebinding) ---
+pub const CONTROLS_ROW_H: f32 = 42.0;
+pub const CONTROLS_START_Y: f32 = 160.0;
+
+pub fn controls_row(row: usize) -> Rectangle {
+    let y = CONTROLS_START_Y + row as f32 * CONTROLS_ROW_H;
+    Rectangle::new(SCREEN_WIDTH / 2.0 - SETTINGS_PANEL_W / 2.0 + 20.0, y, SETTINGS_PANEL_W - 40.0, CONTROLS_ROW_H - 4.0)
+}
+
+pub fn controls_reset_button() -> Rectangle {
+    settings_controls_button()
+}
+
+pub fn controls_back_button() -> Rectangle {
+    settings_back_button()
+}
+
+// --- MODS ---
+// Mod list on the left, details of the focused mod on the right
+pub const MODS_ROW_H: f32 = 64.0;
+pub const MODS_START_Y: f32 = 170.0;
+
+pub fn mods_list_panel() -> Rectangle {
+    Rectangle::new(60.0, 60.0, 700.0, SCREEN_HEIGHT - 220.0)
+}
+
+pub fn mods_row(row: usize) -> Rectangle {
+    let panel = mods_list_panel();
+    Rectangle::new(panel.x + 20.0, MODS_START_Y + row as f32 * MODS_ROW_H, panel.width - 40.0, MODS_ROW_H - 8.0)
+}
+
+pub fn mods_toggle(row: usize) -> Rectangle {
+    let rect = mods_row(row);
+    Rectangle::new(rect.x + rect.width - 110.0, rect.y + 8.0, 100.0, rect.height - 16.0)
+}
+
+pub fn mods_details_panel() -> Rectangle {
+    let list = mods_list_panel();
+    Rectangle::new(list.x + list.width + 40.0, list.y, SCREEN_WIDTH - list.width - 160.0, list.height)
+}
+
+pub fn mods_back_button() -> Rectangle {
+    Rectangle::new(SCREEN_WIDTH / 2.0 - 100.0, SCREEN_HEIGHT - 130.0, 200.0, 60.0)
+}
+
+// --- CHALLENGE SELECT --- (same two-panel layout as the mods screen)
+pub const CHALLENGE_ROW_H: f32 = 64.0;
+pub const CHALLENGE_START_Y: f32 = 170.0;
+
+pub fn challenge_row(row: usize) -> Rectangle {
+    let panel = mods_list_panel();
+    Rectangle::new(panel.x + 20.0, CHALLENGE_START_Y + row as f32 * CHALLENGE_ROW_H, panel.width - 40.0, CHALLENGE_ROW_H - 8.0)
+}
+
+pub fn challenge_play_button() -> Rectangle {
+    let panel = mods_details_panel();
+    Rectangle::new(panel.x + panel.width / 2.0 - 110.0, panel.y + panel.height - 90.0, 220.0, 60.0)
+}
+
+pub fn challenge_back_button() -> Rectangle {
+    mods_back_button()
+}
+
+// --- DAILY --- (leaderboard on the left, the focused result or today's run on the right)
+pub const DAILY_ROW_H: f32 = 44.0;
+pub const DAILY_ROWS_SHOWN: usize = 12;
+
+pub fn daily_row(row: usize) -> Rectangle {
+    let panel = mods_list_panel();
+    Rectangle::new(panel.x + 20.0, CHALLENGE_START_Y + row as f32 * DAILY_ROW_H, panel.width - 40.0, DAILY_ROW_H - 6.0)
+}
+
+// Play / Paste Result, side by side at the bottom of the details panel
+pub fn daily_button(index: usize) -> Rectangle {
+    let panel = mods_details_panel();
+    let (w, gap) = (220.0, 30.0);
+    let left = panel.x + panel.width / 2.0 - w - gap / 2.0;
+    Rectangle::new(left + index as f32 * (w + gap), panel.y + panel.height - 90.0, w, 60.0)
+}
+
+pub fn daily_back_button() -> Rectangle {
+    mods_back_button()
+}
+
+// --- RUNE SELECT ---
+pub const RUNE_ROWS: [RuneType; 4] = [RuneType::Red, RuneType::Blue, RuneType::Green, RuneType::Minor];
+
+pub fn rune_row_y(row: usize) -> f32 {
+    RUNE_START_Y + row as f32 * RUNE_SPACING_Y
+}
+
+// Centre of rune `index` in a row holding `count` runes
+pub fn rune_center(row: usize, index: usize, count: usize) -> Vector2 {
+    let center_x = SCREEN_WIDTH / 2.0 + RUNE_CONTENT_OFFSET;
+    let row_width = (count as f32 - 1.0) * RUNE_SPACING_X;
+    let start_x = center_x - row_width / 2.0;
+    Vector2::new(start_x + index as f32 * RUNE_SPACING_X, rune_row_y(row))
+}
+
+pub fn rune_info_panel() -> Rectangle {
+    Rectangle::new(SCREEN_WIDTH / 2.0 - 60.0, 250.0, 320.0, 250.0)
+}
+
+pub fn rune_loadout_panel() -> Rectangle {
+    Rectangle::new(SCREEN_WIDTH - 420.0, 100.0, 380.0, SCREEN_HEIGHT - 200.0)
+}
+
+// Scrollable benefits / trade-offs list inside the loadout panel
+pub fn rune_loadout_list() -> Rectangle {
+    let panel = rune_loadout_panel();
+    Rectangle::new(panel.x + 20.0, panel.y + 70.0, panel.width - 40.0, panel.height - 90.0)
+}
+
+pub const RUNE_LOADOUT_ROW_H: f32 = 30.0;
+
+// Heirloom row along the bottom-left, under the rune rows
+pub const HEIRLOOM_RADIUS: f32 = 26.0;
+pub const HEIRLOOM_ROW_Y: f32 = SCREEN_HEIGHT - 85.0;
+
+pub fn heirloom_center(index: usize) -> Vector2 {
+    Vector2::new(80.0 + index as f32 * 70.0, HEIRLOOM_ROW_Y)
+}
+
+// Starting deck picker, between the rune rows and the loadout panel
+pub fn rune_deck_panel() -> Rectangle {
+    Rectangle::new(SCREEN_WIDTH / 2.0 - 40.0, 520.0, 380.0, 240.0)
+}
+
+// Previous (-1) / next (1) deck arrows either side of the name
+pub fn rune_deck_arrow(dir: i32) -> Rectangle {
+    let panel = rune_deck_panel();
+    let x = if dir < 0 { panel.x + 15.0 } else { panel.x + panel.width - 55.0 };
+    Rectangle::new(x, panel.y + 40.0, 40.0, 40.0)
+}
+
+// Preview card along the bottom of the picker, overlapping when there are lots
+pub fn rune_deck_card(index: usize, count: usize) -> Rectangle {
+    let panel = rune_deck_panel();
+    let h = 80.0;
+    let w = h * CARD_WIDTH / CARD_HEIGHT;
+    let avail = panel.width - 40.0 - w;
+    let step = if count > 1 { (avail / (count - 1) as f32).min(w + 4.0) } else { 0.0 };
+    Rectangle::new(panel.x + 20.0 + index as f32 * step, panel.y + panel.height - h - 15.0, w, h)
+}
+
+pub fn rune_start_button() -> Rectangle {
+    let (w, h) = (250.0, 70.0);
+    Rectangle::new(SCREEN_WIDTH / 2.0 + RUNE_CONTENT_OFFSET - w / 2.0, SCREEN_HEIGHT - 120.0, w, h)
+}
+
+// --- STATS MENU ---
+pub const STATS_MENU_W: f32 = 600.0;
+pub const STATS_MENU_H: f32 = 500.0;
+pub const STATS_ROW_H: f32 = 60.0;
+
+pub fn stats_menu_panel() -> Rectangle {
+    Rectangle::new(SCREEN_WIDTH / 2.0 - STATS_MENU_W / 2.0, SCREEN_HEIGHT / 2.0 - STATS_MENU_H / 2.0, STATS_MENU_W, STATS_MENU_H)
+}
+
+pub fn stats_menu_close_button() -> Rectangle {
+    let panel = stats_menu_panel();
+    Rectangle::new(panel.x + panel.width - 45.0, panel.y + 15.0, 30.0, 30.0)
+}
+
+pub fn stats_row_y(row: usize) -> f32 {
+    SCREEN_HEIGHT / 2.0 - 100.0 + row as f32 * STATS_ROW_H
+}
+
+pub fn stats_upgrade_button(row: usize) -> Rectangle {
+    Rectangle::new(SCREEN_WIDTH / 2.0 + 100.0, stats_row_y(row), 120.0, 40.0)
+}
+
+// --- BATTLE RESULT ---
+pub const BATTLE_RESULT_LINE_H: f32 = 30.0;
+
+pub fn battle_result_panel(y_offset: f32) -> Rectangle {
+    Rectangle::new(SCREEN_WIDTH / 2.0 - 460.0, SCREEN_HEIGHT / 2.0 - 300.0 + y_offset, 920.0, 600.0)
+}
+
+// 0 = fight stats, 1 = payout; rows past the bottom are cut off
+pub fn battle_result_column(index: usize, y_offset: f32) -> Rectangle {
+    let panel = battle_result_panel(y_offset);
+    let width = (panel.width - 150.0) / 2.0;
+    Rectangle::new(panel.x + 50.0 + index as f32 * (width + 50.0), panel.y + 95.0, width, panel.height - 210.0)
+}
+
+pub fn battle_result_next_button(y_offset: f32) -> Rectangle {
+    let (w, h) = (200.0, 60.0);
+    let panel = battle_result_panel(y_offset);
+    Rectangle::new(SCREEN_WIDTH / 2.0 + 20.0, panel.y + panel.height - h - 25.0, w, h)
+}
+
+pub fn battle_result_deck_button(y_offset: f32) -> Rectangle {
+    let next = battle_result_next_button(y_offset);
+    Rectangle::new(SCREEN_WIDTH / 2.0 - 20.0 - next.width, next.y, next.width, next.height)
+}
+
+// --- RUN END ---
+pub fn run_end_panel() -> Rectangle {
+    Rectangle::new(SCREEN_WIDTH / 2.0 - 400.0, 50.0, 800.0, SCREEN_HEIGHT - 100.0)
+}
+
+// Retry / New Run / Menu (plus Endless after a victory), side by side along the bottom
+pub fn run_end_button(index: usize, count: usize) -> Rectangle {
+    let panel = run_end_panel();
+    let (h, gap) = (60.0, 30.0);
+    let n = count.max(1) as f32;
+    let w = ((panel.width - 60.0 - (n - 1.0) * gap) / n).min(220.0);
+    let left = panel.x + (panel.width - (n * w + (n - 1.0) * gap)) / 2.0;
+    Rectangle::new(left + index as f32 * (w + gap), panel.y + panel.height - h - 30.0, w, h)
+}
+
+// --- DECK VIEW (overlay) ---
+pub fn deck_view_panel() -> Rectangle {
+    Rectangle::new(80.0, 60.0, SCREEN_WIDTH - 160.0, SCREEN_HEIGHT - 120.0)
+}
+
+// Card slot in the deck grid; cards shrink and overlap when a suit has lots of them
+pub fn deck_view_card(row: usize, col: usize, cols: usize) -> Rectangle {
+    let panel = deck_view_panel();
+    let h = 140.0;
+    let w = h * CARD_WIDTH / CARD_HEIGHT;
+    let avail = panel.width - 60.0 - w;
+    let step = if cols > 1 { (avail / (cols - 1) as f32).min(w + 8.0) } else { 0.0 };
+    Rectangle::new(panel.x + 30.0 + col as f32 * step, panel.y + 95.0 + row as f32 * (h + 32.0), w, h)
+}
+
+pub fn deck_view_close_button() -> Rectangle {
+    let panel = deck_view_panel();
+    let (w, h) = (180.0, 50.0);
+    Rectangle::new(panel.x + panel.width - w - 25.0, panel.y + 15.0, w, h)
+}
+
+// --- EVENT ---
+pub fn event_panel() -> Rectangle {
+    Rectangle::new(SCREEN_WIDTH / 2.0 - 450.0, 80.0, 900.0, SCREEN_HEIGHT - 160.0)
+}
+
+pub fn event_choice_button(index: usize) -> Rectangle {
+    let panel = event_panel();
+    Rectangle::new(panel.x + 60.0, 420.0 + index as f32 * 80.0, panel.width - 120.0, 60.0)
+}
+
+pub fn event_continue_button() -> Rectangle {
+    let (w, h) = (250.0, 60.0);
+    Rectangle::new(SCREEN_WIDTH / 2.0 - w / 2.0, SCREEN_HEIGHT - 190.0, w, h)
+}
+
+// --- SHOP ---
+pub fn shop_item(index: usize, y_offset: f32) -> Rectangle {
+    let x = SHOP_START_X + index as f32 * (SHOP_ITEM_W + SHOP_ITEM_GAP);
+    Rectangle::new(x, SHOP_START_Y + y_offset, SHOP_ITEM_W, SHOP_ITEM_H)
+}
+
+pub fn shop_leave_button(y_offset: f32) -> Rectangle {
+    let mut rect = SHOP_LEAVE_BTN_RECT;
+    rect.y += y_offset;
+    rect
+}
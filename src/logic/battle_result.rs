@@ -1,37 +1,206 @@
 use raylib::prelude::*;
 use crate::structures::stats::BaseModifiers;
 use crate::structures::state::GameState;
+use crate::structures::map::NodeKind;
+use crate::structures::score::Score;
+use crate::structures::economy::{self, FightOutcome, Ledger, PayoutInput};
 use crate::consts::*;
+use crate::layout;
+use crate::widgets;
+use crate::input::Action;
 
-// Logic to calculate rewards
-pub fn calculate_rewards(stats: &BaseModifiers) -> (i32, i32, i32) {
-    let base_money = 4;
-    let money_per_hand = 1;
+// Tally: rows appear one after another and their numbers count up
+pub const TALLY_ROW_DELAY: f32 = 0.2;
+pub const TALLY_COUNT_TIME: f32 = 0.45;
+const TALLY_DONE: f32 = 1.0e6; // result_timer after a skip
 
-    // Interest: $1 for every $5 held, capped at interest_cap/5
-    let interest = std::cmp::min(stats.money / 5, stats.interest_cap / 5);
-    let hands_bonus = stats.hands_remaining * money_per_hand;
+// XP for a win by node kind, plus a bit for every hand left over
+const XP_COMBAT: i32 = 20;
+const XP_ELITE: i32 = 35;
+const XP_BOSS: i32 = 60;
+const XP_PER_HAND_LEFT: i32 = 5;
+const XP_TARGET_STEP: i32 = 50; // Each level needs this much more than the last
 
-    (base_money, interest, hands_bonus)
+// Keyboard focus on the button row
+const FOCUS_NEXT: usize = 0;
+const FOCUS_DECK: usize = 1;
+
+// The run's side of the payout rules in structures::economy
+pub fn payout_input(stats: &BaseModifiers) -> PayoutInput {
+    let has_rune = |name: &str| stats.equipped_runes.iter().any(|r| r.name == name);
+    // Elites and bosses pay better
+    let base = match stats.run_map.current_kind() {
+        Some(NodeKind::Elite) => 6,
+        Some(NodeKind::Boss) => 8,
+        _ => 4,
+    };
+    PayoutInput {
+        base,
+        money: stats.money,
+        hands_left: stats.hands_remaining,
+        cards_discarded: stats.fight.cards_discarded,
+        interest_cap: stats.interest_cap,
+        investment: has_rune("Investment"),
+        wealth: has_rune("Wealth"),
+        midas: has_rune("Midas"),
+        recycler: stats.equipped_relics.iter().any(|r| r.data.id == "relic_recycler"),
+    }
+}
+
+// Itemized rewards for the fight just won (shown on the result screen, paid on Next)
+pub fn calculate_rewards(stats: &BaseModifiers) -> Ledger {
+    economy::payout(&payout_input(stats), FightOutcome::Won)
+}
+
+// Losing can cost money too (Midas); called as the run ends
+pub fn settle_loss(stats: &mut BaseModifiers) {
+    let ledger = economy::payout(&payout_input(stats), FightOutcome::Lost);
+    stats.money = ledger.apply(stats.money);
+}
+
+// The fight was just won: hand out XP and start the tally
+pub fn enter(stats: &mut BaseModifiers) {
+    let xp = match stats.run_map.current_kind() {
+        Some(NodeKind::Elite) => XP_ELITE,
+        Some(NodeKind::Boss) => XP_BOSS,
+        _ => XP_COMBAT,
+    } + stats.hands_remaining.max(0) * XP_PER_HAND_LEFT;
+
+    stats.xp += xp;
+    stats.fight.xp_gained = xp;
+    while stats.xp >= stats.xp_target {
+        stats.xp -= stats.xp_target;
+        stats.xp_target += XP_TARGET_STEP;
+        stats.level += 1;
+        stats.stat_points += 1;
+        stats.fight.levels_gained += 1;
+    }
+    stats.result_timer = 0.0;
+    stats.viewing_deck = false;
 }
 
-pub fn update_battle_result(rl: &mut RaylibHandle, state: &mut GameState, stats: &mut BaseModifiers) {
-    let mouse_pos = rl.get_mouse_position();
-    let clicked = rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT);
+// What a row's value counts up to
+#[derive(Debug, Clone)]
+pub enum Tally {
+    Text(String), // Shown as is
+    Score(Score),
+    Count(i32),
+    Times(i32),   // "x3"
+    Money(i32),   // "+$3" / "-$2"
+}
 
-    let btn_w = 200.0;
-    let btn_h = 60.0;
-    let btn_x = SCREEN_WIDTH / 2.0 - btn_w / 2.0;
-    let btn_y = SCREEN_HEIGHT / 2.0 + 100.0;
-    let next_btn = Rectangle::new(btn_x, btn_y, btn_w, btn_h);
+impl Tally {
+    // The value `progress` (0..1) of the way through counting up
+    pub fn show(&self, progress: f32) -> String {
+        let t = progress.clamp(0.0, 1.0) as f64;
+        let part = |n: i32| (n as f64 * t).round() as i32;
+        match self {
+            Tally::Text(text) => text.clone(),
+            Tally::Score(score) => format!("{}", Score::new(score.value() * t)),
+            Tally::Count(n) => format!("+{}", part(*n)),
+            Tally::Times(n) => format!("x{}", part(*n)),
+            Tally::Money(n) => format!("{}${}", if *n < 0 { "-" } else { "+" }, part(*n).abs()),
+        }
+    }
+}
+
+pub struct ResultRow {
+    pub label: String,
+    pub value: Tally,
+    pub color: Color,
+}
 
-    if clicked && next_btn.check_collision_point_rec(mouse_pos) {
-        // Apply Rewards
-        let (base, interest, hands) = calculate_rewards(stats);
-        stats.money += base + interest + hands;
+impl ResultRow {
+    fn new(label: impl Into<String>, value: Tally, color: Color) -> Self {
+        Self { label: label.into(), value, color }
+    }
+}
 
-        // Initialize Shop and Transition
-        crate::logic::shop::init_shop(stats);
-        *state = GameState::Shop;
+// Left column: how the fight went
+pub fn fight_rows(stats: &BaseModifiers) -> Vec<ResultRow> {
+    let mut rows = vec![
+        ResultRow::new("Defeated", Tally::Text(stats.enemy_name.clone()), NEU_RED),
+        ResultRow::new("Enemy HP", Tally::Score(stats.target_score), PARCHMENT),
+        ResultRow::new("Damage dealt", Tally::Score(stats.round_score), NEU_YELLOW),
+        ResultRow::new("Overkill", Tally::Score(stats.round_score.saturating_sub(stats.target_score)), NEU_ORANGE),
+    ];
+    if let Some((rank, total)) = stats.fight.best_hand {
+        rows.push(ResultRow::new(format!("Best hand ({})", rank.name()), Tally::Score(total), NEU_YELLOW));
+    }
+    let hands_used = stats.hands_per_fight - stats.hands_remaining;
+    let discards_used = stats.discards_per_fight - stats.discards_remaining;
+    rows.push(ResultRow::new("Hands used", Tally::Text(format!("{} / {}", hands_used, stats.hands_per_fight)), NEU_BLUE));
+    rows.push(ResultRow::new("Discards used", Tally::Text(format!("{} / {}", discards_used, stats.discards_per_fight)), NEU_RED));
+    rows.push(ResultRow::new("XP gained", Tally::Count(stats.fight.xp_gained), NEU_GREEN));
+    if stats.fight.levels_gained > 0 {
+        rows.push(ResultRow::new("Level up!", Tally::Text(format!("Level {}", stats.level)), NEU_GREEN));
     }
-}
\ No newline at end of file
+    for (name, count) in &stats.fight.relic_triggers {
+        rows.push(ResultRow::new(name.clone(), Tally::Times(*count as i32), NEU_ORANGE));
+    }
+    rows
+}
+
+// Right column: the payout ledger, then its total
+pub fn payout_rows(stats: &BaseModifiers) -> Vec<ResultRow> {
+    let ledger = calculate_rewards(stats);
+    let mut rows: Vec<ResultRow> = ledger.lines.iter()
+        .map(|line| ResultRow::new(line.label.clone(), Tally::Money(line.amount), if line.amount < 0 { NEU_RED } else { NEU_YELLOW }))
+        .collect();
+    rows.push(ResultRow::new("Total", Tally::Money(ledger.total()), NEU_YELLOW));
+    rows
+}
+
+// How far row `index` (counting both columns, fight rows first) has got: None = not shown yet
+pub fn tally_progress(stats: &BaseModifiers, index: usize) -> Option<f32> {
+    let elapsed = stats.result_timer - index as f32 * TALLY_ROW_DELAY;
+    (elapsed >= 0.0).then(|| elapsed / TALLY_COUNT_TIME)
+}
+
+fn tally_done(stats: &BaseModifiers) -> bool {
+    let rows = fight_rows(stats).len() + payout_rows(stats).len();
+    tally_progress(stats, rows - 1).is_some_and(|p| p >= 1.0)
+}
+
+pub fn update_battle_result(rl: &mut RaylibHandle, state: &mut GameState, stats: &mut BaseModifiers, dt: f32) {
+    stats.result_timer += dt * stats.settings.animation_speed;
+    let y_offset = stats.window_y_offset;
+    let confirm = stats.actions.pressed(Action::Confirm);
+
+    if stats.viewing_deck {
+        if confirm || stats.actions.pressed(Action::Back) || widgets::clicked(rl, layout::deck_view_close_button()) {
+            stats.viewing_deck = false;
+        }
+        return;
+    }
+
+    stats.actions.step(&mut stats.focus_col, FOCUS_DECK + 1, Action::Left, Action::Right);
+    if widgets::clicked(rl, layout::battle_result_deck_button(y_offset)) || (confirm && stats.focus_col == FOCUS_DECK) {
+        stats.viewing_deck = true;
+        return;
+    }
+
+    if widgets::clicked(rl, layout::battle_result_next_button(y_offset)) || (confirm && stats.focus_col == FOCUS_NEXT) {
+        // First press just finishes the tally
+        if !tally_done(stats) {
+            stats.result_timer = TALLY_DONE;
+            return;
+        }
+
+        collect(stats, state);
+    }
+}
+
+// Pays out and leaves: back to the map (a new one if that was the boss), unless that
+// was the last boss
+pub fn collect(stats: &mut BaseModifiers, state: &mut GameState) {
+    let ledger = calculate_rewards(stats);
+    stats.money = ledger.apply(stats.money);
+
+    if crate::logic::map::finish_fight(stats) {
+        crate::logic::run_end::end_run(stats, true);
+        *state = GameState::RunEnd;
+    } else {
+        *state = GameState::Map;
+    }
+}
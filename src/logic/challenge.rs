@@ -0,0 +1,93 @@
+// Challenge runs: picked on the challenge select screen (before rune select) and applied
+// when the run starts. The rules themselves are checked where they matter (map
+// generation, enemy picks) through stats.has_rule.
+use raylib::prelude::*;
+use crate::structures::stats::BaseModifiers;
+use crate::structures::state::GameState;
+use crate::structures::challenge::Challenge;
+use crate::logic::{effects, map};
+use crate::layout;
+use crate::widgets;
+use crate::input::Action;
+
+// Row 0 is the standard run, then every challenge sorted by id, then Back
+pub fn challenge_list(stats: &BaseModifiers) -> Vec<&Challenge> {
+    let mut list: Vec<&Challenge> = stats.all_challenges.values().collect();
+    list.sort_by(|a, b| a.id.cmp(&b.id));
+    list
+}
+
+pub fn focus_back(stats: &BaseModifiers) -> usize {
+    stats.all_challenges.len() + 1
+}
+
+// The challenge for a list row; None for the standard run
+pub fn challenge_at(stats: &BaseModifiers, row: usize) -> Option<Challenge> {
+    row.checked_sub(1).and_then(|i| challenge_list(stats).get(i).map(|c| (*c).clone()))
+}
+
+pub fn update_challenge_select(rl: &RaylibHandle, state: &mut GameState, stats: &mut BaseModifiers) {
+    let back_row = focus_back(stats);
+    stats.actions.step(&mut stats.focus_row, back_row + 1, Action::Up, Action::Down);
+    let confirm = stats.actions.pressed(Action::Confirm);
+
+    if stats.actions.pressed(Action::Back) || widgets::clicked(rl, layout::challenge_back_button()) || (confirm && stats.focus_row == back_row) {
+        *state = GameState::Menu;
+        return;
+    }
+
+    for row in 0..back_row {
+        if widgets::clicked(rl, layout::challenge_row(row)) {
+            stats.focus_row = row;
+        }
+    }
+    let focused = stats.focus_row.min(back_row - 1);
+    if widgets::clicked(rl, layout::challenge_play_button()) || (confirm && stats.focus_row < back_row) {
+        let challenge = challenge_at(stats, focused);
+        select(stats, challenge);
+        stats.focus_row = 0;
+        stats.focus_col = 0;
+        *state = GameState::RuneSelect;
+    }
+}
+
+// Remembers the pick and equips its fixed runes, if any. Either way it's not a daily
+// (logic::daily::begin marks that after this).
+pub fn select(stats: &mut BaseModifiers, challenge: Option<Challenge>) {
+    if let Some(forced) = challenge.as_ref().filter(|c| !c.runes.is_empty()).map(|c| &c.runes) {
+        stats.equipped_runes = forced.iter()
+            .filter_map(|id| stats.available_runes.iter().find(|r| r.id == *id).cloned())
+            .collect();
+    } else if stats.challenge.as_ref().is_some_and(|c| !c.runes.is_empty()) {
+        stats.equipped_runes.clear(); // Don't keep the last challenge's loadout
+    }
+    stats.challenge = challenge;
+    stats.daily = None;
+}
+
+// The loadout can't be changed on rune select
+pub fn runes_locked(stats: &BaseModifiers) -> bool {
+    stats.challenge.as_ref().is_some_and(|c| !c.runes.is_empty())
+}
+
+// Run start (after runes and heirlooms): deck, bans, stat changes and the map rules
+pub fn apply(stats: &mut BaseModifiers) {
+    let Some(challenge) = stats.challenge.clone() else { return; };
+    if !challenge.deck.is_standard() {
+        stats.owned_deck = challenge.deck.build();
+    }
+    stats.all_relics.retain(|id, _| challenge.allows_relic(id));
+    effects::apply_all(stats, &challenge.effects);
+    stats.run_map = map::generate_map(stats);
+}
+
+// Marks the challenge done in the profile; true the first time
+pub fn record_completion(stats: &mut BaseModifiers) -> bool {
+    let Some(id) = stats.challenge.as_ref().map(|c| c.id.clone()) else { return false; };
+    if stats.profile.completed(&id) {
+        return false;
+    }
+    stats.profile.completed_challenges.push(id);
+    stats.profile.save();
+    true
+}
@@ -0,0 +1,77 @@
+// Card choreography: deals, discards and the played-hand fan.
+//
+// Each function only sets up tweens (with a per-card delay for the stagger); the
+// AnimationState machine in game.rs waits on `settled()` before moving on, so input is
+// only held while the cards involved are actually in the air.
+use raylib::prelude::*;
+use crate::structures::card::Card;
+use crate::structures::tween::{Easing, Tween};
+use crate::layout;
+
+const DEAL_TIME: f32 = 0.35;
+const DEAL_STAGGER: f32 = 0.07;
+const DISCARD_TIME: f32 = 0.3;
+const DISCARD_STAGGER: f32 = 0.05;
+const PLAY_TIME: f32 = 0.35;
+const PLAY_STAGGER: f32 = 0.06;
+const PLAYED_SCALE: f32 = 1.2;
+
+// Newly drawn cards (by id) fly in from the deck to their slots, one after another
+pub fn deal(hand: &mut [Card], new_ids: &[i32]) {
+    let count = hand.len();
+    for (i, card) in hand.iter_mut().enumerate() {
+        let Some(n) = new_ids.iter().position(|id| *id == card.id) else { continue; };
+        card.current_pos = layout::deck_pos();
+        card.tween = Some(
+            Tween::new(layout::deck_pos(), layout::hand_slot(i, count), DEAL_TIME)
+                .with_delay(n as f32 * DEAL_STAGGER)
+                .with_easing(Easing::OutCubic)
+                .with_arc(60.0)
+                .with_scale(Vector2::new(0.6, 0.6), Vector2::new(1.0, 1.0))
+                .with_rotation(-0.4, 0.0),
+        );
+    }
+}
+
+// Selected cards spin off to the discard pile
+pub fn discard(hand: &mut [Card]) {
+    for (n, card) in hand.iter_mut().filter(|c| c.is_selected).enumerate() {
+        card.tween = Some(
+            Tween::new(card.current_pos, layout::discard_pile_pos(), DISCARD_TIME)
+                .with_delay(n as f32 * DISCARD_STAGGER)
+                .with_easing(Easing::OutCubic)
+                .with_arc(80.0)
+                .with_scale(card.scale, Vector2::new(0.6, 0.6))
+                .with_rotation(card.rotation, 0.5),
+        );
+    }
+}
+
+// Selected cards fan out across PLAY_AREA_Y in hand order, ready to be scored
+pub fn fan_played(hand: &mut [Card]) {
+    let count = hand.iter().filter(|c| c.is_selected).count();
+    let mid = (count as f32 - 1.0) / 2.0;
+    for (n, card) in hand.iter_mut().filter(|c| c.is_selected).enumerate() {
+        let tilt = (n as f32 - mid) * 0.05; // Slight fan
+        card.tween = Some(
+            Tween::new(card.current_pos, layout::play_slot(n, count), PLAY_TIME)
+                .with_delay(n as f32 * PLAY_STAGGER)
+                .with_easing(Easing::OutCubic)
+                .with_arc(40.0)
+                .with_scale(card.scale, Vector2::new(PLAYED_SCALE, PLAYED_SCALE))
+                .with_rotation(card.rotation, tilt),
+        );
+    }
+}
+
+// Played / discarded cards get their normal hand look back before returning to the deck
+pub fn reset_pose(card: &mut Card) {
+    card.scale = Vector2::new(1.0, 1.0);
+    card.rotation = 0.0;
+    card.tween = None;
+}
+
+// True once every selected card (or every card, when `selected_only` is false) has landed
+pub fn settled(hand: &[Card], selected_only: bool) -> bool {
+    hand.iter().filter(|c| !selected_only || c.is_selected).all(|c| c.tween.is_none())
+}
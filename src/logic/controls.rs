@@ -0,0 +1,64 @@
+use raylib::prelude::*;
+use crate::structures::stats::{self, BaseModifiers};
+use crate::structures::state::GameState;
+use crate::structures::bindings::{rebind, Rebind};
+use crate::input::{self, Action, InputBindings, ALL_ACTIONS};
+use crate::layout;
+use crate::widgets;
+use crate::consts::{NEU_RED, NEU_YELLOW};
+
+// Keyboard focus rows: every action, then the Reset and Back buttons
+pub const FOCUS_RESET: usize = ALL_ACTIONS.len();
+pub const FOCUS_BACK: usize = ALL_ACTIONS.len() + 1;
+
+fn finish_rebind(stats: &mut BaseModifiers, action: Action, name: &str, result: Rebind<Action>) {
+    stats.rebinding = None;
+    match result {
+        Rebind::Bound => stats.settings.save(),
+        Rebind::Swapped(other) => {
+            stats.settings.save();
+            let text = format!("{} took that binding; {} gets {}'s old one", action.label(), other.label(), action.label());
+            stats::push_toast(stats, text, NEU_YELLOW);
+        }
+        Rebind::Refused(other) => {
+            let text = format!("{} is {}'s, and {} has no binding to swap it for", name, other.label(), action.label());
+            stats::push_toast(stats, text, NEU_RED);
+        }
+    }
+}
+
+pub fn update_controls(rl: &RaylibHandle, state: &mut GameState, stats: &mut BaseModifiers) {
+    // Waiting for a key: the next bindable press wins; Back (or right-click) cancels
+    if let Some(action) = stats.rebinding {
+        if stats.actions.pressed(Action::Back) || rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_RIGHT) {
+            stats.rebinding = None;
+        } else if let Some(name) = input::pressed_key_name(rl) {
+            let result = rebind(&mut stats.settings.bindings.keys, action, name);
+            finish_rebind(stats, action, name, result);
+        } else if let Some(name) = input::pressed_pad_name(rl) {
+            let result = rebind(&mut stats.settings.bindings.pad, action, name);
+            finish_rebind(stats, action, name, result);
+        }
+        return;
+    }
+
+    stats.actions.step(&mut stats.focus_row, FOCUS_BACK + 1, Action::Up, Action::Down);
+    let confirm = stats.actions.pressed(Action::Confirm);
+
+    if stats.actions.pressed(Action::Back) || widgets::clicked(rl, layout::controls_back_button()) || (confirm && stats.focus_row == FOCUS_BACK) {
+        *state = GameState::Settings;
+        return;
+    }
+    if widgets::clicked(rl, layout::controls_reset_button()) || (confirm && stats.focus_row == FOCUS_RESET) {
+        stats.settings.bindings = InputBindings::default();
+        stats.settings.save();
+        return;
+    }
+
+    for (row, action) in ALL_ACTIONS.iter().enumerate() {
+        if widgets::clicked(rl, layout::controls_row(row)) || (confirm && stats.focus_row == row) {
+            stats.rebinding = Some(*action);
+            break;
+        }
+    }
+}
@@ -0,0 +1,276 @@
+// Daily runs: recording while playing, the daily screen (today's leaderboard, Play, Paste
+// Result) and the headless replay that checks a pasted result. The date / seed / result
+// string side lives in structures::daily.
+use raylib::prelude::*;
+use std::fs;
+use crate::structures::stats::{self, BaseModifiers, SortMode};
+use crate::structures::state::{GameState, AnimationState};
+use crate::structures::assets::{GameAssets, GameData};
+use crate::structures::card::Card;
+use crate::structures::daily::{self, DailyResult, DailyRun, Leaderboard, RunAction};
+use crate::structures::{data_loader, mods};
+use crate::structures::profile::Profile;
+use crate::structures::save::RunSave;
+use crate::structures::settings::Settings;
+use crate::logic::{battle_result, challenge, event, game, map, rune_select, shop, stats_menu};
+use crate::logic::stats_menu::STATS_FOCUS_CLOSE;
+use crate::consts::*;
+use crate::layout::{self, DAILY_ROWS_SHOWN};
+use crate::widgets;
+use crate::input::Action;
+
+// Keyboard focus rows after the leaderboard rows
+pub const FOCUS_PLAY: usize = 0;
+pub const FOCUS_PASTE: usize = 1;
+pub const FOCUS_BACK: usize = 2;
+
+// Notes a decision, if this is a daily run
+pub fn record(stats: &mut BaseModifiers, action: RunAction) {
+    if let Some(daily) = &mut stats.daily {
+        daily.log.push(action);
+    }
+}
+
+// Today's (or a retried) daily: no challenge, the date's seed, then rune select as usual
+pub fn begin(stats: &mut BaseModifiers, date: String) {
+    challenge::select(stats, None);
+    map::start_run(stats, daily::seed_for(&date));
+    stats.daily = Some(DailyRun { date, ..Default::default() });
+}
+
+// The shareable result of the run that just ended, if it was a daily
+pub fn result(stats: &BaseModifiers, victory: bool) -> Option<DailyResult> {
+    let daily = stats.daily.as_ref()?;
+    Some(DailyResult {
+        date: daily.date.clone(),
+        player: daily::player_name(&stats.settings.player_name),
+        score: stats.total_score,
+        ante: stats.ante,
+        victory,
+        deck: daily.deck.clone(),
+        runes: daily.runes.clone(),
+        relics: stats.equipped_relics.iter().map(|r| r.data.name.clone()).collect(),
+        mods: stats.active_mods.clone(),
+        log: daily.log.clone(),
+    })
+}
+
+// Run end: a daily goes on the local leaderboard (replays never get here, they don't
+// go through main's state changes)
+pub fn file_result(stats: &mut BaseModifiers) {
+    let Some(summary) = &mut stats.run_summary else { return; };
+    let Some(result) = summary.daily.clone() else { return; };
+    let mut board = Leaderboard::load();
+    summary.daily_rank = board.add(result, false);
+    board.save();
+    stats.daily_board = board;
+}
+
+// Screens that only wait for Next / Leave / Continue move on by themselves in a replay
+fn settle(stats: &mut BaseModifiers, state: &mut GameState) {
+    match *state {
+        GameState::BattleResult => battle_result::collect(stats, state),
+        GameState::Shop => shop::leave(stats, state),
+        GameState::Event if stats.event_result.is_some() => event::leave(stats, state),
+        _ => {}
+    }
+}
+
+// Selects exactly these cards; false if one isn't in the hand
+fn select_cards(hand: &mut [Card], ids: &[i32]) -> bool {
+    for card in hand.iter_mut() {
+        card.is_selected = ids.contains(&card.id);
+    }
+    ids.iter().all(|id| hand.iter().any(|c| c.id == *id))
+}
+
+// Plays the result's run again from its date, runes, deck and log, with no window and no
+// animations. Returns the finished run, or which action didn't fit.
+pub fn replay(data: &GameData, result: &DailyResult) -> Result<BaseModifiers, String> {
+    // A throwaway profile: the replay mustn't touch profile.json, and heirlooms are off anyway
+    let profile = Profile { detached: true, selected_deck: result.deck.clone(), ..Default::default() };
+    let mut stats = BaseModifiers::new_run(data, Settings::default(), profile, 0);
+    begin(&mut stats, result.date.clone());
+
+    if !result.deck.is_empty() && !stats.all_decks.contains_key(&result.deck) {
+        return Err(format!("unknown deck \"{}\"", result.deck));
+    }
+    for id in &result.runes {
+        let Some(rune) = stats.available_runes.iter().find(|r| r.id == *id).cloned() else {
+            return Err(format!("unknown rune \"{}\"", id));
+        };
+        if stats.equipped_runes.iter().any(|r| r.rune_type == rune.rune_type) {
+            return Err(format!("two runes from the same row (\"{}\")", id));
+        }
+        stats.equipped_runes.push(rune);
+    }
+    rune_select::begin_run(&mut stats);
+
+    let mut state = GameState::Map;
+    let (mut hand, mut deck) = (Vec::new(), Vec::new());
+    let mut animation_state = AnimationState::Idle;
+    for (n, action) in result.log.iter().enumerate() {
+        settle(&mut stats, &mut state);
+        let fail = |why: &str| format!("action {} ({}): {}", n + 1, action.encode(), why);
+        match (state, action) {
+            (GameState::Map, RunAction::Node(index)) => {
+                if !stats.run_map.available().contains(index) {
+                    return Err(fail("that node can't be reached"));
+                }
+                map::enter_node(*index, &mut state, &mut stats, &mut hand, &mut deck, &mut animation_state);
+            }
+            (GameState::Map, RunAction::Resume) => {
+                // Same as Continue from the menu: the run comes back from its save
+                let save = RunSave::capture(&stats);
+                stats = BaseModifiers::new_run(data, Settings::default(), stats.profile.clone(), 0);
+                save.restore(&mut stats);
+            }
+            (GameState::Playing, RunAction::Play(ids)) => {
+                if !select_cards(&mut hand, ids) || !game::can_play(&hand, &stats) {
+                    return Err(fail("those cards can't be played"));
+                }
+                game::score_selected(&hand, &mut stats);
+                game::skip_score_sequence(&mut stats);
+                game::land_score_hit(&mut stats);
+                game::finish_hand(&mut hand, &mut stats);
+                game::resolve_hand(&mut hand, &mut deck, &mut stats, &mut state);
+            }
+            (GameState::Playing, RunAction::Discard(ids)) => {
+                if !select_cards(&mut hand, ids) || !game::can_discard(&hand, &stats) {
+                    return Err(fail("those cards can't be discarded"));
+                }
+                game::discard_selected(&mut hand, &mut stats);
+                game::resolve_hand(&mut hand, &mut deck, &mut stats, &mut state);
+            }
+            (GameState::Playing, RunAction::SortRank) => game::set_sort(&mut hand, &mut stats, SortMode::Rank),
+            (GameState::Playing, RunAction::SortSuit) => game::set_sort(&mut hand, &mut stats, SortMode::Suit),
+            (GameState::Playing, RunAction::MoveCard(from, to)) if *from < hand.len() && *to < hand.len() => {
+                game::move_card(&mut hand, &mut stats, *from, *to);
+            }
+            (GameState::Playing, RunAction::MoveRelic(from, to)) if *from < stats.equipped_relics.len() && *to < stats.equipped_relics.len() => {
+                game::move_relic(&mut stats, *from, *to);
+            }
+            (GameState::Playing, RunAction::Upgrade(row)) if stats.stat_points > 0 && *row < STATS_FOCUS_CLOSE => {
+                stats_menu::upgrade(&mut stats, *row);
+            }
+            (GameState::Event, RunAction::Choose(i)) => {
+                let choice = stats.current_event.as_ref().and_then(|e| e.choices.get(*i)).cloned();
+                match choice {
+                    Some(choice) if event::can_choose(&stats, &choice) => event::choose(&mut stats, &choice),
+                    _ => return Err(fail("that choice isn't open")),
+                }
+            }
+            (GameState::RunEnd, _) => return Err(fail("the run is already over")),
+            _ => return Err(fail("doesn't fit the run at that point")),
+        }
+    }
+    settle(&mut stats, &mut state);
+    if state != GameState::RunEnd {
+        return Err("the log stops before the run ends".to_string());
+    }
+    Ok(stats)
+}
+
+// Parses a result string and replays it; Ok if the replay ends the same way
+pub fn verify(data: &GameData, text: &str) -> Result<DailyResult, String> {
+    let result = DailyResult::parse(text)?;
+    let lines = mods::mismatches(&result.mods, &mods::stamps(&data.mods));
+    if !lines.is_empty() {
+        return Err(format!("it needs the same mods:\n{}", lines.join("\n")));
+    }
+    let stats = replay(data, &result)?;
+    let Some(summary) = &stats.run_summary else {
+        return Err("the replay didn't finish".to_string());
+    };
+    if summary.total_score != result.score {
+        return Err(format!("it claims {} damage, the replay deals {}", result.score, summary.total_score));
+    }
+    if summary.ante != result.ante || summary.victory != result.victory {
+        let outcome = if summary.victory { "a victory" } else { "a defeat" };
+        return Err(format!("the replay ends in {} at ante {}", outcome, summary.ante));
+    }
+    if summary.relics != result.relics {
+        return Err(format!("the replay ends with relics: {}", summary.relics.join(", ")));
+    }
+    Ok(result)
+}
+
+// `--verify-daily <result or file>`: checks one result string, or a file with one per
+// line, without opening a window. Results that check out go on the leaderboard.
+// Returns the exit code.
+pub fn verify_cli(arg: Option<&str>) -> i32 {
+    let Some(arg) = arg else {
+        println!("Usage: --verify-daily \"<result>\" (or a file with one result per line)");
+        return 2;
+    };
+    let text = fs::read_to_string(arg).unwrap_or_else(|_| arg.to_string());
+    let settings = Settings::load();
+    let mut data = GameData::default();
+    data_loader::load_all_data(&mut data, &settings.disabled_mods, false);
+
+    let mut board = Leaderboard::load();
+    let mut failed = 0;
+    for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        match verify(&data, line) {
+            Ok(result) => {
+                let placed = match board.add(result.clone(), true) {
+                    Some(rank) => format!("#{} on {}", rank, result.date),
+                    None => "already on the leaderboard, or below its cut".to_string(),
+                };
+                println!("OK: {} {} ({})", result.player, result.score, placed);
+            }
+            Err(e) => {
+                failed += 1;
+                println!("FAILED: {}", e);
+            }
+        }
+    }
+    board.save();
+    if failed > 0 { 1 } else { 0 }
+}
+
+// Leaderboard rows on the screen: today's best few
+pub fn shown_entries(stats: &BaseModifiers) -> usize {
+    stats.daily_board.day(&daily::today()).len().min(DAILY_ROWS_SHOWN)
+}
+
+pub fn update_daily(rl: &RaylibHandle, state: &mut GameState, stats: &mut BaseModifiers, assets: &GameAssets) {
+    let rows = shown_entries(stats);
+    stats.actions.step(&mut stats.focus_row, rows + FOCUS_BACK + 1, Action::Up, Action::Down);
+    let confirm = stats.actions.pressed(Action::Confirm);
+    let focus = stats.focus_row;
+    let activated = |row: usize, rect: Rectangle| widgets::clicked(rl, rect) || (confirm && focus == rows + row);
+
+    if stats.actions.pressed(Action::Back) || activated(FOCUS_BACK, layout::daily_back_button()) {
+        *state = GameState::Menu;
+    } else if activated(FOCUS_PLAY, layout::daily_button(0)) {
+        begin(stats, daily::today());
+        stats.focus_row = 0;
+        stats.focus_col = 0;
+        *state = GameState::RuneSelect;
+    } else if activated(FOCUS_PASTE, layout::daily_button(1)) {
+        let text = rl.get_clipboard_text().unwrap_or_default();
+        paste_result(stats, &assets.data, &text);
+    } else if let Some(row) = (0..rows).find(|&row| widgets::clicked(rl, layout::daily_row(row))) {
+        stats.focus_row = row;
+    }
+}
+
+// Checks a result from the clipboard and files it, so a teammate's run shows up here too
+fn paste_result(stats: &mut BaseModifiers, data: &GameData, text: &str) {
+    let result = match verify(data, text) {
+        Ok(result) => result,
+        Err(e) => {
+            stats::push_toast(stats, format!("That result doesn't check out: {}", e), NEU_RED);
+            return;
+        }
+    };
+    let (player, date) = (result.player.clone(), result.date.clone());
+    match stats.daily_board.add(result, true) {
+        Some(rank) => {
+            stats.daily_board.save();
+            stats::push_toast(stats, format!("{}'s run checks out: #{} on {}", player, rank, date), NEU_GREEN);
+        }
+        None => stats::push_toast(stats, format!("{}'s run checks out, but it's already on the leaderboard (or below its cut)", player), NEU_YELLOW),
+    }
+}
@@ -0,0 +1,129 @@
+// Applies structures::effect::Effect to the run. Used by event choices and by heirlooms
+// at run start; consumables will go through here too once they can be used.
+use rand::Rng;
+use rand::seq::SliceRandom;
+use crate::structures::stats::BaseModifiers;
+use crate::structures::effect::Effect;
+use crate::structures::hand::HandRank;
+use crate::structures::card::{Card, SUIT_NAMES};
+use crate::structures::card_data::parse_card_name;
+use crate::structures::relic::GameRelic;
+use crate::consts::DECK_X;
+
+// Whether `effect` can be paid as a cost (money can't go negative, HP can't hit 0)
+pub fn can_afford(stats: &BaseModifiers, effect: &Effect) -> bool {
+    match effect {
+        Effect::LoseMoney { amount } => stats.money >= *amount,
+        Effect::LoseHp { amount } => stats.current_hp > *amount,
+        _ => true,
+    }
+}
+
+pub fn apply_all(stats: &mut BaseModifiers, effects: &[Effect]) -> Vec<String> {
+    effects.iter().flat_map(|e| apply(stats, e)).collect()
+}
+
+// Applies one effect and returns what happened, one line per change, for the UI
+pub fn apply(stats: &mut BaseModifiers, effect: &Effect) -> Vec<String> {
+    match effect {
+        Effect::GainMoney { amount } => {
+            stats.money += amount;
+            vec![format!("+${}", amount)]
+        }
+        Effect::LoseMoney { amount } => {
+            stats.money = (stats.money - amount).max(0);
+            vec![format!("-${}", amount)]
+        }
+        Effect::Heal { amount } => {
+            let before = stats.current_hp;
+            stats.current_hp = (stats.current_hp + amount).min(stats.max_hp);
+            vec![format!("+{} HP", stats.current_hp - before)]
+        }
+        Effect::LoseHp { amount } => {
+            // Events hurt but never finish the run on their own
+            stats.current_hp = (stats.current_hp - amount).max(1);
+            vec![format!("-{} HP", amount)]
+        }
+        Effect::GainRelic { relic } => {
+            let mut pool: Vec<&GameRelic> = stats.all_relics.values()
+                .filter(|r| relic.as_ref().is_none_or(|id| *id == r.data.id))
+                .filter(|r| !stats.equipped_relics.iter().any(|e| e.data.id == r.data.id))
+                .collect();
+            pool.sort_by(|a, b| a.data.id.cmp(&b.data.id)); // Deterministic for the seeded rng
+            match pool.choose(&mut stats.rng).map(|r| (*r).clone()) {
+                Some(relic) => {
+                    let line = format!("Found {}!", relic.data.name);
+                    stats.equipped_relics.push(relic);
+                    vec![line]
+                }
+                None => {
+                    stats.money += 10;
+                    vec!["Nothing new to find: +$10".to_string()]
+                }
+            }
+        }
+        Effect::LevelUp { target, chips, mult } => {
+            let rank = match target.as_deref().and_then(HandRank::from_name) {
+                Some(rank) => rank,
+                None => *HandRank::ALL.choose(&mut stats.rng).unwrap(),
+            };
+            let level = stats.hand_levels.entry(rank).or_default();
+            level.level += 1;
+            level.chips += chips;
+            level.mult += mult;
+            vec![format!("{} is now level {} (+{} Chips, +{} Mult)", rank.name(), level.level + 1, chips, mult)]
+        }
+        Effect::ConvertSuit { to, count } => {
+            let Some(suit) = SUIT_NAMES.iter().position(|s| s == to).map(|s| s as i32) else { return Vec::new(); };
+            let candidates: Vec<usize> = (0..stats.owned_deck.len()).filter(|&i| stats.owned_deck[i].suit != suit).collect();
+            let picked: Vec<usize> = candidates.choose_multiple(&mut stats.rng, *count as usize).copied().collect();
+            for &i in &picked {
+                stats.owned_deck[i].suit = suit;
+            }
+            vec![format!("{} cards became {}", picked.len(), to)]
+        }
+        Effect::ModifyCard { enhancement } => {
+            let candidates: Vec<usize> = (0..stats.owned_deck.len()).filter(|&i| stats.owned_deck[i].enhancement.is_none()).collect();
+            match candidates.choose(&mut stats.rng) {
+                Some(&i) => {
+                    stats.owned_deck[i].enhancement = Some(*enhancement);
+                    vec![format!("{} became a {}", stats.owned_deck[i].display_name(), enhancement.name())]
+                }
+                None => vec!["Every card is already enhanced".to_string()],
+            }
+        }
+        Effect::AddSeal { seal } => {
+            let candidates: Vec<usize> = (0..stats.owned_deck.len()).filter(|&i| stats.owned_deck[i].seal.is_none()).collect();
+            match candidates.choose(&mut stats.rng) {
+                Some(&i) => {
+                    stats.owned_deck[i].seal = Some(*seal);
+                    vec![format!("{} got a {}", stats.owned_deck[i].display_name(), seal.name())]
+                }
+                None => vec!["Every card is already sealed".to_string()],
+            }
+        }
+        Effect::CreateCard { card } => {
+            let Some((suit, value)) = parse_card_name(card) else { return Vec::new(); };
+            let id = stats.owned_deck.iter().map(|c| c.id).max().unwrap_or(-1) + 1;
+            let mut new_card = Card::new(id, DECK_X);
+            new_card.suit = suit;
+            new_card.value = value;
+            stats.owned_deck.push(new_card);
+            vec![format!("{} added to your deck", card)]
+        }
+        Effect::ModifyPlayerStats { stat, amount } => {
+            let label = stats.modify_stat(*stat, *amount);
+            vec![format!("{} {:+}", label, amount)]
+        }
+        Effect::D20 { outcomes } => {
+            let roll = stats.rng.gen_range(1..=20);
+            let mut lines = vec![format!("Rolled a {}", roll)];
+            if let Some(outcome) = outcomes.iter().find(|o| (o.min..=o.max).contains(&roll)) {
+                lines.push(outcome.text.clone());
+                lines.extend(apply_all(stats, &outcome.effects));
+            }
+            lines
+        }
+        Effect::None => Vec::new(),
+    }
+}
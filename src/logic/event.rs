@@ -0,0 +1,67 @@
+use raylib::prelude::*;
+use rand::seq::SliceRandom;
+use crate::structures::stats::BaseModifiers;
+use crate::structures::state::GameState;
+use crate::structures::event::{EventChoice, EventData};
+use crate::logic::{daily, effects};
+use crate::structures::daily::RunAction;
+use crate::layout;
+use crate::widgets;
+use crate::input::Action;
+
+// Rolls the event for a map node. Returns false if there are none loaded.
+pub fn start_event(stats: &mut BaseModifiers) -> bool {
+    let mut pool: Vec<&EventData> = stats.all_events.values().collect();
+    pool.sort_by(|a, b| a.id.cmp(&b.id)); // Deterministic for the seeded rng
+    let Some(event) = pool.choose(&mut stats.rng).map(|e| (*e).clone()) else { return false; };
+    stats.current_event = Some(event);
+    stats.event_result = None;
+    true
+}
+
+pub fn can_choose(stats: &BaseModifiers, choice: &EventChoice) -> bool {
+    choice.costs.iter().all(|cost| effects::can_afford(stats, cost))
+}
+
+pub fn update_event(rl: &RaylibHandle, state: &mut GameState, stats: &mut BaseModifiers) {
+    let Some(event) = stats.current_event.clone() else {
+        *state = GameState::Map;
+        return;
+    };
+
+    // Result shown: Continue goes back to the map
+    if stats.event_result.is_some() {
+        if widgets::clicked(rl, layout::event_continue_button()) || stats.actions.pressed(Action::Confirm) {
+            leave(stats, state);
+        }
+        return;
+    }
+
+    stats.actions.step(&mut stats.focus_row, event.choices.len(), Action::Up, Action::Down);
+    let confirm = stats.actions.pressed(Action::Confirm);
+
+    for (i, choice) in event.choices.iter().enumerate() {
+        let picked = widgets::clicked(rl, layout::event_choice_button(i)) || (confirm && stats.focus_row == i);
+        if picked && can_choose(stats, choice) {
+            daily::record(stats, RunAction::Choose(i));
+            choose(stats, choice);
+            break;
+        }
+    }
+}
+
+// Pays the choice's costs and applies its outcomes; the log is shown until Continue
+pub fn choose(stats: &mut BaseModifiers, choice: &EventChoice) {
+    let mut log = effects::apply_all(stats, &choice.costs);
+    log.extend(effects::apply_all(stats, &choice.outcomes));
+    if log.is_empty() {
+        log.push("Nothing happens.".to_string());
+    }
+    stats.event_result = Some(log);
+}
+
+pub fn leave(stats: &mut BaseModifiers, state: &mut GameState) {
+    stats.current_event = None;
+    stats.event_result = None;
+    *state = GameState::Map;
+}
@@ -1,12 +1,27 @@
+use std::collections::HashMap;
+use rand::rngs::StdRng;
 use crate::poker;
 use raylib::prelude::*;
-use crate::structures::stats::{self, BaseModifiers, BossAbility, SortMode};
+use crate::structures::stats::{self, BaseModifiers, FightRecord, SortMode};
+use crate::structures::enemy::BossAbility;
 use crate::structures::card::Card;
 use crate::structures::state::{GameState, AnimationState};
 use crate::consts::*;
-use crate::score_manager;
-use crate::structures::relic::{GameRelic, RelicEffect};
+use crate::score_manager::{self, ScoreSource};
+use crate::structures::score::{Score, format_mult};
+use crate::structures::relic::{Relic, ScoringContext};
+use crate::structures::map::NodeKind;
+use crate::structures::enemy::{Enemy, BOSS_TIER};
+use crate::structures::endless;
+use crate::structures::challenge::ChallengeRule;
 use crate::logic::metrics::GameMetrics;
+use crate::layout;
+use crate::logic::choreography;
+use crate::logic::relics::{self, Lifecycle};
+use crate::logic::daily;
+use crate::structures::daily::RunAction;
+use crate::widgets;
+use crate::input::Action;
 
 pub fn update_game(rl: &RaylibHandle, hand: &mut Vec<Card>, deck: &mut Vec<Card>, stats: &mut BaseModifiers, dt: f32, state: &mut GameState, animation_state: &mut AnimationState) {
 
@@ -22,28 +37,18 @@ pub fn update_game(rl: &RaylibHandle, hand: &mut Vec<Card>, deck: &mut Vec<Card>
         return;
     }
 
-    let mouse_pos = rl.get_mouse_position();
     let mouse_pressed = rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT);
 
-    let play_btn = Rectangle::new(PLAY_BTN_POS.x, PLAY_BTN_POS.y, BTN_WIDTH, BTN_HEIGHT);
-    let discard_btn = Rectangle::new(DISC_BTN_POS.x, DISC_BTN_POS.y, BTN_WIDTH, BTN_HEIGHT);
-    let sort_rank_btn = Rectangle::new(SORT_RANK_POS.x, SORT_RANK_POS.y, SORT_BTN_WIDTH, SORT_BTN_HEIGHT);
-    let sort_suit_btn = Rectangle::new(SORT_SUIT_POS.x, SORT_SUIT_POS.y, SORT_BTN_WIDTH, SORT_BTN_HEIGHT);
-
     // Update Tweens
     for card in hand.iter_mut() {
-        if let Some(tween) = &mut card.tween {
-            tween.update(dt);
-            card.current_pos = tween.solve();
-            if tween.is_finished() {
-                card.current_pos = tween.end;
-                card.tween = None;
-            }
-        }
+        card.update_tween(dt);
     }
 
     match animation_state {
-        AnimationState::Idle => {
+        // Cards can still be picked and sorted while a deal is in flight; only
+        // Play / Discard wait for it to land.
+        AnimationState::Idle | AnimationState::Dealing => {
+            let dealing = *animation_state == AnimationState::Dealing;
             // Hand Helper
             // FIX: Clone cards to pass a slice of structs, not references to references
             let selected_cards: Vec<Card> = hand.iter().filter(|c| c.is_selected).cloned().collect();
@@ -56,96 +61,92 @@ pub fn update_game(rl: &RaylibHandle, hand: &mut Vec<Card>, deck: &mut Vec<Card>
 
             update_card_interaction(rl, hand, stats);
 
-            if mouse_pressed {
-                let selected_count = hand.iter().filter(|c| c.is_selected).count();
-
-                if play_btn.check_collision_point_rec(mouse_pos) && stats.hands_remaining > 0 && selected_count > 0 && selected_count <= 5 {
-                    for card in hand.iter_mut().filter(|c| c.is_selected) {
-                        card.move_to(Vector2::new(SCREEN_WIDTH / 2.0, SCREEN_HEIGHT / 2.0), 0.4);
-                        card.scale = Vector2::new(1.2, 1.2);
-                    }
-                    *animation_state = AnimationState::PlayingAnimation;
-                    stats.score_timer = 0.6;
-                    stats.input_consumed = true;
-                }
+            // Mouse buttons and their keyboard / pad hotkeys trigger the same actions
+            let actions = &stats.actions;
+            let play = widgets::clicked(rl, layout::play_button()) || actions.pressed(Action::Play);
+            let discard = widgets::clicked(rl, layout::discard_button()) || actions.pressed(Action::Discard);
+            let sort_rank = widgets::clicked(rl, layout::sort_rank_button()) || actions.pressed(Action::SortRank);
+            let sort_suit = widgets::clicked(rl, layout::sort_suit_button()) || actions.pressed(Action::SortSuit);
+            let open_stats = widgets::clicked(rl, layout::stats_button()) || actions.pressed(Action::Stats);
 
-                if discard_btn.check_collision_point_rec(mouse_pos) && stats.discards_remaining > 0 && selected_count > 0 && selected_count <= 5 {
-                    stats.discards_remaining -= 1;
-                    stats.game_metrics.log_discard(selected_count);
-                    *animation_state = AnimationState::Discarding;
-                    stats.discard_index = 0;
-                }
+            if play && !dealing && can_play(hand, stats) {
+                daily::record(stats, RunAction::Play(selected_ids(hand)));
+                choreography::fan_played(hand);
+                *animation_state = AnimationState::PlayingAnimation;
+                stats.input_consumed = true;
+            } else if discard && !dealing && can_discard(hand, stats) {
+                daily::record(stats, RunAction::Discard(selected_ids(hand)));
+                discard_selected(hand, stats);
+                *animation_state = AnimationState::Discarding;
+            }
 
-                if sort_rank_btn.check_collision_point_rec(mouse_pos) {
-                    stats.current_sort = SortMode::Rank;
-                    sort_hand(hand, SortMode::Rank);
-                }
-                if sort_suit_btn.check_collision_point_rec(mouse_pos) {
-                    stats.current_sort = SortMode::Suit;
-                    sort_hand(hand, SortMode::Suit);
-                }
+            if sort_rank {
+                daily::record(stats, RunAction::SortRank);
+                set_sort(hand, stats, SortMode::Rank);
+            }
+            if sort_suit {
+                daily::record(stats, RunAction::SortSuit);
+                set_sort(hand, stats, SortMode::Suit);
+            }
+            if open_stats {
+                *state = GameState::StatsMenu;
+            }
+
+            // Play / Discard just handed the selected cards to the choreography
+            if matches!(*animation_state, AnimationState::Idle | AnimationState::Dealing) {
+                update_hand_positions(hand);
+            }
+            if dealing && choreography::settled(hand, false) {
+                *animation_state = AnimationState::Idle;
             }
-            update_hand_positions(hand);
         },
 
         AnimationState::PlayingAnimation => {
-            stats.score_timer -= dt;
+            // Score once the whole hand has fanned out over the play area
+            if choreography::settled(hand, true) {
+                score_selected(hand, stats);
+                *animation_state = AnimationState::ScoringSeq;
+            }
+        },
 
-            if stats.score_timer <= 0.0 {
-                let selected_cards: Vec<Card> = hand.iter().filter(|c| c.is_selected).cloned().collect();
-                let rank = poker::get_hand_rank(&selected_cards, stats);
-                let game_relics = stats.equipped_relics.clone();
-
-                let (base_chips, base_mult) = poker::get_hand_base_score(rank);
-                let result = score_manager::calculate_score(&selected_cards, &game_relics, base_chips, base_mult);
-
-                stats.total_score += result.total;
-                stats.round_score += result.total;
-                stats.display_score += result.total as f32;
-                stats.game_metrics.log_play(result.total);
-
-                // FIX: Replace drain_filter (unstable) with stable retain logic
-                // Move played cards to deck
-                let mut i = 0;
-                while i < hand.len() {
-                    if hand[i].is_selected {
-                        let mut c = hand.remove(i);
-                        c.is_selected = false; // Reset state
-                        deck.push(c);
-                    } else {
-                        i += 1;
-                    }
-                }
+        AnimationState::ScoringSeq => {
+            let step_count = stats.score_sequence.as_ref().map_or(0, |r| r.breakdown.len());
 
-                stats.hands_remaining -= 1;
+            let skip_pressed = mouse_pressed || stats.actions.pressed(Action::Confirm);
+            let skipped = skip_pressed && stats.settings.skip_scoring_on_click;
 
-                if stats.round_score >= stats.target_score {
-                    *state = GameState::BattleResult;
-                } else if stats.hands_remaining == 0 {
-                    *state = GameState::GameOver;
-                } else {
-                    refill_hand(hand, deck, stats);
+            if skipped {
+                skip_score_sequence(stats);
+            } else {
+                stats.score_timer -= dt * stats.settings.animation_speed;
+                if stats.score_timer <= 0.0 && stats.score_index < step_count {
+                    play_score_step(hand, stats);
+                    stats.score_index += 1;
+                    stats.score_timer = stats.score_delay;
                 }
+            }
 
-                *animation_state = AnimationState::Idle;
+            if stats.score_index >= step_count && (stats.score_timer <= 0.0 || skipped) {
+                land_score_hit(stats);
+                stats.score_timer = SCORE_HIT_HOLD;
+                *animation_state = AnimationState::Scoring;
+            }
+        },
+
+        AnimationState::Scoring => {
+            stats.score_timer -= dt * stats.settings.animation_speed;
+            let skip_pressed = mouse_pressed || stats.actions.pressed(Action::Confirm);
+            if stats.score_timer <= 0.0 || (skip_pressed && stats.settings.skip_scoring_on_click) {
+                // Played cards head to the discard pile; Discarding finishes the hand
+                finish_hand(hand, stats);
+                *animation_state = AnimationState::Discarding;
                 stats.input_consumed = false;
             }
         },
 
         AnimationState::Discarding => {
-            // FIX: Stable discard logic
-            let mut i = 0;
-            while i < hand.len() {
-                if hand[i].is_selected {
-                    let mut c = hand.remove(i);
-                    c.is_selected = false;
-                    deck.push(c);
-                } else {
-                    i += 1;
-                }
-            }
-            refill_hand(hand, deck, stats);
-            *animation_state = AnimationState::Idle;
+            if !choreography::settled(hand, true) { return; }
+            *animation_state = resolve_hand(hand, deck, stats, state);
         },
 
         _ => {}
@@ -153,99 +154,457 @@ pub fn update_game(rl: &RaylibHandle, hand: &mut Vec<Card>, deck: &mut Vec<Card>
 }
 
 // Helpers
+const SCORE_STEP_DELAY: f32 = 0.35;
+const SCORE_HIT_HOLD: f32 = 0.6;
+
+// The steps below change the run; the animation states above only pace them. Daily
+// replays (logic::daily) call them back to back.
+
+pub fn selected_ids(hand: &[Card]) -> Vec<i32> {
+    hand.iter().filter(|c| c.is_selected).map(|c| c.id).collect()
+}
+
+pub fn can_play(hand: &[Card], stats: &BaseModifiers) -> bool {
+    let selected = hand.iter().filter(|c| c.is_selected).count();
+    stats.hands_remaining > 0 && (1..=5).contains(&selected)
+}
+
+pub fn can_discard(hand: &[Card], stats: &BaseModifiers) -> bool {
+    let selected = hand.iter().filter(|c| c.is_selected).count();
+    stats.discards_remaining > 0 && (1..=5).contains(&selected) && can_pay_discard(stats)
+}
+
+pub fn set_sort(hand: &mut [Card], stats: &mut BaseModifiers, mode: SortMode) {
+    stats.current_sort = mode;
+    sort_hand(hand, mode);
+}
+
+// Dragging a card along the hand
+pub fn move_card(hand: &mut Vec<Card>, stats: &mut BaseModifiers, from: usize, to: usize) {
+    let card = hand.remove(from);
+    hand.insert(to, card);
+    stats.current_sort = SortMode::Manual;
+}
+
+// Scores the selected cards and queues the breakdown for the sequencer
+pub fn score_selected(hand: &[Card], stats: &mut BaseModifiers) {
+    let (selected_cards, held_cards): (Vec<Card>, Vec<Card>) = hand.iter().cloned().partition(|c| c.is_selected);
+    let rank = poker::get_hand_rank(&selected_cards, stats);
+
+    // Levels from books / events stack on top of the hand's base values
+    let (base_chips, base_mult) = poker::get_hand_base_score(rank);
+    let level = stats.hand_levels.get(&rank).copied().unwrap_or_default();
+    let (base_chips, base_mult) = (base_chips + level.chips, base_mult + level.mult);
+
+    // Relics come out of stats while they score so their hooks can update them
+    let mut relics = std::mem::take(&mut stats.equipped_relics);
+    let context = ScoringContext::new(&selected_cards, &held_cards, hand, Some(rank), base_chips, base_mult, stats);
+    let result = score_manager::calculate_score(&mut relics, context);
+    stats.equipped_relics = relics;
+    stats.game_metrics.log_triggers(&result.queue);
+    stats.fight.record_hand(rank, result.total);
+    if stats.best_hand.is_none_or(|(_, best)| result.total > best) {
+        stats.best_hand = Some((rank, result.total));
+    }
+    for step in &result.breakdown {
+        if let ScoreSource::Relic(i) = step.source && let Some(relic) = stats.equipped_relics.get(i) {
+            stats.fight.record_relic(&relic.data.name);
+        }
+    }
+
+    // Start the sequencer from the hand's base values
+    stats.chips = result.base_chips;
+    stats.mult = result.base_mult;
+    stats.chips_pulse = 1.0;
+    stats.mult_pulse = 1.0;
+    stats.score_sequence = Some(result);
+    stats.score_index = 0;
+    stats.score_delay = SCORE_STEP_DELAY;
+    stats.score_timer = SCORE_STEP_DELAY;
+}
+
+// Jump straight to the final chips/mult
+pub fn skip_score_sequence(stats: &mut BaseModifiers) {
+    if let Some(result) = &stats.score_sequence {
+        stats.chips = result.chips;
+        stats.mult = result.mult;
+        stats.score_index = result.breakdown.len();
+    }
+}
+
+// After the hit lands: the hand is spent and the enemy hits back if it's still standing
+pub fn finish_hand(hand: &mut [Card], stats: &mut BaseModifiers) {
+    stats.hands_remaining -= 1;
+    stats.score_sequence = None;
+    run_relic_hooks(hand, stats, true);
+    if stats.round_score < stats.target_score {
+        enemy_strikes(stats);
+    }
+    choreography::discard(hand);
+}
+
+pub fn discard_selected(hand: &mut [Card], stats: &mut BaseModifiers) {
+    let selected_count = hand.iter().filter(|c| c.is_selected).count();
+    stats.discards_remaining -= 1;
+    if stats.has_ability(&BossAbility::PayToDiscard) {
+        stats.money -= 1;
+    }
+    stats.game_metrics.log_discard(selected_count);
+    stats.fight.cards_discarded += selected_count as i32;
+    run_relic_hooks(hand, stats, false);
+    choreography::discard(hand);
+    stats.discard_index = 0;
+}
+
+// The played / discarded cards go back under the deck, then the fight is won, lost or
+// the hand refills. Returns the animation state to carry on in.
+pub fn resolve_hand(hand: &mut Vec<Card>, deck: &mut Vec<Card>, stats: &mut BaseModifiers, state: &mut GameState) -> AnimationState {
+    // FIX: Stable discard logic
+    let mut i = 0;
+    while i < hand.len() {
+        if hand[i].is_selected {
+            let mut c = hand.remove(i);
+            c.is_selected = false;
+            choreography::reset_pose(&mut c);
+            // Bottom of the deck, so the refill below doesn't draw the same cards back
+            deck.insert(0, c);
+        } else {
+            i += 1;
+        }
+    }
+
+    if stats.round_score >= stats.target_score {
+        crate::logic::battle_result::enter(stats);
+        *state = GameState::BattleResult;
+        AnimationState::Idle
+    } else if stats.hands_remaining == 0 || stats.current_hp <= 0 {
+        crate::logic::battle_result::settle_loss(stats);
+        crate::logic::run_end::end_run(stats, false);
+        *state = GameState::RunEnd;
+        AnimationState::Idle
+    } else {
+        refill_hand(hand, deck, stats)
+    }
+}
+
+// Applies one breakdown entry: update the running chips/mult, pop text over its source
+fn play_score_step(hand: &[Card], stats: &mut BaseModifiers) {
+    let Some(step) = stats.score_sequence.as_ref().and_then(|r| r.breakdown.get(stats.score_index)).cloned() else { return; };
+
+    let pos = match step.source {
+        ScoreSource::Card(id) => hand.iter().find(|c| c.id == id)
+            .map(|c| Vector2::new(c.current_pos.x - 30.0, c.current_pos.y - CARD_HEIGHT * 0.75))
+            .unwrap_or(Vector2::new(SCREEN_WIDTH / 2.0, PLAY_AREA_Y)),
+        ScoreSource::Relic(i) => {
+            let slot = layout::relic_slot(i);
+            Vector2::new(slot.x, slot.y + slot.height + 10.0)
+        }
+    };
+
+    stats.chips = step.chips_after;
+    stats.mult = step.mult_after;
+
+    if matches!(step.source, ScoreSource::Card(_)) && step.trigger.is_retrigger() {
+        stats::spawn_floating_text(stats, "Again!".to_string(), Vector2::new(pos.x, pos.y - 40.0), NEU_ORANGE);
+    }
+
+    if step.chips_added != 0 {
+        stats::spawn_floating_text(stats, format!("+{}", step.chips_added), pos, NEU_BLUE);
+        stats.chips_pulse = 1.0;
+    }
+    if step.mult_added != 0.0 {
+        let offset = if step.chips_added != 0 { 40.0 } else { 0.0 };
+        stats::spawn_floating_text(stats, format!("+{} Mult", format_mult(step.mult_added)), Vector2::new(pos.x, pos.y + offset), NEU_RED);
+        stats.mult_pulse = 1.0;
+    }
+    if let Some(x) = step.x_mult {
+        stats::spawn_floating_text(stats, format!("X{} Mult", format_mult(x)), pos, NEU_RED);
+        stats.mult_pulse = 1.0;
+        stats::spawn_particle_burst(stats, pos, NEU_RED);
+    }
+}
+
+// Relic hooks outside scoring: on_hand_end once the hand has landed (played = true) or
+// on_discard for each discarded card. Only their state changes matter here.
+fn run_relic_hooks(hand: &[Card], stats: &mut BaseModifiers, played: bool) {
+    let (selected, held): (Vec<Card>, Vec<Card>) = hand.iter().cloned().partition(|c| c.is_selected);
+    let rank = played.then(|| poker::get_hand_rank(&selected, stats));
+    let (chips, mult) = (stats.chips, stats.mult);
+
+    let mut relics = std::mem::take(&mut stats.equipped_relics);
+    let cards_played: &[Card] = if played { &selected } else { &[] };
+    let mut context = ScoringContext::new(cards_played, &held, hand, rank, chips, mult as i32, stats);
+    context.set_current(chips, mult);
+    for relic in &mut relics {
+        if played {
+            relic.on_hand_end(&context);
+        } else {
+            for card in &selected {
+                relic.on_discard(&context, card);
+            }
+        }
+    }
+    stats.equipped_relics = relics;
+    relics::remove_destroyed(stats);
+}
+
+// The enemy hits back after every hand that doesn't finish it
+fn enemy_strikes(stats: &mut BaseModifiers) {
+    stats.current_hp = (stats.current_hp - stats.enemy_damage).max(0);
+    stats.add_trauma(0.4);
+    let pos = Vector2::new(P_PANEL_X + 40.0, P_PANEL_Y - 40.0);
+    stats::spawn_floating_text(stats, format!("-{} HP", stats.enemy_damage), pos, NEU_RED);
+}
+
+pub fn can_pay_discard(stats: &BaseModifiers) -> bool {
+    !stats.has_ability(&BossAbility::PayToDiscard) || stats.money >= 1
+}
+
+// End of the sequence: the total hits the enemy
+pub fn land_score_hit(stats: &mut BaseModifiers) {
+    let Some(result) = stats.score_sequence.as_ref() else { return; };
+    let total = result.total;
+
+    stats.total_score += total;
+    stats.round_score += total;
+    stats.display_score += total;
+    stats.game_metrics.log_play(total);
+
+    stats.damage_flash_timer = 0.2;
+    // Shake grows with the order of magnitude of the hit (100 -> light, 1e6+ -> max)
+    let magnitude = (total.value().max(1.0).log10() as f32 / 6.0).clamp(0.15, 1.0);
+    stats.add_trauma(magnitude);
+
+    let hit_pos = Vector2::new(ENEMY_CENTER_X - 60.0, ENEMY_Y + 170.0);
+    stats::spawn_floating_text(stats, format!("{}", total), hit_pos, NEU_YELLOW);
+    stats::spawn_particle_burst(stats, Vector2::new(ENEMY_CENTER_X, ENEMY_Y + 80.0), NEU_ORANGE);
+}
+
+// Mouse has to travel this far (canvas px) before a press on a card becomes a drag
+const DRAG_THRESHOLD: f32 = 8.0;
+
 fn update_card_interaction(rl: &RaylibHandle, hand: &mut Vec<Card>, stats: &mut BaseModifiers) {
     let mouse_pos = rl.get_mouse_position();
     let mouse_pressed = rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT);
+    let mouse_down = rl.is_mouse_button_down(MouseButton::MOUSE_BUTTON_LEFT);
+    let right_pressed = rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_RIGHT);
+    // Drop the inspect panel once its card has been played or discarded
+    if stats.inspected_card.is_some_and(|id| !hand.iter().any(|c| c.id == id)) {
+        stats.inspected_card = None;
+    }
+
+    update_relic_drag(rl, stats);
+
     let mut hovered_index = None;
     for (i, card) in hand.iter_mut().enumerate().rev() {
-        let width = CARD_WIDTH * card.scale.x;
-        let height = CARD_HEIGHT * card.scale.y;
-        let rect = Rectangle::new(card.current_pos.x - width/2.0, card.current_pos.y - height/2.0, width, height);
-        if rect.check_collision_point_rec(mouse_pos) {
+        if layout::card_rect(card).check_collision_point_rec(mouse_pos) {
             hovered_index = Some(i);
             break;
         }
     }
+
+    // Keyboard / pad card cursor. It takes over hovering until the mouse is used again.
+    if !stats.actions.using_keys || hand.is_empty() {
+        stats.card_cursor = None;
+    } else if stats.actions.pressed(Action::Left) || stats.actions.pressed(Action::Right) || stats.card_cursor.is_some() {
+        let mut cursor = stats.card_cursor.unwrap_or(0).min(hand.len() - 1);
+        if stats.card_cursor.is_some() {
+            stats.actions.step(&mut cursor, hand.len(), Action::Left, Action::Right);
+        }
+        stats.card_cursor = Some(cursor);
+        hovered_index = Some(cursor);
+    }
+    let key_toggle = stats.card_cursor.is_some() && stats.actions.pressed(Action::Confirm);
+
+    // Right-click (or Inspect) toggles the inspect panel for the hovered card (or closes it)
+    if right_pressed || (stats.card_cursor.is_some() && stats.actions.pressed(Action::Inspect)) {
+        let target = hovered_index.map(|i| hand[i].id);
+        stats.inspected_card = if target == stats.inspected_card { None } else { target };
+    }
+
+    // --- Press / drag / release ---
+    // A press only toggles selection if it's released without moving; moving past the
+    // threshold turns it into a drag that reorders the hand instead.
+    if mouse_pressed && stats.dragging_relic.is_none() && let Some(i) = hovered_index.filter(|_| stats.card_cursor.is_none()) {
+        let card = &mut hand[i];
+        card.is_pressed = true;
+        card.click_pos = card.current_pos - mouse_pos; // Grab offset, so the card doesn't jump
+        stats.drag_origin = mouse_pos;
+    }
+
+    if let Some(i) = hand.iter().position(|c| c.is_pressed) {
+        if mouse_down {
+            if !hand[i].is_dragging && mouse_pos.distance_to(stats.drag_origin) > DRAG_THRESHOLD {
+                hand[i].is_dragging = true;
+            }
+            if hand[i].is_dragging {
+                let card = &mut hand[i];
+                card.tween = None;
+                card.current_pos = mouse_pos + card.click_pos;
+
+                let target = layout::hand_drop_index(card.current_pos.x, hand.len());
+                if target != i {
+                    daily::record(stats, RunAction::MoveCard(i, target));
+                    move_card(hand, stats, i, target);
+                }
+            }
+        } else {
+            let card = &mut hand[i];
+            if !card.is_dragging {
+                card.is_selected = !card.is_selected;
+                stats.game_metrics.log_click(card.id as usize);
+            }
+            card.is_pressed = false;
+            card.is_dragging = false;
+        }
+    }
+
+    let dragging = hand.iter().any(|c| c.is_dragging);
     for (i, card) in hand.iter_mut().enumerate() {
-        card.is_hovered = Some(i) == hovered_index;
-        if mouse_pressed && card.is_hovered {
+        card.is_hovered = !dragging && Some(i) == hovered_index;
+        if key_toggle && card.is_hovered {
             card.is_selected = !card.is_selected;
             stats.game_metrics.log_click(card.id as usize);
         }
     }
 }
 
+// Relics score left-to-right, so their order matters for xMult. Press a relic and drop it
+// on another slot to move it there.
+fn update_relic_drag(rl: &RaylibHandle, stats: &mut BaseModifiers) {
+    let mouse_pos = rl.get_mouse_position();
+    let count = stats.equipped_relics.len();
+
+    if rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT) {
+        stats.dragging_relic = (0..count).find(|&i| widgets::clicked(rl, layout::relic_slot(i)));
+        stats.drag_origin = mouse_pos;
+    }
+
+    let Some(from) = stats.dragging_relic else { return; };
+    if !rl.is_mouse_button_down(MouseButton::MOUSE_BUTTON_LEFT) || from >= count {
+        stats.dragging_relic = None;
+        return;
+    }
+
+    let to = layout::relic_drop_index(mouse_pos.x, count);
+    if to != from {
+        daily::record(stats, RunAction::MoveRelic(from, to));
+        move_relic(stats, from, to);
+        stats.dragging_relic = Some(to);
+    }
+}
+
+pub fn move_relic(stats: &mut BaseModifiers, from: usize, to: usize) {
+    let relic = stats.equipped_relics.remove(from);
+    stats.equipped_relics.insert(to, relic);
+}
+
 fn update_hand_positions(hand: &mut Vec<Card>) {
     let num_cards = hand.len();
     if num_cards == 0 { return; }
-    let center_x = SCREEN_WIDTH / 2.0 + 50.0;
-    let spacing = 90.0;
-    let start_x = center_x - ((num_cards as f32 - 1.0) * spacing) / 2.0;
-    let base_y = SCREEN_HEIGHT - 120.0;
 
     for (i, card) in hand.iter_mut().enumerate() {
-        if card.tween.is_some() { continue; }
-        let target_x = start_x + (i as f32 * spacing);
+        if card.is_dragging { continue; } // Follows the mouse instead
+        let slot = layout::hand_slot(i, num_cards);
         let y_offset = if card.is_selected { 60.0 } else if card.is_hovered { 30.0 } else { 0.0 };
-        let target_y = base_y - y_offset;
-        if card.current_pos.distance_to(Vector2::new(target_x, target_y)) > 2.0 {
-            card.move_to(Vector2::new(target_x, target_y), 0.15);
+        let target = Vector2::new(slot.x, slot.y - y_offset);
+        // Retarget tweens heading somewhere else, so neighbours slide aside mid-drag
+        if let Some(tween) = &card.tween && tween.end.distance_to(target) <= 2.0 { continue; }
+        if card.current_pos.distance_to(target) > 2.0 {
+            card.move_to(target, 0.15);
         }
     }
 }
 
-fn refill_hand(hand: &mut Vec<Card>, deck: &mut Vec<Card>, stats: &BaseModifiers) {
-    while hand.len() < stats.hand_size as usize {
-        if let Some(mut new_card) = deck.pop() {
-            new_card.current_pos = Vector2::new(DECK_X, DECK_Y);
+// Draws up to hand_size and deals the new cards in from the deck.
+// Returns the animation state to continue in (Dealing if anything was drawn).
+pub fn refill_hand(hand: &mut Vec<Card>, deck: &mut Vec<Card>, stats: &BaseModifiers) -> AnimationState {
+    let mut new_ids = Vec::new();
+    while hand.len() < stats.effective_hand_size() as usize {
+        if let Some(new_card) = deck.pop() {
+            new_ids.push(new_card.id);
             hand.push(new_card);
         } else { break; }
     }
     sort_hand(hand, stats.current_sort);
+    choreography::deal(hand, &new_ids);
+
+    if new_ids.is_empty() { AnimationState::Idle } else { AnimationState::Dealing }
 }
 
-fn sort_hand(hand: &mut Vec<Card>, mode: SortMode) {
+fn sort_hand(hand: &mut [Card], mode: SortMode) {
     match mode {
         SortMode::Rank => hand.sort_by(|a, b| b.value.cmp(&a.value).then(a.suit.cmp(&b.suit))),
         SortMode::Suit => hand.sort_by(|a, b| a.suit.cmp(&b.suit).then(b.value.cmp(&a.value))),
+        SortMode::Manual => {} // New cards just go on the right
+    }
+}
+
+// The enemy's own ability, topped up to `count` with other bosses' (endless stacking).
+// Never two of the same kind, so no double silence.
+fn boss_abilities(db: &HashMap<String, Enemy>, enemy: &Enemy, count: usize, rng: &mut StdRng) -> Vec<BossAbility> {
+    use rand::seq::SliceRandom;
+
+    let mut abilities: Vec<BossAbility> = Some(enemy.ability.clone()).filter(|a| *a != BossAbility::None).into_iter().collect();
+    let mut bosses: Vec<&Enemy> = db.values().filter(|e| e.is_boss() && e.ability != BossAbility::None).collect();
+    bosses.sort_by(|a, b| a.id.cmp(&b.id));
+    let mut pool: Vec<BossAbility> = Vec::new();
+    for boss in bosses {
+        if !abilities.iter().chain(&pool).any(|a| a.label() == boss.ability.label()) {
+            pool.push(boss.ability.clone());
+        }
     }
+    pool.shuffle(rng);
+    let missing = count.saturating_sub(abilities.len());
+    abilities.extend(pool.into_iter().take(missing));
+    abilities
 }
 
-// Add this if you haven't implemented start_next_round in game.rs yet
-// (If you have, make sure it's consistent)
-pub fn start_next_round(stats: &mut BaseModifiers, deck: &mut Vec<Card>) {
-    use rand::{self, Rng, seq::SliceRandom};
-    use crate::structures::enemy::{Enemy, EnemyAbility}; // Ensure correct path
+// Sets up the fight for a map node: picks the enemy for its tier, resets the
+// per-fight counters and shuffles a fresh copy of the run's deck. HP carries over between fights.
+pub fn start_next_round(stats: &mut BaseModifiers, deck: &mut Vec<Card>, kind: NodeKind) {
+    use rand::seq::SliceRandom;
 
     stats.round += 1;
-    stats.current_hp = stats.max_hp;
-    stats.hands_remaining = 4;
-    stats.discards_remaining = 5;
-    stats.round_score = 0;
-    stats.display_score = 0.0;
+    stats.hands_remaining = stats.hands_per_fight;
+    stats.discards_remaining = stats.discards_per_fight;
+    stats.fight = FightRecord::default();
+    stats.round_score = Score::ZERO;
+    stats.display_score = Score::ZERO;
     stats.shop_y_offset = SCREEN_HEIGHT;
 
-    let mut rng = rand::thread_rng();
+    // Tier 1 for antes 1-2, tier 2 for 3-4, tier 3 after that; elites fight one tier up
+    let tier = ((stats.ante + 1) / 2).clamp(1, 3) as u8;
+    let wanted = match kind {
+        _ if stats.has_rule(ChallengeRule::AllBosses) => BOSS_TIER,
+        NodeKind::Boss => BOSS_TIER,
+        NodeKind::Elite => (tier + 1).min(3),
+        _ => tier,
+    };
+
     if let Some(db) = &stats.enemy_database {
-        let enemies: Vec<&Enemy> = db.values().collect();
-        if let Some(chosen) = enemies.choose(&mut rng) {
+        // Sorted so the seeded rng picks the same enemy regardless of HashMap order
+        let mut enemies: Vec<&Enemy> = db.values().filter(|e| e.tier == wanted).collect();
+        enemies.sort_by(|a, b| a.id.cmp(&b.id));
+        if let Some(chosen) = enemies.choose(&mut stats.rng) {
+            let elite = if kind == NodeKind::Elite { 1.5 } else { 1.0 };
+            let depth = endless::depth(stats.ante, FINAL_ANTE);
             stats.current_enemy = Some((*chosen).clone());
             stats.enemy_name = chosen.name.clone();
-            stats.target_score = chosen.hp;
-            stats.active_ability = BossAbility::None; // Placeholder mapping
+            stats.enemy_damage = (chosen.damage as f32 * elite) as i32;
+            let stack = if chosen.is_boss() { stats.endless_curve.boss_abilities(depth) } else { 0 };
+            stats.active_abilities = boss_abilities(db, chosen, stack, &mut stats.rng);
+            // Enemy HP grows with each ante, and faster still in endless; Score keeps this from overflowing late
+            stats.target_score = chosen.hp * elite as f64 * (stats.ante_scaling as f64).powi(stats.ante - 1) * stats.endless_curve.hp_mult(depth);
+            if stats.has_ability(&BossAbility::DoubleTarget) {
+                stats.target_score = stats.target_score * 2.0;
+            }
         }
     }
 
+    // The run's deck carries over, including any suit changes / enhancements from events
     deck.clear();
-    let mut id_counter = 0;
-    for suit in 0..4 {
-        for value in 2..=14 {
-            let mut card = Card::new(id_counter, DECK_X); // FIXED 2 args
-            card.suit = suit;
-            card.value = value;
-            deck.push(card);
-            id_counter += 1;
-        }
-    }
-    deck.shuffle(&mut rng);
-}
\ No newline at end of file
+    deck.extend(stats.owned_deck.iter().cloned());
+    deck.shuffle(&mut stats.rng);
+    relics::fire(stats, Lifecycle::RoundStart);
+}
@@ -0,0 +1,120 @@
+// Debug-mode hot reload of assets/data/*.json and mods/*/*.json (plus relic scripts in
+// their scripts/ folders). Polls modification
+// times (cheap, no extra deps) and re-runs the normal loader when anything changes.
+// The Mods screen also calls reload() after switching a mod on or off.
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use crate::structures::assets::GameAssets;
+use crate::structures::stats::{self, BaseModifiers};
+use crate::structures::data_loader;
+use crate::data_validation::{self, Report, Severity, DATA_DIR};
+use crate::consts::*;
+
+const POLL_INTERVAL: f32 = 0.5; // Seconds between mtime checks
+const TOAST_ISSUES: usize = 4; // Issues listed in the toast; the rest are only in the log
+
+pub struct DataWatcher {
+    mtimes: HashMap<PathBuf, SystemTime>,
+    timer: f32,
+}
+
+impl DataWatcher {
+    pub fn new() -> Self {
+        Self { mtimes: scan(), timer: 0.0 }
+    }
+
+    // True once per batch of edits (a file changed, appeared or was removed)
+    pub fn changed(&mut self, dt: f32) -> bool {
+        self.timer += dt;
+        if self.timer < POLL_INTERVAL {
+            return false;
+        }
+        self.timer = 0.0;
+        let mtimes = scan();
+        let changed = mtimes != self.mtimes;
+        self.mtimes = mtimes;
+        changed
+    }
+}
+
+fn scan() -> HashMap<PathBuf, SystemTime> {
+    let mut dirs = vec![DATA_DIR.to_string()];
+    dirs.extend(data_validation::mod_folders(Path::new(".")));
+    let scripts: Vec<String> = dirs.iter().map(|dir| format!("{}/scripts", dir)).collect();
+    dirs.iter().chain(&scripts)
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flat_map(|dir| dir.flatten())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json" || ext == "rhai"))
+        .filter_map(|path| {
+            let mtime = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            Some((path, mtime))
+        })
+        .collect()
+}
+
+pub fn reload(assets: &mut GameAssets, stats: &mut BaseModifiers) {
+    let report = data_loader::load_all_data(&mut assets.data, &stats.settings.disabled_mods, false);
+    assets.mod_icons_dirty = true;
+    data_loader::copy_to_stats(&assets.data, stats);
+    rebind(stats);
+    toast_report(stats, &report, "Game data reloaded");
+}
+
+// Shows a reload / load result. Errors get a red toast listing the first few issues.
+pub fn toast_report(stats: &mut BaseModifiers, report: &Report, headline: &str) {
+    let errors: Vec<String> = report.issues.iter()
+        .filter(|i| i.severity == Severity::Error)
+        .map(|i| i.to_string())
+        .collect();
+    if errors.is_empty() {
+        stats::push_toast(stats, headline.to_string(), NEU_GREEN);
+        return;
+    }
+
+    let mut text = format!("{} with {} error(s):", headline, errors.len());
+    for line in errors.iter().take(TOAST_ISSUES) {
+        text.push('\n');
+        text.push_str(line);
+    }
+    if errors.len() > TOAST_ISSUES {
+        text.push_str(&format!("\n...and {} more (see log)", errors.len() - TOAST_ISSUES));
+    }
+    stats::push_toast(stats, text, NEU_RED);
+}
+
+// Swaps everything the player holds for the freshly loaded definition with the same id.
+// Items whose id vanished from the data are kept as they were. The current enemy is
+// left alone; its HP was already scaled for this fight. Relics keep their state (new
+// extra keys from a script's init() are added).
+fn rebind(stats: &mut BaseModifiers) {
+    for relic in stats.equipped_relics.iter_mut().chain(stats.current_shop_relics.iter_mut()) {
+        if let Some(fresh) = stats.all_relics.get(&relic.data.id) {
+            let state = std::mem::take(&mut relic.state);
+            *relic = fresh.clone();
+            for (key, value) in std::mem::replace(&mut relic.state, state).vars {
+                relic.state.vars.entry(key).or_insert(value);
+            }
+        }
+    }
+    for rune in &mut stats.equipped_runes {
+        if let Some(fresh) = stats.available_runes.iter().find(|r| r.id == rune.id) {
+            *rune = fresh.clone();
+        }
+    }
+    for item in &mut stats.consumables {
+        if let Some(fresh) = stats.all_consumables.get(&item.id) {
+            *item = fresh.clone();
+        }
+    }
+    for heirloom in &mut stats.heirlooms {
+        if let Some(fresh) = stats.all_heirlooms.get(&heirloom.id) {
+            *heirloom = fresh.clone();
+        }
+    }
+    if let Some(event) = &mut stats.current_event && let Some(fresh) = stats.all_events.get(&event.id) {
+        *event = fresh.clone();
+    }
+}
@@ -0,0 +1,126 @@
+use raylib::prelude::*;
+use rand::Rng;
+use crate::structures::stats::{self, BaseModifiers};
+use crate::structures::state::{GameState, AnimationState};
+use crate::structures::card::{self, Card};
+use crate::structures::map::{NodeKind, RunMap};
+use crate::structures::challenge::ChallengeRule;
+use crate::structures::effect::Effect;
+use crate::logic::effects;
+use crate::logic::relics::{self, Lifecycle};
+use crate::logic::daily;
+use crate::structures::daily::RunAction;
+use crate::structures::save::RunSave;
+use crate::consts::*;
+use crate::layout;
+use crate::widgets;
+use crate::input::Action;
+
+pub fn random_seed() -> u64 {
+    rand::thread_rng().r#gen()
+}
+
+// This ante's map, with the challenge's map rules applied
+pub fn generate_map(stats: &BaseModifiers) -> RunMap {
+    let map = RunMap::generate(stats.run_seed, stats.ante);
+    if stats.has_rule(ChallengeRule::NoShop) { map.without_shops() } else { map }
+}
+
+// Fresh run (see BaseModifiers::new_run): first ante's map and a standard deck, which
+// rune_select::begin_run swaps for the picked starting deck. Each fight shuffles its own copy.
+pub fn start_run(stats: &mut BaseModifiers, seed: u64) {
+    stats.seed_run(seed);
+    stats.round = 0;
+    stats.ante = 1;
+    stats.owned_deck = card::standard_deck();
+    stats.hand_levels.clear();
+    stats.run_map = generate_map(stats);
+}
+
+// Called when a fight is won and its rewards are paid out. True if that was the final
+// boss and the run is won.
+pub fn finish_fight(stats: &mut BaseModifiers) -> bool {
+    let was_boss = stats.run_map.current_kind() == Some(NodeKind::Boss);
+    let won_run = was_boss && stats.ante >= FINAL_ANTE && !stats.endless;
+    stats.enemies_defeated += 1;
+    relics::fire(stats, Lifecycle::RoundEnd);
+    if was_boss {
+        relics::fire(stats, Lifecycle::BossDefeated);
+        if !won_run {
+            stats.ante += 1;
+            stats.run_map = generate_map(stats);
+        }
+    }
+    crate::logic::profile::record_fight_won(stats, was_boss);
+    won_run
+}
+
+// "Endless" on the victory screen: on to the next ante, with the endless curve on top
+pub fn enter_endless(stats: &mut BaseModifiers) {
+    stats.endless = true;
+    stats.run_summary = None;
+    stats.ante += 1;
+    stats.run_map = generate_map(stats);
+}
+
+pub fn update_map(rl: &RaylibHandle, state: &mut GameState, stats: &mut BaseModifiers, hand: &mut Vec<Card>, deck: &mut Vec<Card>, animation_state: &mut AnimationState) {
+    let available = stats.run_map.available();
+
+    // Keyboard focus walks the reachable nodes left to right
+    stats.actions.step(&mut stats.focus_col, available.len(), Action::Left, Action::Right);
+    let confirm = stats.actions.pressed(Action::Confirm);
+
+    for (n, &index) in available.iter().enumerate() {
+        let node = &stats.run_map.nodes[index];
+        let center = layout::map_node_center(node.row, node.col, node.row_width);
+        if widgets::clicked_circle(rl, center, layout::MAP_NODE_RADIUS) || (confirm && stats.focus_col == n) {
+            daily::record(stats, RunAction::Node(index));
+            enter_node(index, state, stats, hand, deck, animation_state);
+            // Rest, treasure and an empty event stay on the map, so the save main makes on
+            // entering it never runs; save here or Continue loses the node.
+            if *state == GameState::Map {
+                RunSave::capture(stats).save();
+            }
+            break;
+        }
+    }
+}
+
+pub fn enter_node(index: usize, state: &mut GameState, stats: &mut BaseModifiers, hand: &mut Vec<Card>, deck: &mut Vec<Card>, animation_state: &mut AnimationState) {
+    stats.run_map.enter(index);
+    let node = &stats.run_map.nodes[index];
+    let kind = node.kind;
+    let center = layout::map_node_center(node.row, node.col, node.row_width);
+    let text_pos = Vector2::new(center.x - 60.0, center.y - 60.0);
+
+    match kind {
+        NodeKind::Combat | NodeKind::Elite | NodeKind::Boss => {
+            crate::logic::game::start_next_round(stats, deck, kind);
+            hand.clear();
+            *animation_state = crate::logic::game::refill_hand(hand, deck, stats);
+            *state = GameState::Playing;
+        }
+        NodeKind::Shop => {
+            crate::logic::shop::init_shop(stats);
+            relics::fire(stats, Lifecycle::ShopEnter);
+            *state = GameState::Shop;
+        }
+        NodeKind::Rest => {
+            let heal = (stats.max_hp * 3 / 10).max(1);
+            stats.current_hp = (stats.current_hp + heal).min(stats.max_hp);
+            stats::spawn_floating_text(stats, format!("+{} HP", heal), text_pos, NEU_GREEN);
+        }
+        NodeKind::Treasure => {
+            for line in effects::apply(stats, &Effect::GainRelic { relic: None }) {
+                stats::spawn_floating_text(stats, line, text_pos, NEU_ORANGE);
+            }
+        }
+        NodeKind::Event => {
+            if crate::logic::event::start_event(stats) {
+                *state = GameState::Event;
+            } else {
+                stats::spawn_floating_text(stats, "Nothing happens...".to_string(), text_pos, PARCHMENT);
+            }
+        }
+    }
+}
@@ -1,8 +1,52 @@
 use raylib::prelude::*;
+use crate::structures::stats::{self, BaseModifiers};
 use crate::structures::state::GameState;
+use crate::structures::save::RunSave;
+use crate::structures::mods;
+use crate::structures::daily::{Leaderboard, RunAction};
+use crate::logic::daily;
+use crate::consts::*;
+use crate::layout::menu_button;
+use crate::widgets;
+use crate::input::Action;
 
-pub fn update_menu(rl: &RaylibHandle, state: &mut GameState) {
-    if rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT) {
-        *state = GameState::RuneSelect;
+pub const MENU_ITEMS: [&str; 6] = ["Continue", "Start Run", "Daily", "Mods", "Settings", "Quit"];
+
+pub fn update_menu(rl: &RaylibHandle, state: &mut GameState, stats: &mut BaseModifiers) {
+    stats.actions.step(&mut stats.focus_row, MENU_ITEMS.len(), Action::Up, Action::Down);
+    let activated = |i: usize| widgets::clicked(rl, menu_button(i)) || (stats.actions.pressed(Action::Confirm) && stats.focus_row == i);
+
+    if activated(0) && RunSave::exists() {
+        continue_run(state, stats);
+    } else if activated(1) {
+        stats.focus_row = 0;
+        *state = GameState::ChallengeSelect;
+    } else if activated(2) {
+        stats.daily_board = Leaderboard::load();
+        *state = GameState::Daily;
+    } else if activated(3) {
+        *state = GameState::Mods;
+    } else if activated(4) {
+        stats.previous_state = GameState::Menu;
+        *state = GameState::Settings;
+    } else if activated(5) {
+        *state = GameState::Exit;
+    }
+}
+
+// Back onto the map of the saved run, as long as the same mods are loaded
+fn continue_run(state: &mut GameState, stats: &mut BaseModifiers) {
+    let Some(save) = RunSave::load() else {
+        stats::push_toast(stats, "The saved run could not be read".to_string(), NEU_RED);
+        return;
+    };
+    let lines = mods::mismatches(&save.mods, &stats.active_mods);
+    if !lines.is_empty() {
+        let text = format!("The saved run needs the same mods:\n{}", lines.join("\n"));
+        stats::push_toast(stats, text, NEU_RED);
+        return;
     }
+    save.restore(stats);
+    daily::record(stats, RunAction::Resume);
+    *state = GameState::Map;
 }
@@ -1,27 +1,47 @@
+use crate::structures::score::Score;
+use crate::structures::trigger::Trigger;
+
 #[derive(Debug, Clone)]
 pub struct GameMetrics {
     pub clicks: u32,
     pub hands_played: u32,
     pub discards_used: u32,
+    pub verbose: bool, // Mirrors settings.debug_logging
 }
 
 impl GameMetrics {
     pub fn new() -> Self {
-        Self { clicks: 0, hands_played: 0, discards_used: 0 }
+        Self { clicks: 0, hands_played: 0, discards_used: 0, verbose: false }
     }
 
     pub fn log_click(&mut self, card_index: usize) {
         self.clicks += 1;
+        if !self.verbose { return; }
         println!("[DEBUG] CLICKED Card Index: {} | Total Clicks: {}", card_index, self.clicks);
     }
 
-    pub fn log_play(&mut self, score: i32) {
+    pub fn log_play(&mut self, score: Score) {
         self.hands_played += 1;
+        if !self.verbose { return; }
         println!(">>> [ACTION] HAND PLAYED | Score: {} | Total Hands: {}", score, self.hands_played);
     }
 
+    // Scoring order for the hand, e.g. "P0 P0+1 P1 H2 R0" (played / held index, +retrigger, relic)
+    pub fn log_triggers(&self, queue: &[Trigger]) {
+        if !self.verbose { return; }
+        let order: Vec<String> = queue.iter().map(|t| match *t {
+            Trigger::Played { index, repeat: 0 } => format!("P{}", index),
+            Trigger::Played { index, repeat } => format!("P{}+{}", index, repeat),
+            Trigger::Held { index, repeat: 0 } => format!("H{}", index),
+            Trigger::Held { index, repeat } => format!("H{}+{}", index, repeat),
+            Trigger::Relic(i) => format!("R{}", i),
+        }).collect();
+        println!("[DEBUG] TRIGGERS: {}", order.join(" "));
+    }
+
     pub fn log_discard(&mut self, count: usize) {
         self.discards_used += 1;
+        if !self.verbose { return; }
         println!(">>> [ACTION] DISCARDED {} cards | Total Discards: {}", count, self.discards_used);
     }
 }
\ No newline at end of file
@@ -5,6 +5,19 @@ pub mod rune_select;
 pub mod stats_menu;
 pub mod battle_result;
 pub mod metrics;
+pub mod settings;
+pub mod map;
+pub mod choreography;
+pub mod controls;
+pub mod effects;
+pub mod event;
+pub mod profile;
+pub mod hot_reload;
+pub mod mods;
+pub mod relics;
+pub mod run_end;
+pub mod challenge;
+pub mod daily;
 
 pub use game::update_game;
 pub use shop::update_shop;
@@ -12,3 +25,11 @@ pub use menu::update_menu;
 pub use rune_select::update_rune_select;
 pub use stats_menu::update_stats_menu;
 pub use battle_result::update_battle_result;
+pub use settings::update_settings;
+pub use map::update_map;
+pub use controls::update_controls;
+pub use event::update_event;
+pub use mods::update_mods;
+pub use run_end::update_run_end;
+pub use challenge::update_challenge_select;
+pub use daily::update_daily;
@@ -0,0 +1,68 @@
+use raylib::prelude::*;
+use crate::structures::stats::{self, BaseModifiers};
+use crate::structures::state::GameState;
+use crate::structures::assets::GameAssets;
+use crate::structures::mods;
+use crate::logic::hot_reload;
+use crate::input::Action;
+use crate::layout;
+use crate::widgets;
+use crate::consts::*;
+
+// Keyboard focus rows: one per mod, then Back. Focus also picks the mod in the details panel.
+pub fn focus_back(assets: &GameAssets) -> usize {
+    assets.data.mods.len()
+}
+
+pub fn update_mods(rl: &RaylibHandle, state: &mut GameState, stats: &mut BaseModifiers, assets: &mut GameAssets) {
+    let back_row = focus_back(assets);
+    stats.actions.step(&mut stats.focus_row, back_row + 1, Action::Up, Action::Down);
+    let confirm = stats.actions.pressed(Action::Confirm);
+
+    if stats.actions.pressed(Action::Back) || widgets::clicked(rl, layout::mods_back_button()) || (confirm && stats.focus_row == back_row) {
+        *state = GameState::Menu;
+        return;
+    }
+
+    for row in 0..assets.data.mods.len() {
+        if widgets::clicked(rl, layout::mods_toggle(row)) || (confirm && stats.focus_row == row) {
+            stats.focus_row = row;
+            toggle_mod(stats, assets, row);
+            return;
+        }
+        if widgets::clicked(rl, layout::mods_row(row)) {
+            stats.focus_row = row;
+        }
+    }
+}
+
+// Flips a mod on / off, remembers it in settings and rebuilds the databases
+fn toggle_mod(stats: &mut BaseModifiers, assets: &mut GameAssets, row: usize) {
+    let info = &assets.data.mods[row];
+    if info.manifest.is_none() {
+        stats::push_toast(stats, format!("{} can't be enabled: fix its mod.json first", info.folder), NEU_RED);
+        return;
+    }
+    let id = info.id();
+    let disabled = &mut stats.settings.disabled_mods;
+    if info.enabled {
+        disabled.push(id);
+    } else {
+        disabled.retain(|m| *m != id);
+    }
+    stats.settings.save();
+    hot_reload::reload(assets, stats);
+}
+
+// The profile remembers which mods its last run used; say so if that set has changed
+pub fn check_profile_mods(stats: &mut BaseModifiers) {
+    if stats.profile.fights_won == 0 && stats.profile.mods.is_empty() {
+        return; // Nothing played yet
+    }
+    let lines = mods::mismatches(&stats.profile.mods, &stats.active_mods);
+    if lines.is_empty() {
+        return;
+    }
+    let text = format!("Mods changed since your last run:\n{}", lines.join("\n"));
+    stats::push_toast(stats, text, NEU_YELLOW);
+}
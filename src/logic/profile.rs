@@ -0,0 +1,62 @@
+// Meta-progression: lifetime milestones in stats.profile and the heirlooms they unlock
+use raylib::prelude::*;
+use crate::structures::stats::{self, BaseModifiers};
+use crate::structures::heirloom::Heirloom;
+use crate::structures::profile::HEIRLOOM_SLOTS;
+use crate::logic::effects;
+use crate::consts::*;
+
+// Called when a fight is won (after finish_fight has moved the ante on for bosses)
+pub fn record_fight_won(stats: &mut BaseModifiers, was_boss: bool) {
+    stats.profile.fights_won += 1;
+    if was_boss {
+        stats.profile.bosses_defeated += 1;
+    }
+    if stats.endless {
+        stats.profile.endless_best_ante = stats.profile.endless_best_ante.max(stats.ante);
+    } else {
+        stats.profile.best_ante = stats.profile.best_ante.max(stats.ante);
+    }
+
+    let mut newly_unlocked: Vec<&Heirloom> = stats.all_heirlooms.values()
+        .filter(|h| !stats.profile.is_unlocked(&h.id) && h.unlock.is_met(&stats.profile))
+        .collect();
+    newly_unlocked.sort_by(|a, b| a.id.cmp(&b.id));
+    let newly_unlocked: Vec<(String, String)> = newly_unlocked.iter().map(|h| (h.id.clone(), h.name.clone())).collect();
+
+    for (n, (id, name)) in newly_unlocked.into_iter().enumerate() {
+        stats.profile.unlocked_heirlooms.push(id);
+        let pos = Vector2::new(SCREEN_WIDTH / 2.0 - 200.0, 160.0 + n as f32 * 50.0);
+        stats::spawn_floating_text(stats, format!("Heirloom unlocked: {}", name), pos, NEU_YELLOW);
+    }
+    stats.profile.save();
+}
+
+// Rune select: equip / unequip an unlocked heirloom, within the slot limit
+pub fn toggle_heirloom(stats: &mut BaseModifiers, id: &str) {
+    if !stats.profile.is_unlocked(id) {
+        return;
+    }
+    if stats.profile.is_equipped(id) {
+        stats.profile.equipped_heirlooms.retain(|h| h != id);
+    } else if stats.profile.equipped_heirlooms.len() < HEIRLOOM_SLOTS {
+        stats.profile.equipped_heirlooms.push(id.to_string());
+    }
+}
+
+// Run start: the equipped heirlooms go into stats.heirlooms and their effects apply
+pub fn apply_heirlooms(stats: &mut BaseModifiers) {
+    stats.heirlooms = stats.profile.equipped_heirlooms.iter()
+        .filter_map(|id| stats.all_heirlooms.get(id).cloned())
+        .collect();
+    for heirloom in stats.heirlooms.clone() {
+        effects::apply(stats, &heirloom.effect);
+    }
+}
+
+// Every heirloom in display order (sorted by id so the row doesn't shuffle)
+pub fn heirloom_list(stats: &BaseModifiers) -> Vec<&Heirloom> {
+    let mut list: Vec<&Heirloom> = stats.all_heirlooms.values().collect();
+    list.sort_by(|a, b| a.id.cmp(&b.id));
+    list
+}
@@ -0,0 +1,44 @@
+use crate::structures::stats::{self, BaseModifiers};
+use crate::structures::relic::Relic;
+use crate::consts::*;
+
+// Points in a run where every equipped relic gets a look-in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Lifecycle {
+    RoundStart,
+    RoundEnd,
+    ShopEnter,
+    ShopExit,
+    BossDefeated,
+}
+
+pub fn fire(stats: &mut BaseModifiers, event: Lifecycle) {
+    // Taken out so hooks can read the rest of stats
+    let mut relics = std::mem::take(&mut stats.equipped_relics);
+    for relic in &mut relics {
+        match event {
+            Lifecycle::RoundStart => relic.on_round_start(stats),
+            Lifecycle::RoundEnd => relic.on_round_end(stats),
+            Lifecycle::ShopEnter => relic.on_shop_enter(stats),
+            Lifecycle::ShopExit => relic.on_shop_exit(stats),
+            Lifecycle::BossDefeated => relic.on_boss_defeated(stats),
+        }
+    }
+    stats.equipped_relics = relics;
+    remove_destroyed(stats);
+}
+
+// Drops relics whose state says they're used up, with a toast for each
+pub fn remove_destroyed(stats: &mut BaseModifiers) {
+    let gone: Vec<String> = stats.equipped_relics.iter()
+        .filter(|r| r.state.destroyed)
+        .map(|r| r.data.name.clone())
+        .collect();
+    if gone.is_empty() {
+        return;
+    }
+    stats.equipped_relics.retain(|r| !r.state.destroyed);
+    for name in gone {
+        stats::push_toast(stats, format!("{} was destroyed", name), NEU_RED);
+    }
+}
@@ -0,0 +1,103 @@
+use raylib::prelude::*;
+use crate::structures::stats::{self, BaseModifiers, RunSummary};
+use crate::structures::state::{GameState, AnimationState};
+use crate::structures::assets::GameAssets;
+use crate::structures::card::Card;
+use crate::logic::{challenge, daily, map};
+use crate::consts::*;
+use crate::layout;
+use crate::widgets;
+use crate::input::Action;
+
+pub const RUN_END_ITEMS: [&str; 3] = ["Retry Seed", "New Run", "Main Menu"];
+pub const VICTORY_ITEMS: [&str; 4] = ["Endless", "Retry Seed", "New Run", "Main Menu"];
+pub const DAILY_ITEMS: [&str; 4] = ["Copy Result", "Retry Seed", "New Run", "Main Menu"];
+
+// Snapshot the run for the run-end screen. `victory` = the final boss just fell.
+pub fn end_run(stats: &mut BaseModifiers, victory: bool) {
+    let new_high_score = stats.endless && stats.total_score > stats.profile.endless_high_score;
+    if new_high_score {
+        stats.profile.endless_high_score = stats.total_score;
+        stats.profile.save();
+    }
+    if victory {
+        challenge::record_completion(stats);
+    }
+    stats.run_summary = Some(RunSummary {
+        victory,
+        seed: stats.run_seed,
+        ante: stats.ante,
+        round: stats.round,
+        level: stats.level,
+        enemies_defeated: stats.enemies_defeated,
+        total_score: stats.total_score,
+        best_hand: stats.best_hand,
+        money: stats.money,
+        relics: stats.equipped_relics.iter().map(|r| r.data.name.clone()).collect(),
+        enemy: stats.enemy_name.clone(),
+        endless: stats.endless,
+        new_high_score,
+        challenge: stats.challenge.as_ref().map(|c| c.name.clone()),
+        daily: daily::result(stats, victory),
+        daily_rank: None, // Filed by main once the screen is up (logic::daily::file_result)
+    });
+}
+
+// Victories can keep going in endless mode, except in a daily (everyone stops at the same boss)
+pub fn run_end_items(stats: &BaseModifiers) -> &'static [&'static str] {
+    match &stats.run_summary {
+        Some(summary) if summary.daily.is_some() => &DAILY_ITEMS,
+        Some(summary) if summary.victory => &VICTORY_ITEMS,
+        _ => &RUN_END_ITEMS,
+    }
+}
+
+pub fn update_run_end(rl: &mut RaylibHandle, state: &mut GameState, stats: &mut BaseModifiers, assets: &GameAssets, hand: &mut Vec<Card>, deck: &mut Vec<Card>, animation_state: &mut AnimationState) {
+    let items = run_end_items(stats);
+    stats.actions.step(&mut stats.focus_col, items.len(), Action::Left, Action::Right);
+    let confirm = stats.actions.pressed(Action::Confirm);
+    let chosen = (0..items.len()).find(|&i| widgets::clicked(rl, layout::run_end_button(i, items.len())) || (confirm && stats.focus_col == i));
+    // R still means "go again"
+    let chosen = chosen.map(|i| items[i]).or(rl.is_key_pressed(KeyboardKey::KEY_R).then_some("New Run"));
+
+    let (seed, next) = match chosen {
+        Some("Endless") => {
+            map::enter_endless(stats);
+            *state = GameState::Map;
+            return;
+        }
+        Some("Copy Result") => {
+            copy_result(rl, stats);
+            return;
+        }
+        Some("Retry Seed") => (stats.run_seed, GameState::RuneSelect),
+        Some("New Run") => (map::random_seed(), GameState::ChallengeSelect),
+        Some("Main Menu") => (map::random_seed(), GameState::Menu),
+        _ => return,
+    };
+
+    // Same options, profile and databases; everything else starts over. A retry keeps the
+    // challenge, or plays the same date's daily again.
+    let retry = next == GameState::RuneSelect;
+    let challenge = stats.challenge.clone().filter(|_| retry);
+    let daily_date = stats.daily.as_ref().map(|d| d.date.clone()).filter(|_| retry);
+    *stats = BaseModifiers::new_run(&assets.data, stats.settings.clone(), stats.profile.clone(), seed);
+    match daily_date {
+        Some(date) => daily::begin(stats, date),
+        None => challenge::select(stats, challenge),
+    }
+    deck.clear();
+    hand.clear();
+    *animation_state = AnimationState::Idle;
+    *state = next;
+}
+
+// The daily result string, for pasting into chat (also printed, for the terminal)
+fn copy_result(rl: &mut RaylibHandle, stats: &mut BaseModifiers) {
+    let Some(text) = stats.run_summary.as_ref().and_then(|s| s.daily.as_ref()).map(|r| r.to_string()) else { return; };
+    println!("{}", text);
+    match rl.set_clipboard_text(&text) {
+        Ok(()) => stats::push_toast(stats, "Result copied to the clipboard".to_string(), NEU_GREEN),
+        Err(_) => stats::push_toast(stats, "Could not copy the result".to_string(), NEU_RED),
+    }
+}
@@ -1,75 +1,214 @@
 use raylib::prelude::*;
 use crate::structures::stats::BaseModifiers;
 use crate::structures::state::GameState;
+use crate::structures::deck::{StartingDeck, DEFAULT_DECK};
+use crate::structures::effect::PlayerStat;
 use crate::consts::*;
+use crate::layout::{self, RUNE_ROWS, RUNE_LOADOUT_ROW_H, HEIRLOOM_RADIUS};
+use crate::logic::{challenge, profile};
+use crate::widgets;
+use crate::input::Action;
+
+// Keyboard focus rows after the rune rows
+pub const FOCUS_HEIRLOOMS: usize = RUNE_ROWS.len();
+pub const FOCUS_DECK: usize = RUNE_ROWS.len() + 1;
+pub const FOCUS_START: usize = RUNE_ROWS.len() + 2;
+
+// Starting decks in picker order: the standard one first, then by id
+pub fn deck_list(stats: &BaseModifiers) -> Vec<&StartingDeck> {
+    let mut decks: Vec<&StartingDeck> = stats.all_decks.values().collect();
+    decks.sort_by_key(|d| (d.id != DEFAULT_DECK, d.id.clone()));
+    decks
+}
+
+// The profile's pick, falling back to the first deck if it's gone (e.g. its mod was disabled)
+pub fn selected_deck(stats: &BaseModifiers) -> Option<&StartingDeck> {
+    let id = if stats.profile.selected_deck.is_empty() { DEFAULT_DECK } else { stats.profile.selected_deck.as_str() };
+    stats.all_decks.get(id).or_else(|| deck_list(stats).first().copied())
+}
+
+// A challenge with its own deck replaces the pick
+pub fn deck_locked(stats: &BaseModifiers) -> bool {
+    stats.challenge.as_ref().is_some_and(|c| !c.deck.is_standard())
+}
+
+fn cycle_deck(stats: &mut BaseModifiers, dir: i32) {
+    let ids: Vec<String> = deck_list(stats).iter().map(|d| d.id.clone()).collect();
+    if ids.is_empty() { return; }
+    let current = selected_deck(stats).and_then(|d| ids.iter().position(|id| *id == d.id)).unwrap_or(0);
+    let next = (current as i32 + dir).rem_euclid(ids.len() as i32) as usize;
+    stats.profile.selected_deck = ids[next].clone();
+}
 
 pub fn update_rune_select(rl: &RaylibHandle, state: &mut GameState, stats: &mut BaseModifiers) {
-    let mouse_pos = rl.get_mouse_position();
-    let clicked = rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT);
-    let center_x = SCREEN_WIDTH / 2.0;
-
-    let content_offset = RUNE_CONTENT_OFFSET;
-    let start_y_base = RUNE_START_Y;
-
-    let rows = [
-        (crate::structures::stats::RuneType::Red, start_y_base),
-        (crate::structures::stats::RuneType::Blue, start_y_base + RUNE_SPACING_Y),
-        (crate::structures::stats::RuneType::Green, start_y_base + RUNE_SPACING_Y * 2.0),
-        (crate::structures::stats::RuneType::Minor, start_y_base + RUNE_SPACING_Y * 3.0),
-    ];
-
-    if clicked {
-        for (r_type, y_pos) in rows.iter() {
-            let row_runes: Vec<_> = stats.available_runes.iter().filter(|r| r.rune_type == *r_type).collect();
-            let count = row_runes.len();
-            if count == 0 { continue; }
-
-            let spacing = RUNE_SPACING_X;
-            let row_width = (count as f32 - 1.0) * spacing;
-            let start_x = (center_x + content_offset) - row_width / 2.0;
-
-            for (i, rune) in row_runes.iter().enumerate() {
-                let x = start_x + (i as f32 * spacing);
-                let y = *y_pos;
-                let dist = ((mouse_pos.x - x).powi(2) + (mouse_pos.y - y).powi(2)).sqrt();
-                if dist < RUNE_RADIUS {
-                    stats.equipped_runes.retain(|r| r.rune_type != *r_type);
-                    stats.equipped_runes.push((*rune).clone());
-                }
-            }
+    let content_h = loadout_rows(stats).len() as f32 * RUNE_LOADOUT_ROW_H;
+    widgets::scroll(rl, layout::rune_loadout_list(), &mut stats.loadout_scroll, content_h);
+
+    // Keyboard focus: one row per rune colour, the heirlooms, the deck, then the Start button (focus_col = item in row)
+    stats.actions.step(&mut stats.focus_row, FOCUS_START + 1, Action::Up, Action::Down);
+    let confirm = stats.actions.pressed(Action::Confirm);
+    let locked = challenge::runes_locked(stats); // Challenge with a fixed loadout
+
+    for (row, r_type) in RUNE_ROWS.iter().enumerate() {
+        let row_runes: Vec<_> = stats.available_runes.iter().filter(|r| r.rune_type == *r_type).cloned().collect();
+        let count = row_runes.len();
+        if count == 0 { continue; }
+
+        let focused_row = stats.focus_row == row;
+        if focused_row {
+            stats.focus_col = stats.focus_col.min(count - 1);
+            stats.actions.step(&mut stats.focus_col, count, Action::Left, Action::Right);
         }
 
-        let btn_w = 250.0;
-        let btn_h = 70.0;
-        let btn_x = center_x + content_offset - btn_w / 2.0;
-        let btn_y = SCREEN_HEIGHT - 120.0;
-        let btn_rect = Rectangle::new(btn_x, btn_y, btn_w, btn_h);
-
-        if btn_rect.check_collision_point_rec(mouse_pos) {
-            stats.shop_price_mult = 1.0;
-            stats.ante_scaling = 1.5;
-            stats.stat_points = 3;
-
-            for rune in &stats.equipped_runes {
-                match rune.name.as_str() {
-                    "Paladin" => { stats.max_hp += 40; stats.current_hp += 40; },
-                    "Reaper" => {
-                        stats.max_hp -= 15;
-                        if stats.max_hp < 1 { stats.max_hp = 1; }
-                        stats.current_hp = stats.max_hp;
-                    },
-                    "Judgement" => stats.ante_scaling = 2.0,
-                    "Greed" => { stats.hands_remaining += 1; stats.discards_remaining += 1; },
-                    "Investment" => stats.money = 0,
-                    "Merchant" => stats.shop_price_mult = 1.2,
-                    "Evolution" => stats.ante_scaling = 2.25,
-                    "Force" => stats.mult += 10,
-                    "Flow" => stats.chips += 10,
-                    "Wealth" => stats.money += 3,
-                    _ => {}
-                }
+        for (i, rune) in row_runes.iter().enumerate() {
+            let key_pick = confirm && focused_row && stats.focus_col == i;
+            if (widgets::clicked_circle(rl, layout::rune_center(row, i, count), RUNE_RADIUS) || key_pick) && !locked {
+                stats.equipped_runes.retain(|r| r.rune_type != *r_type);
+                stats.equipped_runes.push(rune.clone());
             }
-            *state = GameState::Playing;
         }
     }
+
+    let heirloom_ids: Vec<String> = profile::heirloom_list(stats).iter().map(|h| h.id.clone()).collect();
+    if stats.focus_row == FOCUS_HEIRLOOMS && !heirloom_ids.is_empty() {
+        stats.focus_col = stats.focus_col.min(heirloom_ids.len() - 1);
+        stats.actions.step(&mut stats.focus_col, heirloom_ids.len(), Action::Left, Action::Right);
+    }
+    for (i, id) in heirloom_ids.iter().enumerate() {
+        let key_pick = confirm && stats.focus_row == FOCUS_HEIRLOOMS && stats.focus_col == i;
+        if widgets::clicked_circle(rl, layout::heirloom_center(i), HEIRLOOM_RADIUS) || key_pick {
+            profile::toggle_heirloom(stats, id);
+        }
+    }
+
+    if !deck_locked(stats) {
+        let on_row = stats.focus_row == FOCUS_DECK;
+        if widgets::clicked(rl, layout::rune_deck_arrow(-1)) || (on_row && stats.actions.pressed(Action::Left)) {
+            cycle_deck(stats, -1);
+        }
+        if widgets::clicked(rl, layout::rune_deck_arrow(1)) || (on_row && (stats.actions.pressed(Action::Right) || confirm)) {
+            cycle_deck(stats, 1);
+        }
+    }
+
+    if widgets::clicked(rl, layout::rune_start_button()) || (confirm && stats.focus_row == FOCUS_START) {
+        begin_run(stats);
+        stats.profile.mods = stats.active_mods.clone();
+        stats.profile.save();
+        *state = GameState::Map;
+    }
+}
+
+// Start Run: starting deck, rune stat changes, heirlooms and the challenge
+pub fn begin_run(stats: &mut BaseModifiers) {
+    stats.shop_price_mult = 1.0;
+    stats.ante_scaling = 1.5;
+    stats.stat_points = 3;
+    let deck = selected_deck(stats).map(|d| (d.id.clone(), d.spec.build()));
+    if let Some((_, cards)) = &deck {
+        stats.owned_deck = cards.clone();
+    }
+
+    for rune in stats.equipped_runes.clone() {
+        match rune.name.as_str() {
+            "Paladin" => { stats.modify_stat(PlayerStat::MaxHp, 40); },
+            "Reaper" => {
+                stats.max_hp -= 15;
+                if stats.max_hp < 1 { stats.max_hp = 1; }
+                stats.current_hp = stats.max_hp;
+            },
+            "Judgement" => stats.ante_scaling = 2.0,
+            "Greed" => {
+                stats.modify_stat(PlayerStat::HandsPerFight, 1);
+                stats.modify_stat(PlayerStat::DiscardsPerFight, 1);
+            },
+            "Investment" => stats.money = 0,
+            "Merchant" => stats.shop_price_mult = 1.2,
+            "Evolution" => stats.ante_scaling = 2.25,
+            "Force" => stats.mult += 10.0,
+            "Flow" => stats.chips += 10,
+            _ => {}
+        }
+    }
+
+    // Heirlooms go through the same stat modifiers, after the runes. A daily skips them
+    // and notes the loadout for its result instead.
+    let runes: Vec<String> = stats.equipped_runes.iter().map(|r| r.id.clone()).collect();
+    match &mut stats.daily {
+        Some(daily) => {
+            daily.runes = runes;
+            daily.deck = deck.map(|(id, _)| id).unwrap_or_default();
+        }
+        None => profile::apply_heirlooms(stats),
+    }
+    challenge::apply(stats);
+}
+
+// Benefits / trade-offs of the current loadout as (text, colour) rows for the scroll list
+pub fn loadout_rows(stats: &BaseModifiers) -> Vec<(String, Color)> {
+    let mut benefits: Vec<String> = Vec::new();
+    let mut downsides: Vec<String> = Vec::new();
+
+    for rune in &stats.equipped_runes {
+        let name = &rune.name;
+        match name.as_str() {
+            "Paladin" => {
+                benefits.push(format!("- {}: +40 Max HP", name));
+                downsides.push(format!("- {}: -10% Relic Mult", name));
+            },
+            "Reaper" => {
+                benefits.push(format!("- {}: Lifesteal on Kill", name));
+                downsides.push(format!("- {}: -15 Max HP", name));
+            },
+            "Judgement" => {
+                benefits.push(format!("- {}: Balanced Calc", name));
+                downsides.push(format!("- {}: Enemies 2x HP", name));
+            },
+            "Midas" => {
+                benefits.push(format!("- {}: Gain Gold on Win", name));
+                downsides.push(format!("- {}: Lose Gold on Loss", name));
+            },
+            "Greed" => {
+                benefits.push(format!("- {}: +1 Hand, +1 Disc", name));
+                downsides.push(format!("- {}: -1 Relic Slot", name));
+            },
+            "Investment" => {
+                benefits.push(format!("- {}: +5% Interest", name));
+                downsides.push(format!("- {}: Start with $0", name));
+            },
+            "Merchant" => {
+                benefits.push(format!("- {}: +1 Shop Slot", name));
+                downsides.push(format!("- {}: Prices +20%", name));
+            },
+            "Mentalist" => {
+                benefits.push(format!("- {}: Free Scrolls", name));
+                downsides.push(format!("- {}: Less Scrolls", name));
+            },
+            "Evolution" => {
+                benefits.push(format!("- {}: Relics 1.5x Val", name));
+                downsides.push(format!("- {}: Enemies 1.5x HP", name));
+            },
+            "Force" => benefits.push(format!("- {}: +10 Base Mult", name)),
+            "Flow" => benefits.push(format!("- {}: +10 Base Chips", name)),
+            "Wealth" => benefits.push(format!("- {}: +3 Gold/Round", name)),
+            _ => {}
+        }
+    }
+
+    for id in stats.profile.equipped_heirlooms.iter().filter(|_| stats.daily.is_none()) {
+        if let Some(heirloom) = stats.all_heirlooms.get(id) {
+            benefits.push(format!("- {}: {}", heirloom.name, heirloom.description));
+        }
+    }
+
+    if let Some(challenge) = &stats.challenge {
+        downsides.push(format!("- Challenge {}: {}", challenge.name, challenge.description));
+    }
+
+    let mut rows = vec![("BENEFITS".to_string(), Color::GRAY)];
+    rows.extend(benefits.into_iter().map(|t| (t, NEU_GREEN)));
+    rows.push((String::new(), Color::GRAY));
+    rows.push(("TRADE-OFFS".to_string(), Color::GRAY));
+    rows.extend(downsides.into_iter().map(|t| (t, NEU_RED)));
+    rows
 }
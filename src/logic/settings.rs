@@ -0,0 +1,100 @@
+use raylib::prelude::*;
+use crate::structures::stats::BaseModifiers;
+use crate::structures::state::GameState;
+use crate::structures::settings::{Settings, RESOLUTIONS, FPS_CAPS, ANIMATION_SPEED_RANGE, GAME_SPEED_RANGE, SCREEN_SHAKE_RANGE};
+use crate::layout;
+use crate::widgets;
+use crate::input::Action;
+
+pub const SETTINGS_ROWS: [&str; 10] = [
+    "Resolution",
+    "Fullscreen",
+    "VSync (restart)",
+    "FPS Cap",
+    "Game Speed",
+    "Screen Shake",
+    "Animation Speed",
+    "Skip Scoring on Click",
+    "High Contrast Cards",
+    "Debug Logging",
+];
+
+pub fn value_text(settings: &Settings, row: usize) -> String {
+    let on_off = |b: bool| if b { "On".to_string() } else { "Off".to_string() };
+    match row {
+        0 => format!("{}x{}", settings.window_width, settings.window_height),
+        1 => on_off(settings.fullscreen),
+        2 => on_off(settings.vsync),
+        3 => format!("{}", settings.fps_cap),
+        4 => format!("{:.2}x", settings.game_speed),
+        5 => format!("{:.0}%", settings.screen_shake * 100.0),
+        6 => format!("{:.1}x", settings.animation_speed),
+        7 => on_off(settings.skip_scoring_on_click),
+        8 => on_off(settings.high_contrast),
+        9 => on_off(settings.debug_logging),
+        _ => String::new(),
+    }
+}
+
+// Steps one setting left (-1) or right (+1)
+fn change_setting(settings: &mut Settings, row: usize, dir: i32) {
+    let cycle = |idx: usize, len: usize| ((idx as i32 + dir).rem_euclid(len as i32)) as usize;
+    match row {
+        0 => {
+            let (w, h) = RESOLUTIONS[cycle(settings.resolution_index(), RESOLUTIONS.len())];
+            settings.window_width = w;
+            settings.window_height = h;
+        }
+        1 => settings.fullscreen = !settings.fullscreen,
+        2 => settings.vsync = !settings.vsync,
+        3 => settings.fps_cap = FPS_CAPS[cycle(settings.fps_index(), FPS_CAPS.len())],
+        4 => settings.game_speed = (settings.game_speed + 0.25 * dir as f32).clamp(GAME_SPEED_RANGE.0, GAME_SPEED_RANGE.1),
+        5 => settings.screen_shake = (settings.screen_shake + 0.25 * dir as f32).clamp(SCREEN_SHAKE_RANGE.0, SCREEN_SHAKE_RANGE.1),
+        6 => settings.animation_speed = (settings.animation_speed + 0.5 * dir as f32).clamp(ANIMATION_SPEED_RANGE.0, ANIMATION_SPEED_RANGE.1),
+        7 => settings.skip_scoring_on_click = !settings.skip_scoring_on_click,
+        8 => settings.high_contrast = !settings.high_contrast,
+        9 => settings.debug_logging = !settings.debug_logging,
+        _ => {}
+    }
+}
+
+// Pushes settings into the window and anything that mirrors them
+pub fn apply_settings(rl: &mut RaylibHandle, stats: &mut BaseModifiers) {
+    crate::window_init::apply_display_settings(rl, &stats.settings);
+    stats.game_metrics.verbose = stats.settings.debug_logging;
+}
+
+// Keyboard focus rows: every setting, then the Controls and Back buttons
+pub const FOCUS_CONTROLS: usize = SETTINGS_ROWS.len();
+pub const FOCUS_BACK: usize = SETTINGS_ROWS.len() + 1;
+
+pub fn update_settings(rl: &mut RaylibHandle, state: &mut GameState, stats: &mut BaseModifiers) {
+    stats.actions.step(&mut stats.focus_row, FOCUS_BACK + 1, Action::Up, Action::Down);
+    let confirm = stats.actions.pressed(Action::Confirm);
+
+    if stats.actions.pressed(Action::Back) || widgets::clicked(rl, layout::settings_back_button()) || (confirm && stats.focus_row == FOCUS_BACK) {
+        *state = stats.previous_state;
+        return;
+    }
+    if widgets::clicked(rl, layout::settings_controls_button()) || (confirm && stats.focus_row == FOCUS_CONTROLS) {
+        *state = GameState::Controls;
+        return;
+    }
+
+    for row in 0..SETTINGS_ROWS.len() {
+        let focused = stats.focus_row == row;
+        let (left, right) = layout::settings_arrows(row);
+        let dir = if widgets::clicked(rl, left) || (focused && stats.actions.pressed(Action::Left)) {
+            -1
+        } else if widgets::clicked(rl, right) || (focused && (stats.actions.pressed(Action::Right) || confirm)) {
+            1
+        } else {
+            continue;
+        };
+
+        change_setting(&mut stats.settings, row, dir);
+        apply_settings(rl, stats);
+        stats.settings.save();
+        break;
+    }
+}
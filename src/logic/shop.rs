@@ -2,9 +2,12 @@ use raylib::prelude::*;
 use rand::{self, Rng, seq::SliceRandom};
 use crate::structures::stats::BaseModifiers;
 use crate::structures::state::GameState;
-use crate::structures::card::Card;
 use crate::structures::relic::GameRelic;
+use crate::logic::relics::{self, Lifecycle};
 use crate::consts::*;
+use crate::layout;
+use crate::widgets;
+use crate::input::Action;
 
 // --- INITIALIZATION ---
 pub fn init_shop(stats: &mut BaseModifiers) {
@@ -16,37 +19,43 @@ pub fn init_shop(stats: &mut BaseModifiers) {
 
     // 3. Roll for new items (3 Slots)
     let num_slots = 3;
-    let mut rng = rand::thread_rng();
-
     for _ in 0..num_slots {
         // --- THE D20 MECHANIC ---
-        let roll = rng.gen_range(1..=20);
+        let roll = stats.rng.gen_range(1..=20);
         let target_rarity = roll_rarity(roll);
 
         println!("Shop Roll: {} -> Looking for {}", roll, target_rarity);
 
         // Filter the Database by Rarity
         // We look at all_relics (loaded in stats)
-        let pool: Vec<&GameRelic> = stats.all_relics.values()
+        let mut pool: Vec<&GameRelic> = stats.all_relics.values()
             .filter(|r| r.data.rarity == target_rarity)
             .collect();
+        pool.sort_by(|a, b| a.data.id.cmp(&b.data.id)); // Deterministic for the seeded rng
 
         // Fallback: If we rolled "Mythic" but have none in the DB, show "Common"
         let final_pool = if pool.is_empty() {
-            stats.all_relics.values()
+            let mut common: Vec<&GameRelic> = stats.all_relics.values()
                 .filter(|r| r.data.rarity == "Common")
-                .collect()
+                .collect();
+            common.sort_by(|a, b| a.data.id.cmp(&b.data.id));
+            common
         } else {
             pool
         };
 
         // Pick a random item from the pool
-        if let Some(relic) = final_pool.choose(&mut rng) {
+        if let Some(relic) = final_pool.choose(&mut stats.rng) {
             stats.current_shop_relics.push((*relic).clone());
         }
     }
 }
 
+pub fn leave(stats: &mut BaseModifiers, state: &mut GameState) {
+    relics::fire(stats, Lifecycle::ShopExit);
+    *state = GameState::Map;
+}
+
 // Helper: The D20 Table
 fn roll_rarity(roll: i32) -> String {
     match roll {
@@ -60,10 +69,8 @@ fn roll_rarity(roll: i32) -> String {
 }
 
 // --- UPDATE LOOP ---
-pub fn update_shop(rl: &mut RaylibHandle, state: &mut GameState, stats: &mut BaseModifiers, deck: &mut Vec<Card>) {
+pub fn update_shop(rl: &mut RaylibHandle, state: &mut GameState, stats: &mut BaseModifiers) {
     let dt = rl.get_frame_time();
-    let mouse_pos = rl.get_mouse_position();
-    let clicked = rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT);
 
     // 1. Slide Animation (Slide Up to 0)
     if stats.shop_y_offset > 0.0 {
@@ -74,17 +81,14 @@ pub fn update_shop(rl: &mut RaylibHandle, state: &mut GameState, stats: &mut Bas
     }
 
     // 2. Next Round Button Logic
-    // Located at bottom right, moves with the panel
-    let btn_w = 200.0;
-    let btn_h = 60.0;
-    let btn_x = SCREEN_WIDTH - btn_w - 50.0;
-    let btn_y = SCREEN_HEIGHT - 100.0 + stats.shop_y_offset; // Follows animation
-    let next_rect = Rectangle::new(btn_x, btn_y, btn_w, btn_h);
+    // Keyboard focus walks the shop items, then the Leave button
+    let leave_index = stats.current_shop_relics.len();
+    stats.actions.step(&mut stats.focus_col, leave_index + 1, Action::Left, Action::Right);
+    let leave_pressed = stats.actions.pressed(Action::Confirm) && stats.focus_col == leave_index;
 
-    if clicked && next_rect.check_collision_point_rec(mouse_pos) {
-        // Start the next fight
-        crate::logic::game::start_next_round(stats, deck);
-        *state = GameState::Playing;
+    // The "Leave" button, moves with the panel
+    if widgets::clicked(rl, layout::shop_leave_button(stats.shop_y_offset)) || leave_pressed {
+        leave(stats, state);
     }
 
     // 3. Buying Logic (Placeholder)
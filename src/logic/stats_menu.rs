@@ -1,49 +1,45 @@
 use raylib::prelude::*;
 use crate::structures::stats::BaseModifiers;
 use crate::structures::state::GameState;
-use crate::consts::*;
+use crate::layout;
+use crate::widgets;
+use crate::input::Action;
+use crate::logic::daily;
+use crate::structures::daily::RunAction;
+
+pub const STATS_FOCUS_CLOSE: usize = 3;
 
 pub fn update_stats_menu(rl: &RaylibHandle, state: &mut GameState, stats: &mut BaseModifiers) {
-    if rl.is_key_pressed(KeyboardKey::KEY_ESCAPE) {
+    // Keyboard focus: the three upgrade rows, then the close button
+    stats.actions.step(&mut stats.focus_row, STATS_FOCUS_CLOSE + 1, Action::Up, Action::Down);
+    let confirm = stats.actions.pressed(Action::Confirm);
+    let activated = |row: usize| widgets::clicked(rl, layout::stats_upgrade_button(row)) || (confirm && stats.focus_row == row);
+
+    if stats.actions.pressed(Action::Back) || stats.actions.pressed(Action::Stats) {
         *state = GameState::Playing;
     }
 
-    let mouse_pos = rl.get_mouse_position();
-    let clicked = rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT);
-
-    let center_x = SCREEN_WIDTH / 2.0;
-    let center_y = SCREEN_HEIGHT / 2.0;
-    let box_w = 600.0;
-    let box_h = 500.0;
-    let start_y = center_y - 100.0;
-
-    let close_btn_x = center_x + box_w/2.0 - 45.0;
-    let close_btn_y = center_y - box_h/2.0 + 15.0;
-    let close_btn = Rectangle::new(close_btn_x, close_btn_y, 30.0, 30.0);
-
-    if clicked && close_btn.check_collision_point_rec(mouse_pos) {
+    if widgets::clicked(rl, layout::stats_menu_close_button()) || (confirm && stats.focus_row == STATS_FOCUS_CLOSE) {
         *state = GameState::Playing;
     }
 
-    if stats.stat_points > 0 && clicked {
-        let btn_w = 120.0;
-        let btn_h = 40.0;
-        let btn_x = center_x + 100.0;
-
-        let rect_hp = Rectangle::new(btn_x, start_y, btn_w, btn_h);
-        let rect_crit = Rectangle::new(btn_x, start_y + 60.0, btn_w, btn_h);
-        let rect_dmg = Rectangle::new(btn_x, start_y + 120.0, btn_w, btn_h);
+    let picked = (0..STATS_FOCUS_CLOSE).find(|&row| activated(row));
+    if let Some(row) = picked.filter(|_| stats.stat_points > 0) {
+        daily::record(stats, RunAction::Upgrade(row));
+        upgrade(stats, row);
+    }
+}
 
-        if rect_hp.check_collision_point_rec(mouse_pos) {
+// Spends a stat point on row 0 (max HP), 1 (crit chance) or 2 (crit mult)
+pub fn upgrade(stats: &mut BaseModifiers, row: usize) {
+    match row {
+        0 => {
             stats.max_hp += 10;
             stats.current_hp += 10;
-            stats.stat_points -= 1;
-        } else if rect_crit.check_collision_point_rec(mouse_pos) {
-            stats.crit_chance += 0.05;
-            stats.stat_points -= 1;
-        } else if rect_dmg.check_collision_point_rec(mouse_pos) {
-            stats.crit_mult += 0.5;
-            stats.stat_points -= 1;
         }
+        1 => stats.crit_chance += 0.05,
+        2 => stats.crit_mult += 0.5,
+        _ => return,
     }
+    stats.stat_points -= 1;
 }
@@ -7,108 +7,122 @@ mod poker;
 mod bench;
 mod utils;
 mod score_manager;
+mod layout; // New (Virtual canvas + shared rects)
+mod widgets; // New (Immediate-mode buttons/panels)
+mod input; // New (Keyboard/gamepad action map)
+mod data_validation; // New (assets/data schema checks)
+mod scripting; // New (Rhai relic scripts, `scripting` feature)
 
-use raylib::prelude::*;
-use structures::card::Card;
 use structures::stats::BaseModifiers;
+use structures::settings::Settings;
+use structures::profile::Profile;
 use structures::assets::GameAssets;
 use structures::state::{GameState, AnimationState};
-use consts::*;
 use std::time::Instant;
 
 fn main() {
-    let (mut rl, thread) = window_init::initialize_window();
+    // Checks daily results headlessly and exits, see logic::daily::verify_cli
+    let args: Vec<String> = std::env::args().collect();
+    if let Some(i) = args.iter().position(|arg| arg == "--verify-daily") {
+        std::process::exit(logic::daily::verify_cli(args.get(i + 1).map(String::as_str)));
+    }
+
+    let settings = Settings::load();
+    let (mut rl, thread) = window_init::initialize_window(&settings);
     let mut stats = BaseModifiers::default();
+    stats.settings = settings;
+    stats.profile = Profile::load();
+    logic::settings::apply_settings(&mut rl, &mut stats);
 
     // 1. Load Assets & All Game Data
     let mut assets = GameAssets::load(&mut rl, &thread);
-    structures::data_loader::load_all_data(&mut assets);
+    let strict_data = std::env::args().any(|arg| arg == "--strict-data");
+    let data_report = structures::data_loader::load_all_data(&mut assets.data, &stats.settings.disabled_mods, strict_data);
+    assets.mod_icons_dirty = true;
 
-    println!("> Loaded {} Relics", assets.relics_db.len());
-    println!("> Loaded {} Runes", assets.runes_db.len());
+    println!("> Loaded {} Relics", assets.data.relics_db.len());
+    println!("> Loaded {} Runes", assets.data.runes_db.len());
 
-    // 2. Transfer Data References to Stats
-    stats.all_relics = assets.relics_db.clone();
-    stats.all_consumables = assets.consumables_db.clone();
-    stats.all_heirlooms = assets.heirlooms_db.clone();
-    stats.enemy_database = Some(assets.enemies_db.clone());
-    stats.available_runes = assets.runes_db.values().cloned().collect();
+    // 2. Start a run on the loaded data (seed + first map). Deck and hand are dealt per fight.
+    stats = BaseModifiers::new_run(&assets.data, stats.settings.clone(), stats.profile.clone(), logic::map::random_seed());
+    if data_report.error_count() > 0 {
+        logic::hot_reload::toast_report(&mut stats, &data_report, "Game data loaded");
+    }
+    logic::mods::check_profile_mods(&mut stats);
 
-    let mut current_state = GameState::RuneSelect;
-    let mut animation_state = AnimationState::Idle;
+    let mut current_state = GameState::Menu;
     let mut bench = bench::GameBench::new();
+    let mut data_watcher = logic::hot_reload::DataWatcher::new();
 
-    // 3. Initialize Game State (Deck, Enemy, Hand)
-    // --- FIX START ---
     let mut deck = Vec::new();
     let mut hand = Vec::new();
-
-    // A. Generate Deck & Enemy via Logic (Uses the shuffle logic from game.rs)
-    logic::game::start_next_round(&mut stats, &mut deck);
-    stats.round = 1; // Reset round count to 1
-
-    // B. Deal Initial Hand (So we don't start with 0 cards)
-    while hand.len() < stats.hand_size as usize {
-        if let Some(mut card) = deck.pop() {
-            card.current_pos = Vector2::new(DECK_X, DECK_Y);
-            hand.push(card);
-        } else {
-            break;
-        }
-    }
-
-    // Sort for visual clarity
-    stats.current_sort = structures::stats::SortMode::Rank;
-    hand.sort_by(|a, b| b.value.cmp(&a.value).then(a.suit.cmp(&b.suit)));
-    // --- FIX END ---
+    let mut animation_state = AnimationState::Idle;
 
     while !rl.window_should_close() {
         let frame_start = bench.start_frame();
-        let dt = rl.get_frame_time();
+        let dt = rl.get_frame_time() * stats.settings.game_speed;
+
+        // Debug mode: pick up edits to assets/data without a restart
+        if stats.settings.debug_logging && data_watcher.changed(rl.get_frame_time()) {
+            logic::hot_reload::reload(&mut assets, &mut stats);
+        }
+        if assets.mod_icons_dirty {
+            assets.load_mod_icons(&mut rl, &thread);
+        }
+
+        // Map the mouse into canvas space so every hit-test below uses layout:: rects as-is
+        layout::Viewport::current(&rl).apply_mouse_transform(&mut rl);
+        stats.actions = input::poll(&rl, &stats.settings.bindings, &stats.actions);
+        let state_before = current_state;
 
         let update_start = Instant::now();
         match current_state {
-            GameState::Menu => logic::update_menu(&rl, &mut current_state),
+            GameState::Menu => logic::update_menu(&rl, &mut current_state, &mut stats),
+            GameState::Settings => logic::update_settings(&mut rl, &mut current_state, &mut stats),
+            GameState::Controls => logic::update_controls(&rl, &mut current_state, &mut stats),
+            GameState::Mods => logic::update_mods(&rl, &mut current_state, &mut stats, &mut assets),
+            GameState::ChallengeSelect => logic::update_challenge_select(&rl, &mut current_state, &mut stats),
             GameState::RuneSelect => logic::update_rune_select(&rl, &mut current_state, &mut stats),
-            GameState::Playing => logic::update_game(&rl, &mut hand, &mut deck, &mut stats, dt, &mut current_state, &mut animation_state),
-            GameState::BattleResult => logic::update_battle_result(&mut rl, &mut current_state, &mut stats),
-            GameState::StatsMenu => logic::update_stats_menu(&rl, &mut current_state, &mut stats),
-            GameState::Shop => logic::update_shop(&mut rl, &mut current_state, &mut stats, &mut deck),
-            GameState::GameOver => {
-                if rl.is_key_pressed(KeyboardKey::KEY_R) {
-                    // Reset Logic
-                    let saved_db = stats.enemy_database.clone();
-                    let saved_relics = stats.all_relics.clone();
-                    let saved_runes = stats.available_runes.clone();
-
-                    stats = BaseModifiers::default();
-                    stats.enemy_database = saved_db;
-                    stats.all_relics = saved_relics;
-                    stats.available_runes = saved_runes;
-
-                    // Re-init deck & hand using the same logic
-                    deck.clear();
-                    hand.clear();
-                    logic::game::start_next_round(&mut stats, &mut deck);
-                    stats.round = 1;
-
-                    while hand.len() < stats.hand_size as usize {
-                        if let Some(mut card) = deck.pop() {
-                            card.current_pos = Vector2::new(DECK_X, DECK_Y);
-                            hand.push(card);
-                        } else { break; }
-                    }
-                    hand.sort_by(|a, b| b.value.cmp(&a.value).then(a.suit.cmp(&b.suit)));
-
-                    stats.deck_count = deck.len() as i32;
-                    current_state = GameState::RuneSelect;
+            GameState::Map => logic::update_map(&rl, &mut current_state, &mut stats, &mut hand, &mut deck, &mut animation_state),
+            GameState::Event => logic::update_event(&rl, &mut current_state, &mut stats),
+            GameState::Playing => {
+                let back = stats.actions.pressed(input::Action::Back);
+                if back && stats.inspected_card.is_some() {
+                    stats.inspected_card = None;
+                } else if back && animation_state == AnimationState::Idle {
+                    stats.previous_state = GameState::Playing;
+                    current_state = GameState::Settings;
+                } else {
+                    logic::update_game(&rl, &mut hand, &mut deck, &mut stats, dt, &mut current_state, &mut animation_state);
                 }
             }
-            _ => {}
+            GameState::BattleResult => logic::update_battle_result(&mut rl, &mut current_state, &mut stats, dt),
+            GameState::StatsMenu => logic::update_stats_menu(&rl, &mut current_state, &mut stats),
+            GameState::Shop => logic::update_shop(&mut rl, &mut current_state, &mut stats),
+            GameState::Daily => logic::update_daily(&rl, &mut current_state, &mut stats, &assets),
+            GameState::RunEnd => logic::update_run_end(&mut rl, &mut current_state, &mut stats, &assets, &mut hand, &mut deck, &mut animation_state),
+            GameState::Exit => break,
         }
         bench.record_update(update_start.elapsed());
 
+        // Every screen starts with keyboard focus on its first widget
+        if current_state != state_before {
+            stats.focus_row = 0;
+            stats.focus_col = 0;
+            // Back on the map is a safe point to save; a lost run has nothing to continue.
+            // A finished daily goes on the leaderboard.
+            match current_state {
+                GameState::Map => structures::save::RunSave::capture(&stats).save(),
+                GameState::RunEnd => {
+                    structures::save::RunSave::delete();
+                    logic::daily::file_result(&mut stats);
+                }
+                _ => {}
+            }
+        }
+
         stats.update_screen_shake(dt);
+        stats.update_vfx(dt);
         stats.update_cached_strings();
 
         let draw_start = Instant::now();
@@ -118,7 +132,7 @@ fn main() {
         drop(d);
         bench.end_frame(frame_start);
 
-        if let Some(report) = bench.report() {
+        if let Some(report) = bench.report() && stats.settings.debug_logging {
             println!("{}", report);
         }
     }
@@ -1,7 +1,7 @@
 use crate::structures::card::Card;
 use crate::structures::hand::HandRank;
 use std::collections::HashMap;
-use crate::structures::stats::{BaseModifiers, BossAbility}; // Added this import
+use crate::structures::stats::BaseModifiers;
 // ... [get_counts, is_flush, is_straight, get_hand_base_score, get_card_chip_value remain exactly the same] ...
 // (Paste previous helper functions here if replacing file, or append this new function)
 
@@ -39,6 +39,11 @@ fn is_straight(hand: &[Card]) -> bool {
     straight
 }
 
+// Ten through Ace (checked on top of is_straight, so the wheel doesn't count)
+fn is_royal(hand: &[Card]) -> bool {
+    (10..=14).all(|v| hand.iter().any(|card| card.value == v))
+}
+
 pub fn get_hand_base_score(rank: HandRank) -> (i32, i32) {
     match rank {
         HandRank::HighCard => (5, 1),
@@ -50,6 +55,7 @@ pub fn get_hand_base_score(rank: HandRank) -> (i32, i32) {
         HandRank::FullHouse => (40, 4),
         HandRank::FourOfAKind => (60, 7),
         HandRank::StraightFlush => (100, 8),
+        HandRank::RoyalFlush => (150, 10),
     }
 }
 
@@ -67,12 +73,13 @@ pub fn get_hand_rank(hand: &[Card], stats: &BaseModifiers) -> HandRank {
     let straight = is_straight(hand);
 
     if straight && flush {
-        if let BossAbility::SilenceSuit(silenced_suit) = stats.active_ability {
+        let rank = if is_royal(hand) { HandRank::RoyalFlush } else { HandRank::StraightFlush };
+        if let Some(silenced_suit) = stats.silenced_suit() {
             if hand[0].suit != silenced_suit {
-                return HandRank::StraightFlush;
+                return rank;
             }
         } else {
-            return HandRank::StraightFlush;
+            return rank;
         }
     }
 
@@ -92,7 +99,7 @@ pub fn get_hand_rank(hand: &[Card], stats: &BaseModifiers) -> HandRank {
     if fours == 1 { return HandRank::FourOfAKind; }
     if threes == 1 && pairs == 1 { return HandRank::FullHouse; }
     if flush {
-        if let BossAbility::SilenceSuit(silenced_suit) = stats.active_ability {
+        if let Some(silenced_suit) = stats.silenced_suit() {
             if hand[0].suit != silenced_suit {
                 return HandRank::Flush;
             }
@@ -116,7 +123,7 @@ pub fn apply_relic_bonuses(stats: &mut BaseModifiers, hand: &[Card]) {
     for relic in relics {
         match relic.data.id.as_str() {
             "j_joker" => {
-                stats.mult += 4;
+                stats.mult += 4.0;
             },
             "j_greedy" => {
                 // Suit 1 is Diamonds (0=Heart, 1=Diamond, 2=Spade, 3=Club)
@@ -126,21 +133,21 @@ pub fn apply_relic_bonuses(stats: &mut BaseModifiers, hand: &[Card]) {
                 }
             },
             "j_duo" => {
-                if rank == HandRank::Pair { stats.mult *= 2; }
+                if rank == HandRank::Pair { stats.mult *= 2.0; }
             },
             "j_trio" => {
-                if rank == HandRank::ThreeOfAKind { stats.mult *= 3; }
+                if rank == HandRank::ThreeOfAKind { stats.mult *= 3.0; }
             },
             "j_family" => {
-                if rank == HandRank::FourOfAKind { stats.mult *= 4; }
+                if rank == HandRank::FourOfAKind { stats.mult *= 4.0; }
             },
             "relic_twin_daggers" => {
                 if rank == HandRank::Pair || rank == HandRank::TwoPair {
-                    stats.mult += 1;
+                    stats.mult += 1.0;
                 }
             },
             "relic_fading_torch" => {
-                stats.mult += 20;
+                stats.mult += 20.0;
             },
             _ => {}
         }
@@ -153,7 +160,7 @@ pub fn get_scoring_ids(hand: &[Card], stats: &BaseModifiers) -> Vec<i32> {
     let mut ids = Vec::new();
 
     match rank {
-        HandRank::StraightFlush | HandRank::Flush => {
+        HandRank::RoyalFlush | HandRank::StraightFlush | HandRank::Flush => {
             // For flush, we take the cards matching the dominant suit (should be all if is_flush is true)
             // But strict Balatro rules: Top 5 scoring cards if > 5.
             // Simplified: If it's a flush, all selected matching suit count.
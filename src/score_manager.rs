@@ -1,35 +1,127 @@
+use crate::structures::relic::{GameRelic, Relic, RelicEffect, ScoringContext};
 use crate::structures::card::Card;
-use crate::structures::relic::{GameRelic, RelicEffect}; // Import GameRelic and RelicEffect
+use crate::structures::score::Score;
+use crate::structures::trigger::{self, Trigger};
 
+// Where a scoring step came from, so the sequencer knows where to put the popup
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScoreSource {
+    Card(i32),    // card id
+    Relic(usize), // index into equipped_relics
+}
+
+// One entry of the breakdown: what changed and the running totals afterwards
+#[derive(Debug, Clone)]
+pub struct ScoreStep {
+    pub source: ScoreSource,
+    pub trigger: Trigger, // Queue entry that produced it (relic steps inside a card trigger share it)
+    pub chips_added: i32,
+    pub mult_added: f64,
+    pub x_mult: Option<f64>,
+    pub chips_after: i32,
+    pub mult_after: f64,
+}
+
+#[derive(Debug, Clone)]
 pub struct ScoreResult {
+    pub base_chips: i32,
+    pub base_mult: f64,
     pub chips: i32,
-    pub mult: i32,
-    pub total: i32, // chips * mult
+    pub mult: f64,   // f64 so repeated xMult doesn't round down after every relic
+    pub total: Score, // chips * mult
+    pub queue: Vec<Trigger>, // Every trigger in order, including ones that changed nothing
+    pub breakdown: Vec<ScoreStep>,
 }
 
-pub fn calculate_score(
-    hand: &[Card], 
-    relics: &[GameRelic], // Use the concrete GameRelic struct
-    base_chips: i32, 
-    base_mult: i32
-) -> ScoreResult {
+// Builds the trigger queue (see structures::trigger) and plays it out. Relic hooks see
+// the running totals through `context` and may update their own state.
+pub fn calculate_score(relics: &mut [GameRelic], mut context: ScoringContext) -> ScoreResult {
+    let base_chips = context.base_chips;
+    let base_mult = context.base_mult;
     let mut chips = base_chips;
-    let mut mult = base_mult;
+    let mut mult = base_mult as f64;
+    let mut breakdown = Vec::new();
 
-    // Step A: Add Played Card Chips
-    for card in hand {
-        chips += card.value; // Add card enhancements here if you have them
-    }
+    let played = context.played_cards;
+    let held = context.held_cards;
+    let played_counts: Vec<u32> = played.iter().map(|card| retriggers(relics, &context, card, false)).collect();
+    let held_counts: Vec<u32> = held.iter().map(|card| retriggers(relics, &context, card, true)).collect();
+    let queue = trigger::build_queue(&played_counts, &held_counts, relics.len());
+
+    for &trigger in &queue {
+        match trigger {
+            // The card's own chips / enhancement, then each relic's per-card hook
+            Trigger::Played { index, .. } => {
+                let card = &played[index];
+                let (mut chips_added, mut mult_added, mut x_mult) = (card.value, 0.0, None);
+                if let Some(enh) = card.enhancement {
+                    chips_added += enh.chips();
+                    mult_added += enh.mult();
+                    x_mult = enh.x_mult();
+                }
+                chips += chips_added;
+                mult += mult_added;
+                if let Some(x) = x_mult { mult *= x; }
+                breakdown.push(ScoreStep {
+                    source: ScoreSource::Card(card.id), trigger,
+                    chips_added, mult_added, x_mult,
+                    chips_after: chips, mult_after: mult,
+                });
 
-    // Step B: Apply Relics (Left-to-Right Order of Operations)
-    for relic in relics {
-        match relic.effect {
-            RelicEffect::PlusMult(m) => mult += m,
-            RelicEffect::PlusChips(c) => chips += c,
-            RelicEffect::XMult(x) => mult = (mult as f32 * x) as i32,
-            RelicEffect::None => {},
+                for (i, relic) in relics.iter_mut().enumerate() {
+                    context.set_current(chips, mult);
+                    let effect = relic.on_played_card_scored(&context, card);
+                    breakdown.extend(apply_relic_effect(effect, i, trigger, &mut chips, &mut mult));
+                }
+            }
+            // Held cards have no effect of their own yet; relics may give them one
+            Trigger::Held { index, .. } => {
+                let card = &held[index];
+                for (i, relic) in relics.iter_mut().enumerate() {
+                    context.set_current(chips, mult);
+                    let effect = relic.on_held_card(&context, card);
+                    breakdown.extend(apply_relic_effect(effect, i, trigger, &mut chips, &mut mult));
+                }
+            }
+            // Relics left to right
+            Trigger::Relic(i) => {
+                context.set_current(chips, mult);
+                let effect = relics[i].on_hand_scored(&context);
+                breakdown.extend(apply_relic_effect(effect, i, trigger, &mut chips, &mut mult));
+            }
         }
     }
 
-    ScoreResult { chips, mult, total: chips * mult }
-}
\ No newline at end of file
+    ScoreResult {
+        base_chips,
+        base_mult: base_mult as f64,
+        chips,
+        mult,
+        total: Score::from_chips_mult(chips as f64, mult),
+        queue,
+        breakdown,
+    }
+}
+
+// Extra triggers for one card: its seal plus whatever the relics add (capped in build_queue)
+fn retriggers(relics: &mut [GameRelic], context: &ScoringContext, card: &Card, held: bool) -> u32 {
+    let from_seal = card.seal.map_or(0, |s| s.retriggers());
+    relics.iter_mut().fold(from_seal, |n, relic| n.saturating_add(relic.retriggers(context, card, held)))
+}
+
+fn apply_relic_effect(effect: RelicEffect, index: usize, trigger: Trigger, chips: &mut i32, mult: &mut f64) -> Option<ScoreStep> {
+    let (chips_added, mult_added, x_mult) = match effect {
+        RelicEffect::PlusMult(m) => (0, m as f64, None),
+        RelicEffect::PlusChips(c) => (c, 0.0, None),
+        RelicEffect::XMult(x) => (0, 0.0, Some(x as f64)),
+        RelicEffect::None => return None,
+    };
+    *chips += chips_added;
+    *mult += mult_added;
+    if let Some(x) = x_mult { *mult *= x; }
+    Some(ScoreStep {
+        source: ScoreSource::Relic(index), trigger,
+        chips_added, mult_added, x_mult,
+        chips_after: *chips, mult_after: *mult,
+    })
+}
@@ -0,0 +1,286 @@
+// Relic scripts. A relic in relics.json can name a script file, relative to the folder
+// the json is in (so mods ship their own): "script": "scripts/twin_daggers.rhai".
+// The script implements any of the Relic trait hooks as Rhai functions:
+//
+//   fn init()                            starting state, e.g. #{ mult: 0 }
+//   fn on_hand_scored(ctx)               effect or ()
+//   fn on_played_card_scored(ctx, card)  effect or (), once per trigger of the card
+//   fn on_held_card(ctx, card)           effect or (), once per trigger of a held card
+//   fn retriggers(ctx, card)             extra times the card scores, e.g. 1 (or ());
+//                                        card.held says whether it's a held card
+//   fn on_hand_end(ctx)                  state updates only, the effect is ignored
+//   fn on_discard(ctx, card)             state updates only, the effect is ignored
+//   fn on_round_start(ctx), on_round_end(ctx), on_shop_enter(ctx), on_shop_exit(ctx),
+//      on_boss_defeated(ctx)             state updates only; ctx has no hand / card info
+//   fn describe()                        tooltip text, e.g. `+${this.mult} Mult`
+//
+// `this` is the relic's RelicState: counter, mult, chips, charges and destroyed, plus any
+// other whole-number keys the script adds. It's kept for the whole run and saved with it.
+// Setting `this.destroyed = true` removes the relic after the hook.
+// Effects are maps: #{ mult: 4 }, #{ chips: 30 } or #{ x_mult: 1.5 }.
+// `ctx` and `card` are read-only copies (see context_map / card_map). Scripts can't touch
+// anything else, and run under operation / depth / size limits so a bad one can't hang
+// the game.
+//
+// Without the `scripting` feature, scripts still load but every hook does nothing.
+use std::fs;
+use crate::structures::card::Card;
+use crate::structures::relic::{RelicEffect, RelicState, ScoringContext};
+use crate::structures::stats::BaseModifiers;
+
+#[derive(Debug, Clone)]
+pub struct RelicScript {
+    pub path: String,
+    #[cfg(feature = "scripting")]
+    ast: rhai::AST,
+}
+
+#[derive(Debug)]
+pub struct ScriptError {
+    pub line: usize, // 1-based, or 1 when the error has no position (e.g. unreadable file)
+    pub message: String,
+}
+
+#[cfg(feature = "scripting")]
+pub fn compile(path: &str) -> Result<RelicScript, ScriptError> {
+    let source = fs::read_to_string(path).map_err(|e| ScriptError { line: 1, message: format!("could not read script: {}", e) })?;
+    let ast = ENGINE.with(|engine| engine.compile(&source)).map_err(|e| ScriptError {
+        line: e.position().line().unwrap_or(1),
+        message: format!("script error: {}", e.err_type()),
+    })?;
+    Ok(RelicScript { path: path.to_string(), ast })
+}
+
+#[cfg(not(feature = "scripting"))]
+pub fn compile(path: &str) -> Result<RelicScript, ScriptError> {
+    fs::metadata(path).map_err(|e| ScriptError { line: 1, message: format!("could not read script: {}", e) })?;
+    println!("> Warning: built without the scripting feature, {} will do nothing", path);
+    Ok(RelicScript { path: path.to_string() })
+}
+
+#[cfg(feature = "scripting")]
+pub fn initial_state(script: &RelicScript) -> RelicState {
+    let mut this = rhai::Dynamic::from_map(rhai::Map::new());
+    match engine::call(script, "init", &mut this, ()) {
+        Ok(Some(value)) => engine::to_state(value),
+        Ok(None) => RelicState::default(),
+        Err(e) => {
+            println!("Script error in {} (init): {}", script.path, e);
+            RelicState::default()
+        }
+    }
+}
+
+#[cfg(not(feature = "scripting"))]
+pub fn initial_state(_script: &RelicScript) -> RelicState {
+    RelicState::default()
+}
+
+// Runs one hook. Errors (including hitting the step limit) are logged and count as no effect.
+#[cfg(feature = "scripting")]
+pub fn call_hook(script: &RelicScript, hook: &str, state: &mut RelicState, context: &ScoringContext, card: Option<&Card>) -> RelicEffect {
+    let mut this = engine::from_state(state);
+    let ctx = engine::context_map(context);
+    let result = match card {
+        Some(card) => engine::call(script, hook, &mut this, (ctx, engine::card_map(card))),
+        None => engine::call(script, hook, &mut this, (ctx,)),
+    };
+    *state = engine::to_state(this);
+
+    match result.and_then(|value| value.map_or(Ok(RelicEffect::None), engine::to_effect)) {
+        Ok(effect) => effect,
+        Err(e) => {
+            println!("Script error in {} ({}): {}", script.path, hook, e);
+            RelicEffect::None
+        }
+    }
+}
+
+#[cfg(not(feature = "scripting"))]
+pub fn call_hook(_script: &RelicScript, _hook: &str, _state: &mut RelicState, _context: &ScoringContext, _card: Option<&Card>) -> RelicEffect {
+    RelicEffect::None
+}
+
+// retriggers(): how many extra times `card` scores. Anything but a whole number is 0.
+#[cfg(feature = "scripting")]
+pub fn call_retriggers(script: &RelicScript, state: &mut RelicState, context: &ScoringContext, card: &Card, held: bool) -> u32 {
+    let mut this = engine::from_state(state);
+    let mut card = engine::card_map(card);
+    card.insert("held".into(), held.into());
+    let result = engine::call(script, "retriggers", &mut this, (engine::context_map(context), card));
+    *state = engine::to_state(this);
+    match result {
+        Ok(value) => value.and_then(|v| v.as_int().ok()).map_or(0, |n| n.max(0) as u32),
+        Err(e) => {
+            println!("Script error in {} (retriggers): {}", script.path, e);
+            0
+        }
+    }
+}
+
+#[cfg(not(feature = "scripting"))]
+pub fn call_retriggers(_script: &RelicScript, _state: &mut RelicState, _context: &ScoringContext, _card: &Card, _held: bool) -> u32 {
+    0
+}
+
+// Round / shop / boss hooks. They only change state, so any return value is ignored.
+#[cfg(feature = "scripting")]
+pub fn call_lifecycle(script: &RelicScript, hook: &str, state: &mut RelicState, stats: &BaseModifiers) {
+    let mut this = engine::from_state(state);
+    let result = engine::call(script, hook, &mut this, (engine::stats_map(stats),));
+    *state = engine::to_state(this);
+    if let Err(e) = result {
+        println!("Script error in {} ({}): {}", script.path, hook, e);
+    }
+}
+
+#[cfg(not(feature = "scripting"))]
+pub fn call_lifecycle(_script: &RelicScript, _hook: &str, _state: &mut RelicState, _stats: &BaseModifiers) {}
+
+// The script's describe() for tooltips, if it has one
+#[cfg(feature = "scripting")]
+pub fn describe(script: &RelicScript, state: &RelicState) -> Option<String> {
+    let mut this = engine::from_state(state);
+    match engine::call(script, "describe", &mut this, ()) {
+        Ok(value) => value.map(|v| v.to_string()),
+        Err(e) => {
+            println!("Script error in {} (describe): {}", script.path, e);
+            None
+        }
+    }
+}
+
+#[cfg(not(feature = "scripting"))]
+pub fn describe(_script: &RelicScript, _state: &RelicState) -> Option<String> {
+    None
+}
+
+#[cfg(feature = "scripting")]
+thread_local! {
+    static ENGINE: rhai::Engine = engine::sandboxed();
+}
+
+#[cfg(feature = "scripting")]
+mod engine {
+    use rhai::{Array, CallFnOptions, Dynamic, Engine, FuncArgs, Map, Scope, INT};
+    use super::{RelicScript, RelicState, ENGINE};
+    use crate::structures::card::{Card, SUIT_NAMES};
+    use crate::structures::relic::{RelicEffect, ScoringContext};
+    use crate::structures::stats::BaseModifiers;
+
+    const MAX_OPERATIONS: u64 = 20_000; // Per hook call; plenty for counters and card loops
+
+    pub fn sandboxed() -> Engine {
+        let mut engine = Engine::new();
+        engine.set_max_operations(MAX_OPERATIONS);
+        engine.set_max_call_levels(16);
+        engine.set_max_expr_depths(64, 32);
+        engine.set_max_string_size(1024);
+        engine.set_max_array_size(256);
+        engine.set_max_map_size(64);
+        // No `import` from disk and no eval
+        engine.set_module_resolver(rhai::module_resolvers::DummyModuleResolver::new());
+        engine.disable_symbol("eval");
+        engine.on_print(|text| println!("[script] {}", text));
+        engine.on_debug(|text, _, pos| println!("[script] {} ({})", text, pos));
+        engine
+    }
+
+    // Calls `hook` with `this` bound to the state. Ok(None) if the script doesn't define it
+    // or it returned ().
+    pub fn call(script: &RelicScript, hook: &str, this: &mut Dynamic, args: impl FuncArgs) -> Result<Option<Dynamic>, String> {
+        if !script.ast.iter_functions().any(|f| f.name == hook) {
+            return Ok(None);
+        }
+        let options = CallFnOptions::new().eval_ast(false).bind_this_ptr(this);
+        let value: Dynamic = ENGINE.with(|engine| engine.call_fn_with_options(options, &mut Scope::new(), &script.ast, hook, args))
+            .map_err(|e| e.to_string())?;
+        Ok((!value.is_unit()).then_some(value))
+    }
+
+    pub fn from_state(state: &RelicState) -> Dynamic {
+        let mut map: Map = state.vars.iter().map(|(k, v)| (k.as_str().into(), Dynamic::from(*v as INT))).collect();
+        map.insert("counter".into(), (state.counter as INT).into());
+        map.insert("mult".into(), (state.mult as INT).into());
+        map.insert("chips".into(), (state.chips as INT).into());
+        map.insert("charges".into(), (state.charges as INT).into());
+        map.insert("destroyed".into(), state.destroyed.into());
+        Dynamic::from_map(map)
+    }
+
+    // Named fields go back into place, other whole numbers into vars; anything else is dropped
+    pub fn to_state(value: Dynamic) -> RelicState {
+        let mut state = RelicState::default();
+        let Some(map) = value.try_cast::<Map>() else { return state; };
+        for (key, v) in map {
+            if key == "destroyed" {
+                state.destroyed = v.as_bool().unwrap_or(false);
+                continue;
+            }
+            let Ok(n) = v.as_int() else { continue; };
+            match key.as_str() {
+                "counter" => state.counter = n,
+                "mult" => state.mult = n,
+                "chips" => state.chips = n,
+                "charges" => state.charges = n,
+                _ => { state.vars.insert(key.to_string(), n); }
+            }
+        }
+        state
+    }
+
+    pub fn to_effect(value: Dynamic) -> Result<RelicEffect, String> {
+        let type_name = value.type_name();
+        let Some(map) = value.try_cast::<Map>() else {
+            return Err(format!("hook returned {}, expected a map like #{{ mult: 4 }} or nothing", type_name));
+        };
+        let number = |v: &Dynamic| v.as_float().ok().or_else(|| v.as_int().ok().map(|n| n as f64));
+        if let Some(n) = map.get("mult").and_then(number) {
+            Ok(RelicEffect::PlusMult(n as i32))
+        } else if let Some(n) = map.get("chips").and_then(number) {
+            Ok(RelicEffect::PlusChips(n as i32))
+        } else if let Some(x) = map.get("x_mult").and_then(number) {
+            Ok(RelicEffect::XMult(x as f32))
+        } else {
+            Err("effect map needs one of mult, chips or x_mult".to_string())
+        }
+    }
+
+    // What a script can see of the scoring context
+    pub fn context_map(context: &ScoringContext) -> Map {
+        let cards = |cards: &[Card]| -> Array { cards.iter().map(|c| Dynamic::from_map(card_map(c))).collect() };
+        let mut map = stats_map(context.stats_snapshot);
+        map.insert("chips".into(), (context.current_chips as INT).into());
+        map.insert("mult".into(), (context.current_mult as INT).into());
+        map.insert("score".into(), (context.current_score as INT).into());
+        map.insert("base_chips".into(), (context.base_chips as INT).into());
+        map.insert("base_mult".into(), (context.base_mult as INT).into());
+        map.insert("hand".into(), context.hand_rank.map_or("", |r| r.name()).into());
+        map.insert("played".into(), cards(context.played_cards).into());
+        map.insert("held".into(), cards(context.held_cards).into());
+        map
+    }
+
+    // The run-wide part of ctx, all that lifecycle hooks get
+    pub fn stats_map(stats: &BaseModifiers) -> Map {
+        let mut map = Map::new();
+        map.insert("money".into(), (stats.money as INT).into());
+        map.insert("hp".into(), (stats.current_hp as INT).into());
+        map.insert("max_hp".into(), (stats.max_hp as INT).into());
+        map.insert("ante".into(), (stats.ante as INT).into());
+        map.insert("round".into(), (stats.round as INT).into());
+        map.insert("hands_left".into(), (stats.hands_remaining as INT).into());
+        map.insert("discards_left".into(), (stats.discards_remaining as INT).into());
+        map
+    }
+
+    pub fn card_map(card: &Card) -> Map {
+        let mut map = Map::new();
+        map.insert("name".into(), card.display_name().into());
+        map.insert("value".into(), (card.value as INT).into());
+        map.insert("suit".into(), SUIT_NAMES.get(card.suit as usize).copied().unwrap_or("").into());
+        map.insert("enhancement".into(), card.enhancement.map_or("", |e| e.name()).into());
+        map.insert("seal".into(), card.seal.map_or("", |s| s.name()).into());
+        map
+    }
+}
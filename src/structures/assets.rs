@@ -4,8 +4,14 @@ use std::collections::HashMap;
 use crate::structures::relic::GameRelic;
 use crate::structures::consumable::Consumable; // NEW
 use crate::structures::heirloom::Heirloom;     // NEW
-use crate::structures::enemy::Enemy;           // NEW
+use crate::structures::enemy::Enemy;
+use crate::structures::endless::EndlessCurve;
+use crate::structures::challenge::Challenge;
+use crate::structures::deck::StartingDeck;           // NEW
 use crate::structures::stats::Rune;
+use crate::structures::event::EventData;
+use crate::structures::mods::ModInfo;
+use crate::data_validation::{relic_icon_file, rune_icon_file};
 
 pub struct GameAssets {
     pub tex_card_back: Texture2D,
@@ -26,13 +32,30 @@ pub struct GameAssets {
     pub rune_icons: HashMap<String, Texture2D>,
     // Store Relic Icons
     pub relic_icons: HashMap<String, Texture2D>,
+    // Relic / rune icons from enabled mods, by name. Checked before the base ones.
+    pub mod_icons: HashMap<String, Texture2D>,
+    pub mod_icons_dirty: bool, // Set after each data load; main reloads them (needs the GL thread)
 
     // Databases (Loaded via JSON later)
+    pub data: GameData,
+}
+
+// Everything loaded from the data files, apart from the textures so it also loads without
+// a window (daily replays, see logic::daily)
+#[derive(Default)]
+pub struct GameData {
     pub relics_db: HashMap<String, GameRelic>,
     pub consumables_db: HashMap<String, Consumable>,
     pub heirlooms_db: HashMap<String, Heirloom>,
     pub enemies_db: HashMap<String, Enemy>,
+    pub endless_curve: EndlessCurve, // enemies.json "endless"; the last file that sets it wins
     pub runes_db: HashMap<String, Rune>,
+    pub events_db: HashMap<String, EventData>,
+    pub challenges_db: HashMap<String, Challenge>,
+    pub decks_db: HashMap<String, StartingDeck>,
+
+    // Everything found under mods/, in load order, with what loading each one did
+    pub mods: Vec<ModInfo>,
 }
 
 impl GameAssets {
@@ -123,13 +146,44 @@ impl GameAssets {
             font_main,
             rune_icons,
             relic_icons,
+            mod_icons: HashMap::new(),
+            mod_icons_dirty: false,
 
             // --- NEW FIELDS INIT (Empty Maps) ---
-            relics_db: HashMap::new(),
-            consumables_db: HashMap::new(),
-            heirlooms_db: HashMap::new(),
-            enemies_db: HashMap::new(),
-            runes_db: HashMap::new(),
+            data: GameData::default(),
         }
     }
+
+    // Looks for an icon named after every loaded relic and rune in each enabled mod's
+    // folder. Later mods win, like they do for the data itself.
+    pub fn load_mod_icons(&mut self, rl: &mut RaylibHandle, thread: &RaylibThread) {
+        self.mod_icons_dirty = false;
+        self.mod_icons.clear();
+
+        let mut files: Vec<(String, String)> = self.data.relics_db.values()
+            .map(|r| (r.data.name.clone(), relic_icon_file(&r.data.name)))
+            .collect();
+        files.extend(self.data.runes_db.values().map(|r| (r.name.clone(), rune_icon_file(&format!("{:?}", r.rune_type), &r.name))));
+
+        for info in self.data.mods.iter().filter(|m| m.is_active()) {
+            for (name, file) in &files {
+                let path = format!("{}/{}", info.folder, file);
+                if !std::path::Path::new(&path).exists() {
+                    continue;
+                }
+                match rl.load_texture(thread, &path) {
+                    Ok(tex) => {
+                        tex.set_texture_filter(thread, TextureFilter::TEXTURE_FILTER_BILINEAR);
+                        self.mod_icons.insert(name.clone(), tex);
+                        println!("Loaded Mod Icon: {} ({})", name, info.id());
+                    }
+                    Err(e) => println!("> Warning: Could not load mod icon {}: {}", path, e),
+                }
+            }
+        }
+    }
+
+    pub fn rune_icon(&self, name: &str) -> Option<&Texture2D> {
+        self.mod_icons.get(name).or_else(|| self.rune_icons.get(name))
+    }
 }
\ No newline at end of file
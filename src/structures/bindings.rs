@@ -0,0 +1,35 @@
+// Rebinding one action's keys (or pad buttons) without ever leaving two actions on the
+// same input or an action with nothing bound. Generic over the action so it doesn't
+// pull in raylib through input::Action.
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rebind<A> {
+    Bound,      // The input was free (or already this action's)
+    Swapped(A), // Taken from this action, which got the old binding in its place
+    Refused(A), // This action's only binding, and there was nothing to give it back
+}
+
+// Binds `action` to this one input. If another action already had it, that action takes
+// over `action`'s old binding in its place (a swap). When there is nothing to give it
+// (`action` had no binding, or only ones the other action has too) the list is left alone.
+pub fn rebind<A: Ord + Copy>(list: &mut BTreeMap<A, Vec<String>>, action: A, name: &str) -> Rebind<A> {
+    let old = list.get(&action).cloned().unwrap_or_default();
+    let taken = list.iter_mut().find_map(|(&other, names)| {
+        let at = names.iter().position(|n| n == name)?;
+        (other != action).then_some((other, names, at))
+    });
+    let swapped = match taken {
+        Some((other, names, at)) => {
+            let given: Vec<String> = old.into_iter().filter(|n| n != name && !names.contains(n)).collect();
+            if given.is_empty() {
+                return Rebind::Refused(other);
+            }
+            names.splice(at..=at, given);
+            Rebind::Swapped(other)
+        }
+        None => Rebind::Bound,
+    };
+    list.insert(action, vec![name.to_string()]);
+    swapped
+}
@@ -1,5 +1,14 @@
 use raylib::prelude::*;
 use crate::structures::tween::Tween; // Import the new Tween struct
+use crate::consts::DECK_X;
+
+pub use crate::structures::card_data::{Enhancement, Seal, SUIT_NAMES};
+
+impl Seal {
+    pub fn color(&self) -> Color {
+        match self { Seal::Red => Color::new(220, 40, 40, 255) }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct Card {
@@ -22,6 +31,8 @@ pub struct Card {
     pub is_pressed: bool,
     pub click_pos: Vector2,
     pub tween: Option<Tween>, // New tween field
+    pub enhancement: Option<Enhancement>,
+    pub seal: Option<Seal>,
 }
 
 impl Card {
@@ -37,10 +48,55 @@ impl Card {
             is_dragging: false, is_pressed: false,
             click_pos: Vector2::zero(),
             tween: None, // Initialize tween as None
+            enhancement: None,
+            seal: None,
         }
     }
 
+    // e.g. "Ace of Spades"
+    pub fn display_name(&self) -> String {
+        let rank = match self.value {
+            11 => "Jack".to_string(),
+            12 => "Queen".to_string(),
+            13 => "King".to_string(),
+            14 => "Ace".to_string(),
+            v => v.to_string(),
+        };
+        let suit = SUIT_NAMES.get(self.suit as usize).unwrap_or(&"?");
+        format!("{} of {}", rank, suit)
+    }
+
     pub fn move_to(&mut self, dest: Vector2, duration: f32) {
         self.tween = Some(Tween::new(self.current_pos, dest, duration));
     }
-}
\ No newline at end of file
+
+    // Advances the running tween and copies its channels onto the card
+    pub fn update_tween(&mut self, dt: f32) {
+        let Some(tween) = &mut self.tween else { return; };
+        tween.update(dt);
+        self.current_pos = tween.solve();
+        if let Some(scale) = tween.solve_scale() { self.scale = scale; }
+        if let Some(rotation) = tween.solve_rotation() { self.rotation = rotation; }
+
+        if tween.is_finished() {
+            self.current_pos = tween.end;
+            if let Some(scale) = tween.end_scale() { self.scale = scale; }
+            if let Some(rotation) = tween.end_rotation() { self.rotation = rotation; }
+            self.tween = None;
+        }
+    }
+}
+
+// The 52 cards every run starts with
+pub fn standard_deck() -> Vec<Card> {
+    let mut deck = Vec::new();
+    for suit in 0..4 {
+        for value in 2..=14 {
+            let mut card = Card::new(deck.len() as i32, DECK_X);
+            card.suit = suit;
+            card.value = value;
+            deck.push(card);
+        }
+    }
+    deck
+}
@@ -0,0 +1,78 @@
+// What the data files say about cards: suits, enhancements, seals and card names like
+// "Ace of Spades". Kept apart from card.rs (which needs raylib) so data_validation can
+// check names against these directly. Seal::color lives in card.rs.
+use serde::{Deserialize, Serialize};
+
+// Card modifiers applied by scrolls (Effect::ModifyCard)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Enhancement {
+    Bonus, // +30 Chips
+    Mult,  // +4 Mult
+    Glass, // x2 Mult
+}
+
+impl Enhancement {
+    pub fn chips(&self) -> i32 {
+        match self { Enhancement::Bonus => 30, _ => 0 }
+    }
+
+    pub fn mult(&self) -> f64 {
+        match self { Enhancement::Mult => 4.0, _ => 0.0 }
+    }
+
+    pub fn x_mult(&self) -> Option<f64> {
+        match self { Enhancement::Glass => Some(2.0), _ => None }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Enhancement::Bonus => "Bonus Card",
+            Enhancement::Mult => "Mult Card",
+            Enhancement::Glass => "Glass Card",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Enhancement::Bonus => "+30 Chips when scored.",
+            Enhancement::Mult => "+4 Mult when scored.",
+            Enhancement::Glass => "x2 Mult when scored.",
+        }
+    }
+}
+
+// Stamped on a card by effects (Effect::AddSeal); one per card
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Seal {
+    Red, // Retrigger this card 1 time
+}
+
+impl Seal {
+    pub fn retriggers(&self) -> u32 {
+        match self { Seal::Red => 1 }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self { Seal::Red => "Red Seal" }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self { Seal::Red => "Retrigger this card 1 time." }
+    }
+}
+
+pub const SUIT_NAMES: [&str; 4] = ["Hearts", "Diamonds", "Spades", "Clubs"];
+
+// Parses a display name back into (suit, value), e.g. "Queen of Hearts" -> (0, 12)
+pub fn parse_card_name(name: &str) -> Option<(i32, i32)> {
+    let (rank, suit) = name.split_once(" of ")?;
+    let value = match rank {
+        "Jack" => 11,
+        "Queen" => 12,
+        "King" => 13,
+        "Ace" => 14,
+        v => v.parse().ok().filter(|v| (2..=10).contains(v))?,
+    };
+    let suit = SUIT_NAMES.iter().position(|s| *s == suit)? as i32;
+    Some((suit, value))
+}
@@ -0,0 +1,48 @@
+use serde::Deserialize;
+use crate::structures::deck::DeckSpec;
+use crate::structures::effect::Effect;
+
+// Preset run from challenges.json, picked on the challenge select screen. Everything is
+// applied once at run start (logic::challenge::apply); the rules keep working all run.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Challenge {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub runes: Vec<String>, // Rune ids; when set the loadout is fixed
+    #[serde(default)]
+    pub deck: DeckSpec,
+    #[serde(default)]
+    pub banned_relics: Vec<String>,
+    #[serde(default)]
+    pub effects: Vec<Effect>, // e.g. ModifyPlayerStats on hand_size / hands_per_fight
+    #[serde(default)]
+    pub rules: Vec<ChallengeRule>,
+}
+
+impl Challenge {
+    pub fn allows_relic(&self, id: &str) -> bool {
+        !self.banned_relics.iter().any(|r| r == id)
+    }
+
+    pub fn has_rule(&self, rule: ChallengeRule) -> bool {
+        self.rules.contains(&rule)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum ChallengeRule {
+    NoShop,    // Shop nodes on the map are fights instead
+    AllBosses, // Every fight is against a boss
+}
+
+impl ChallengeRule {
+    pub fn description(&self) -> &'static str {
+        match self {
+            ChallengeRule::NoShop => "No shops: they are fights instead.",
+            ChallengeRule::AllBosses => "Every enemy is a boss.",
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use serde::Deserialize;
+use crate::structures::effect::Effect;
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct Consumable {
@@ -6,16 +7,6 @@ pub struct Consumable {
     pub name: String,
     pub rarity: String,
     pub cost: i32,
-    pub effect: ConsumableEffect, // Enum: LevelUp, ConvertSuit, etc.
-    pub target: Option<String>,   // For Books (e.g., "Straight")
+    pub effect: Effect, // Enum: LevelUp, ConvertSuit, etc.
     pub description: String,
 }
-
-#[derive(Debug, Clone, Deserialize)]
-#[serde(tag = "type")] 
-pub enum ConsumableEffect {
-    LevelUp { chips: i32, mult: i32 },
-    ModifyCard { enhancement: String },
-    CreateCard { card: String },
-    None,
-}
@@ -0,0 +1,289 @@
+// Daily runs: the seed comes from the (UTC) date, so everyone playing that day gets the
+// same map, enemies, shuffles and shop rolls. Every decision that changes the run is
+// logged as a RunAction, and the exported result string carries that log so anyone can
+// replay it without a window (logic::daily::replay, `--verify-daily`) and check the score.
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::structures::mods::ModStamp;
+use crate::structures::score::Score;
+
+pub const LEADERBOARD_PATH: &str = "leaderboard.json";
+pub const LEADERBOARD_KEEP: usize = 50; // Entries kept per date
+pub const RESULT_TAG: &str = "ONB-DAILY-1"; // First field of a result string; bump if the format changes
+
+// "YYYY-MM-DD" for a count of days since 1970-01-01
+pub fn date_from_days(days: i64) -> String {
+    // Civil-from-days (Howard Hinnant), with March as the first month of the year
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+// Today in UTC, so a team spread over time zones still shares one daily
+pub fn today() -> String {
+    let secs = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    date_from_days((secs / 86_400) as i64)
+}
+
+pub fn is_date(text: &str) -> bool {
+    let parts: Vec<&str> = text.split('-').collect();
+    parts.len() == 3 && [4, 2, 2].iter().zip(&parts).all(|(len, p)| p.len() == *len && p.bytes().all(|b| b.is_ascii_digit()))
+}
+
+// Run seed for a date. FNV-1a then a splitmix finish: fixed arithmetic, so every build
+// and platform agrees (std's hashers don't promise that).
+pub fn seed_for(date: &str) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in format!("daily:{}", date).bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    hash ^ (hash >> 31)
+}
+
+// Name shown on results: the setting, else the login name
+pub fn player_name(setting: &str) -> String {
+    let name = Some(setting.to_string())
+        .filter(|s| !s.trim().is_empty())
+        .or_else(|| std::env::var("USER").ok())
+        .or_else(|| std::env::var("USERNAME").ok())
+        .unwrap_or_else(|| "Player".to_string());
+    name.replace('|', "/").trim().to_string()
+}
+
+// One decision, in the order it was made. Indexes are positions at that moment (map node,
+// hand slot, relic slot, event choice, stats menu row); cards go by id.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RunAction {
+    Node(usize),
+    Play(Vec<i32>),
+    Discard(Vec<i32>),
+    SortRank,
+    SortSuit,
+    MoveCard(usize, usize),  // Dragged along the hand, from -> to
+    MoveRelic(usize, usize), // Relic slots, from -> to
+    Upgrade(usize),
+    Choose(usize),
+    Resume, // Continued from the save file, which restarts the rng stream
+}
+
+fn ids(text: &str) -> Option<Vec<i32>> {
+    text.split(',').map(|id| id.parse().ok()).collect::<Option<Vec<i32>>>().filter(|ids| !ids.is_empty())
+}
+
+fn pair(text: &str) -> Option<(usize, usize)> {
+    let (from, to) = text.split_once('-')?;
+    Some((from.parse().ok()?, to.parse().ok()?))
+}
+
+impl RunAction {
+    // Short token for the result string: "n2", "p4,17,30", "m1-3", ...
+    pub fn encode(&self) -> String {
+        let list = |ids: &[i32]| ids.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(",");
+        match self {
+            RunAction::Node(i) => format!("n{}", i),
+            RunAction::Play(cards) => format!("p{}", list(cards)),
+            RunAction::Discard(cards) => format!("d{}", list(cards)),
+            RunAction::SortRank => "sr".to_string(),
+            RunAction::SortSuit => "ss".to_string(),
+            RunAction::MoveCard(from, to) => format!("m{}-{}", from, to),
+            RunAction::MoveRelic(from, to) => format!("r{}-{}", from, to),
+            RunAction::Upgrade(row) => format!("u{}", row),
+            RunAction::Choose(i) => format!("e{}", i),
+            RunAction::Resume => "c".to_string(),
+        }
+    }
+
+    pub fn decode(token: &str) -> Option<Self> {
+        match token {
+            "sr" => return Some(RunAction::SortRank),
+            "ss" => return Some(RunAction::SortSuit),
+            "c" => return Some(RunAction::Resume),
+            _ => {}
+        }
+        let rest = token.get(1..)?;
+        match token.chars().next()? {
+            'n' => rest.parse().ok().map(RunAction::Node),
+            'p' => ids(rest).map(RunAction::Play),
+            'd' => ids(rest).map(RunAction::Discard),
+            'm' => pair(rest).map(|(from, to)| RunAction::MoveCard(from, to)),
+            'r' => pair(rest).map(|(from, to)| RunAction::MoveRelic(from, to)),
+            'u' => rest.parse().ok().map(RunAction::Upgrade),
+            'e' => rest.parse().ok().map(RunAction::Choose),
+            _ => None,
+        }
+    }
+}
+
+pub fn encode_log(log: &[RunAction]) -> String {
+    log.iter().map(RunAction::encode).collect::<Vec<_>>().join(".")
+}
+
+pub fn decode_log(text: &str) -> Result<Vec<RunAction>, String> {
+    if text.is_empty() {
+        return Ok(Vec::new());
+    }
+    text.split('.')
+        .enumerate()
+        .map(|(i, token)| RunAction::decode(token).ok_or_else(|| format!("action {} (\"{}\") is not a valid action", i + 1, token)))
+        .collect()
+}
+
+// The daily run in progress (stats.daily). Saved with the run, so a continued run keeps
+// its log up to the save point.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DailyRun {
+    pub date: String,
+    pub deck: String,       // Starting deck id
+    pub runes: Vec<String>, // Rune ids picked on rune select
+    pub log: Vec<RunAction>,
+}
+
+// A finished daily run, as shared in chat:
+// ONB-DAILY-1 | date | player | score | Ante n | Victory/Defeat | Deck id | Runes ids | Relics names | Mods id@version | log
+// (empty lists are "-")
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DailyResult {
+    pub date: String,
+    pub player: String,
+    pub score: Score,
+    pub ante: i32,
+    pub victory: bool,
+    pub deck: String,
+    pub runes: Vec<String>,
+    pub relics: Vec<String>, // Names, for reading; the replay works out its own
+    pub mods: Vec<ModStamp>,
+    pub log: Vec<RunAction>,
+}
+
+fn list_field(items: &[String]) -> String {
+    if items.is_empty() { "-".to_string() } else { items.join(", ") }
+}
+
+fn labelled<'a>(text: &'a str, label: &str) -> Result<&'a str, String> {
+    text.strip_prefix(label).ok_or_else(|| format!("expected \"{}...\", found \"{}\"", label, text))
+}
+
+fn parse_list(text: &str) -> Vec<String> {
+    if text == "-" { Vec::new() } else { text.split(", ").map(str::to_string).collect() }
+}
+
+impl fmt::Display for DailyResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mods: Vec<String> = self.mods.iter().map(|m| format!("{}@{}", m.id, m.version)).collect();
+        let log = if self.log.is_empty() { "-".to_string() } else { encode_log(&self.log) };
+        write!(f, "{} | {} | {} | {} ({:?}) | Ante {} | {} | Deck {} | Runes {} | Relics {} | Mods {} | {}",
+            RESULT_TAG, self.date, self.player, self.score.compact(), self.score.value(), self.ante,
+            if self.victory { "Victory" } else { "Defeat" }, self.deck, list_field(&self.runes),
+            list_field(&self.relics), list_field(&mods), log)
+    }
+}
+
+impl DailyResult {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = text.trim().split(" | ").collect();
+        if fields.first() != Some(&RESULT_TAG) {
+            return Err(format!("not a daily result (should start with {})", RESULT_TAG));
+        }
+        let [_, date, player, score, ante, outcome, deck, runes, relics, mods, log] = fields[..] else {
+            return Err(format!("expected 11 fields separated by \" | \", found {}", fields.len()));
+        };
+        if !is_date(date) {
+            return Err(format!("\"{}\" is not a date", date));
+        }
+        // The exact value is in brackets after the compact one
+        let score = score.rsplit_once('(').and_then(|(_, raw)| raw.strip_suffix(')')).and_then(|raw| raw.parse::<f64>().ok())
+            .ok_or_else(|| format!("bad score \"{}\"", score))?;
+        let ante = ante.strip_prefix("Ante ").and_then(|n| n.parse().ok()).ok_or_else(|| format!("bad ante \"{}\"", ante))?;
+        let victory = match outcome {
+            "Victory" => true,
+            "Defeat" => false,
+            _ => return Err(format!("bad outcome \"{}\"", outcome)),
+        };
+        let mods = parse_list(labelled(mods, "Mods ")?).iter()
+            .map(|m| m.split_once('@').map(|(id, version)| ModStamp { id: id.to_string(), version: version.to_string() }).ok_or_else(|| format!("bad mod \"{}\"", m)))
+            .collect::<Result<Vec<_>, String>>()?;
+        Ok(DailyResult {
+            date: date.to_string(),
+            player: player.to_string(),
+            score: Score::new(score),
+            ante,
+            victory,
+            deck: labelled(deck, "Deck ")?.to_string(),
+            runes: parse_list(labelled(runes, "Runes ")?),
+            relics: parse_list(labelled(relics, "Relics ")?),
+            mods,
+            log: if log == "-" { Vec::new() } else { decode_log(log)? },
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    pub result: DailyResult,
+    #[serde(default)]
+    pub imported: bool, // Pasted in (and replayed) rather than played here
+}
+
+// Best daily results per date, highest score first. Same load/save rules as Settings.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Leaderboard {
+    pub days: BTreeMap<String, Vec<LeaderboardEntry>>,
+}
+
+impl Leaderboard {
+    pub fn load() -> Self {
+        match fs::read_to_string(LEADERBOARD_PATH) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+                println!("Warning: Could not parse {}: {} (starting a new leaderboard)", LEADERBOARD_PATH, e);
+                Leaderboard::default()
+            }),
+            Err(_) => Leaderboard::default(),
+        }
+    }
+
+    pub fn save(&self) {
+        match serde_json::to_string_pretty(self) {
+            Ok(json) => {
+                if let Err(e) = fs::write(LEADERBOARD_PATH, json) {
+                    println!("Warning: Could not write {}: {}", LEADERBOARD_PATH, e);
+                }
+            }
+            Err(e) => println!("Warning: Could not serialize leaderboard: {}", e),
+        }
+    }
+
+    pub fn day(&self, date: &str) -> &[LeaderboardEntry] {
+        self.days.get(date).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    // Files the result under its date. Returns its 1-based rank, or None if that exact
+    // result is already there or didn't make the cut.
+    pub fn add(&mut self, result: DailyResult, imported: bool) -> Option<usize> {
+        let entries = self.days.entry(result.date.clone()).or_default();
+        if entries.iter().any(|e| e.result == result) {
+            return None;
+        }
+        let rank = entries.iter().take_while(|e| e.result.score >= result.score).count();
+        if rank >= LEADERBOARD_KEEP {
+            return None;
+        }
+        entries.insert(rank, LeaderboardEntry { result, imported });
+        entries.truncate(LEADERBOARD_KEEP);
+        Some(rank + 1)
+    }
+}
@@ -1,22 +1,51 @@
 use serde::de::DeserializeOwned;
 use std::fs;
-use crate::structures::assets::GameAssets;
+use std::path::Path;
+use crate::structures::assets::GameData;
 use crate::structures::relic::{GameRelic, RelicEffect};
 use crate::structures::consumable::Consumable;
 use crate::structures::heirloom::Heirloom;
-use crate::structures::enemy::Enemy;
-use crate::structures::stats::Rune; // <--- NEW IMPORT
+use crate::structures::enemy::EnemyFile;
+use crate::structures::endless::EndlessCurve;
+use crate::structures::challenge::Challenge;
+use crate::structures::deck::StartingDeck;
+use crate::structures::event::EventData;
+use crate::structures::stats::{BaseModifiers, Rune}; // <--- NEW IMPORT
+use crate::structures::mods::{self, ModInfo, Origins};
+use crate::data_validation::{self, Issue, Report, Severity, DATA_DIR};
+use crate::scripting::{self, RelicScript};
 
-// Generic JSON Helper
+// Generic JSON Helper. Entries are parsed one by one so a typo (e.g. an unknown effect
+// type) only drops that entry, and the error says which one it was.
 fn load_json<T: DeserializeOwned>(path: &str) -> Vec<T> {
     let content = fs::read_to_string(path).unwrap_or_else(|_| {
         println!("Warning: Could not read {}", path);
         "[]".to_string()
     });
 
-    serde_json::from_str(&content).unwrap_or_else(|e| {
+    let entries: Vec<serde_json::Value> = serde_json::from_str(&content).unwrap_or_else(|e| {
         println!("Error parsing {}: {}", path, e);
         Vec::new()
+    });
+
+    entries.into_iter().enumerate().filter_map(|(i, entry)| {
+        let id = entry.get("id").and_then(|id| id.as_str()).unwrap_or("?").to_string();
+        serde_json::from_value(entry)
+            .map_err(|e| println!("Error in {} entry {} ({}): {}", path, i, id, e))
+            .ok()
+    }).collect()
+}
+
+// Same as load_json, for files holding a single object instead of a list
+fn load_json_object<T: DeserializeOwned + Default>(path: &str) -> T {
+    let Ok(content) = fs::read_to_string(path) else {
+        println!("Warning: Could not read {}", path);
+        return T::default();
+    };
+
+    serde_json::from_str(&content).unwrap_or_else(|e| {
+        println!("Error parsing {}: {}", path, e);
+        T::default()
     })
 }
 
@@ -29,60 +58,213 @@ pub struct RelicData {
     pub value: Option<i32>,
     #[serde(default = "default_rarity")]
     pub rarity: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub script: Option<String>, // Relative to the folder relics.json is in (see scripting.rs)
 }
 
 fn default_rarity() -> String {
     "Common".to_string()
 }
 
-// --- MAIN LOADER ---
-pub fn load_all_data(assets: &mut GameAssets) {
-    println!("--- LOADING DATA ---");
+// Skips entries the validator found errors in, so a bad hand name or effect field
+// doesn't turn into odd behaviour mid-run. `file` is the path the entries came from.
+fn valid<T>(report: &Report, file: &str, items: Vec<T>, id: impl Fn(&T) -> &str) -> Vec<T> {
+    items.into_iter().filter(|item| !report.entry_has_errors(file, id(item))).collect()
+}
 
-    // 1. Load Relics
-    let relic_list: Vec<RelicData> = load_json("assets/data/relics.json");
-    for data in relic_list {
-        let game_relic = GameRelic {
-            data,
-            effect: RelicEffect::None,
-        };
-        assets.relics_db.insert(game_relic.data.id.clone(), game_relic);
+// Compiles a relic's script. A script that doesn't compile is a data error and drops the
+// relic (Some(None) = no script, None = skip it).
+fn load_script(report: &mut Report, dir: &str, data: &RelicData) -> Option<Option<RelicScript>> {
+    let Some(file) = &data.script else { return Some(None); };
+    let path = format!("{}/{}", dir, file);
+    match scripting::compile(&path) {
+        Ok(script) => Some(Some(script)),
+        Err(e) => {
+            let issue = Issue { severity: Severity::Error, file: path, line: e.line, entry: Some(data.id.clone()), message: e.message };
+            println!("{}", issue);
+            report.issues.push(issue);
+            None
+        }
     }
-    println!("Loaded {} Relics", assets.relics_db.len());
+}
+
+// One folder of data files into the databases: assets/data (source = None) or a mod
+// folder, where a missing file just means the mod leaves that table alone
+fn load_dir(data: &mut GameData, dir: &str, report: &mut Report, origins: &mut Origins, mut source: Option<&mut ModInfo>) {
+    let required = source.is_none();
+    let file = |name: &str| {
+        let path = format!("{}/{}", dir, name);
+        (required || Path::new(&path).exists()).then_some(path)
+    };
 
-    // 2. Load Scrolls
-    let scrolls: Vec<Consumable> = load_json("assets/data/scrolls.json");
-    for item in scrolls {
-        assets.consumables_db.insert(item.id.clone(), item);
+    // 1. Load Relics
+    if let Some(path) = file("relics.json") {
+        let relics: Vec<RelicData> = load_json(&path);
+        for relic in valid(report, &path, relics, |r| &r.id) {
+            let Some(script) = load_script(report, dir, &relic) else { continue; };
+            let state = script.as_ref().map(scripting::initial_state).unwrap_or_default();
+            // Scoring relics are all scripted now; the rest are passive
+            let effect = RelicEffect::None;
+            let id = relic.id.clone();
+            mods::merge(&mut data.relics_db, origins, "relic", id, GameRelic { data: relic, effect, script, state }, source.as_deref_mut());
+        }
     }
 
-    // 3. Load Books
-    let books: Vec<Consumable> = load_json("assets/data/books.json");
-    for item in books {
-        assets.consumables_db.insert(item.id.clone(), item);
+    // 2-3. Load Scrolls and Books (one table)
+    for name in ["scrolls.json", "books.json"] {
+        let Some(path) = file(name) else { continue; };
+        let items: Vec<Consumable> = load_json(&path);
+        for item in valid(report, &path, items, |c| &c.id) {
+            mods::merge(&mut data.consumables_db, origins, "consumable", item.id.clone(), item, source.as_deref_mut());
+        }
     }
-    println!("Loaded {} Consumables", assets.consumables_db.len());
 
     // 4. Load Heirlooms
-    let heirlooms: Vec<Heirloom> = load_json("assets/data/heirlooms.json");
-    for item in heirlooms {
-        assets.heirlooms_db.insert(item.id.clone(), item);
+    if let Some(path) = file("heirlooms.json") {
+        let heirlooms: Vec<Heirloom> = load_json(&path);
+        for item in valid(report, &path, heirlooms, |h| &h.id) {
+            mods::merge(&mut data.heirlooms_db, origins, "heirloom", item.id.clone(), item, source.as_deref_mut());
+        }
     }
-    println!("Loaded {} Heirlooms", assets.heirlooms_db.len());
 
-    // 5. Load Enemies
-    let enemies: Vec<Enemy> = load_json("assets/data/enemies.json");
-    for item in enemies {
-        assets.enemies_db.insert(item.id.clone(), item);
+    // 5. Load Enemies (the validator knows them by name)
+    if let Some(path) = file("enemies.json") {
+        let mut enemy_file: EnemyFile = load_json_object(&path);
+        if let Some(curve) = enemy_file.endless.take() {
+            data.endless_curve = curve;
+        }
+        for item in valid(report, &path, enemy_file.into_enemies(), |e| &e.name) {
+            mods::merge(&mut data.enemies_db, origins, "enemy", item.id.clone(), item, source.as_deref_mut());
+        }
     }
-    println!("Loaded {} Enemies", assets.enemies_db.len());
 
     // 6. Load Runes (FIX: This was missing!)
-    let runes: Vec<Rune> = load_json("assets/data/runes.json");
-    for item in runes {
-        assets.runes_db.insert(item.id.clone(), item);
+    if let Some(path) = file("runes.json") {
+        let runes: Vec<Rune> = load_json(&path);
+        for item in valid(report, &path, runes, |r| &r.id) {
+            mods::merge(&mut data.runes_db, origins, "rune", item.id.clone(), item, source.as_deref_mut());
+        }
+    }
+
+    // 7. Load Events
+    if let Some(path) = file("events.json") {
+        let events: Vec<EventData> = load_json(&path);
+        for item in valid(report, &path, events, |e| &e.id) {
+            mods::merge(&mut data.events_db, origins, "event", item.id.clone(), item, source.as_deref_mut());
+        }
+    }
+
+    // 8. Load Starting Decks
+    if let Some(path) = file("decks.json") {
+        let decks: Vec<StartingDeck> = load_json(&path);
+        for item in valid(report, &path, decks, |d| &d.id) {
+            mods::merge(&mut data.decks_db, origins, "deck", item.id.clone(), item, source.as_deref_mut());
+        }
     }
-    println!("Loaded {} Runes", assets.runes_db.len());
 
+    // 9. Load Challenges
+    if let Some(path) = file("challenges.json") {
+        let challenges: Vec<Challenge> = load_json(&path);
+        for item in valid(report, &path, challenges, |c| &c.id) {
+            mods::merge(&mut data.challenges_db, origins, "challenge", item.id.clone(), item, source.as_deref_mut());
+        }
+    }
+}
+
+// A manifest's `disable` list: each id is removed from whichever table has it
+fn apply_disables(data: &mut GameData, origins: &mut Origins, info: &mut ModInfo) {
+    let ids = info.manifest.as_ref().map(|m| m.disable.clone()).unwrap_or_default();
+    for id in ids {
+        let removed = mods::disable(&mut data.relics_db, origins, "relic", &id)
+            | mods::disable(&mut data.consumables_db, origins, "consumable", &id)
+            | mods::disable(&mut data.heirlooms_db, origins, "heirloom", &id)
+            | mods::disable(&mut data.enemies_db, origins, "enemy", &id)
+            | mods::disable(&mut data.runes_db, origins, "rune", &id)
+            | mods::disable(&mut data.events_db, origins, "event", &id)
+            | mods::disable(&mut data.challenges_db, origins, "challenge", &id)
+            | mods::disable(&mut data.decks_db, origins, "deck", &id);
+        if removed {
+            info.disabled.push(id);
+        } else {
+            info.errors.push(format!("disable: nothing loaded has the id \"{}\"", id));
+        }
+    }
+}
+
+// --- MAIN LOADER ---
+// Also used for hot reload, so it starts from empty databases. Base data loads first,
+// then every enabled mod under mods/ in load order (ids in `disabled_mods` are skipped).
+// `strict` (the --strict-data flag) refuses to start with any validation error;
+// otherwise the report is returned for the caller to show.
+pub fn load_all_data(data: &mut GameData, disabled_mods: &[String], strict: bool) -> Report {
+    println!("--- LOADING DATA ---");
+    data.relics_db.clear();
+    data.consumables_db.clear();
+    data.heirlooms_db.clear();
+    data.enemies_db.clear();
+    data.endless_curve = EndlessCurve::default();
+    data.runes_db.clear();
+    data.events_db.clear();
+    data.challenges_db.clear();
+    data.decks_db.clear();
+
+    let mut mod_list = mods::discover(Path::new("."), disabled_mods);
+    let mod_dirs: Vec<String> = mod_list.iter().filter(|m| m.is_active()).map(|m| m.folder.clone()).collect();
+
+    let mut report = data_validation::validate_with_mods(Path::new("."), &mod_dirs);
+    for issue in &report.issues {
+        println!("{}", issue);
+    }
+    if !report.issues.is_empty() {
+        println!("Data check: {} error(s), {} warning(s)", report.error_count(), report.warning_count());
+    }
+    if strict && report.error_count() > 0 {
+        println!("{} data error(s), refusing to start in strict mode", report.error_count());
+        std::process::exit(1);
+    }
+
+    let mut origins = Origins::new();
+    load_dir(data, DATA_DIR, &mut report, &mut origins, None);
+    for info in mod_list.iter_mut().filter(|m| m.is_active()) {
+        let folder = info.folder.clone();
+        load_dir(data, &folder, &mut report, &mut origins, Some(info));
+        let prefix = format!("{}/", folder);
+        info.errors.extend(report.issues.iter()
+            .filter(|i| i.severity == Severity::Error && i.file.starts_with(&prefix))
+            .map(|i| i.to_string()));
+        apply_disables(data, &mut origins, info);
+        println!("Mod {} ({}): {} new, {} overridden, {} disabled", info.id(), folder, info.added.len(), info.overridden.len(), info.disabled.len());
+        for conflict in &info.conflicts {
+            println!("> Mod conflict: {}", conflict);
+        }
+    }
+    data.mods = mod_list;
+
+    println!("Loaded {} Relics", data.relics_db.len());
+    println!("Loaded {} Consumables", data.consumables_db.len());
+    println!("Loaded {} Heirlooms", data.heirlooms_db.len());
+    println!("Loaded {} Enemies", data.enemies_db.len());
+    println!("Loaded {} Runes", data.runes_db.len());
+    println!("Loaded {} Events", data.events_db.len());
+    println!("Loaded {} Challenges", data.challenges_db.len());
+    println!("Loaded {} Decks", data.decks_db.len());
     println!("--- DATA LOAD COMPLETE ---");
+    report
+}
+
+// Copies the loaded databases into the stats copies the game logic reads
+pub fn copy_to_stats(data: &GameData, stats: &mut BaseModifiers) {
+    stats.all_relics = data.relics_db.clone();
+    stats.all_consumables = data.consumables_db.clone();
+    stats.all_heirlooms = data.heirlooms_db.clone();
+    stats.all_events = data.events_db.clone();
+    stats.all_challenges = data.challenges_db.clone();
+    stats.all_decks = data.decks_db.clone();
+    stats.enemy_database = Some(data.enemies_db.clone());
+    stats.endless_curve = data.endless_curve;
+    stats.available_runes = data.runes_db.values().cloned().collect();
+    stats.active_mods = mods::stamps(&data.mods);
+    if let Some(challenge) = &stats.challenge {
+        stats.all_relics.retain(|id, _| challenge.allows_relic(id));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,107 @@
+use serde::Deserialize;
+use crate::structures::card::{standard_deck, Card, Enhancement, SUIT_NAMES};
+use crate::structures::card_data::parse_card_name;
+
+pub const DEFAULT_DECK: &str = "deck_standard";
+
+// Starting deck from decks.json, picked on the rune select screen. The built deck becomes
+// the run's owned_deck.
+#[derive(Debug, Clone, Deserialize)]
+pub struct StartingDeck {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(flatten)]
+    pub spec: DeckSpec,
+}
+
+// How a deck differs from the standard 52: filtered, copied, topped up and enhanced, in
+// that order. Also used by challenges.json "deck".
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct DeckSpec {
+    pub suits: Vec<String>, // Empty = all four
+    pub no_face_cards: bool, // No Jacks, Queens or Kings
+    pub copies: i32,        // Of each card left; 0 counts as 1
+    pub extra_cards: Vec<String>, // Added on top, by name ("Ace of Spades")
+    pub enhance: Vec<EnhanceRule>, // Later rules win on cards several rules match
+}
+
+// Every card matching suit / value (both optional) gets the enhancement
+#[derive(Debug, Clone, Deserialize)]
+pub struct EnhanceRule {
+    pub enhancement: Enhancement,
+    #[serde(default)]
+    pub suit: Option<String>,
+    #[serde(default)]
+    pub value: Option<i32>, // 2-14, Ace = 14
+}
+
+impl EnhanceRule {
+    fn matches(&self, card: &Card) -> bool {
+        let suit = SUIT_NAMES.get(card.suit as usize).copied().unwrap_or("");
+        self.suit.as_ref().is_none_or(|s| s == suit) && self.value.is_none_or(|v| v == card.value)
+    }
+}
+
+impl DeckSpec {
+    pub fn is_standard(&self) -> bool {
+        self.suits.is_empty() && !self.no_face_cards && self.copies <= 1 && self.extra_cards.is_empty() && self.enhance.is_empty()
+    }
+
+    fn allows(&self, card: &Card) -> bool {
+        let suit = SUIT_NAMES.get(card.suit as usize).copied().unwrap_or("");
+        let suit_ok = self.suits.is_empty() || self.suits.iter().any(|s| s == suit);
+        suit_ok && !(self.no_face_cards && (11..=13).contains(&card.value))
+    }
+
+    pub fn build(&self) -> Vec<Card> {
+        let base = standard_deck();
+        let mut deck: Vec<Card> = Vec::new();
+        for _ in 0..self.copies.max(1) {
+            deck.extend(base.iter().filter(|c| self.allows(c)).cloned());
+        }
+        for name in &self.extra_cards {
+            let Some((suit, value)) = parse_card_name(name) else { continue; };
+            if let Some(card) = base.iter().find(|c| c.suit == suit && c.value == value) {
+                deck.push(card.clone());
+            }
+        }
+        for rule in &self.enhance {
+            for card in deck.iter_mut().filter(|c| rule.matches(c)) {
+                card.enhancement = Some(rule.enhancement);
+            }
+        }
+        for (i, card) in deck.iter_mut().enumerate() {
+            card.id = i as i32;
+        }
+        deck
+    }
+
+    // e.g. "Spades only, no face cards, x2 (20 cards)"
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        if !self.suits.is_empty() {
+            parts.push(format!("{} only", self.suits.join(" / ")));
+        }
+        if self.no_face_cards {
+            parts.push("no face cards".to_string());
+        }
+        if self.copies > 1 {
+            parts.push(format!("x{}", self.copies));
+        }
+        if !self.extra_cards.is_empty() {
+            parts.push(format!("+{} extra", self.extra_cards.len()));
+        }
+        let deck = self.build();
+        let enhanced = deck.iter().filter(|c| c.enhancement.is_some()).count();
+        if enhanced > 0 {
+            parts.push(format!("{} enhanced", enhanced));
+        }
+        if parts.is_empty() {
+            return format!("Standard ({} cards)", deck.len());
+        }
+        format!("{} ({} cards)", parts.join(", "), deck.len())
+    }
+}
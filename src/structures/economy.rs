@@ -0,0 +1,90 @@
+// End-of-fight money. Everything that pays out (or takes) at the end of a fight goes
+// through `payout` so the battle result screen can list it line by line.
+// logic::battle_result fills in PayoutInput from stats.
+
+pub const MONEY_PER_HAND: i32 = 1;
+pub const INTEREST_STEP: i32 = 5;      // $1 interest per $5 held...
+pub const INVESTMENT_PERCENT: i32 = 5; // ...plus this much of it with the Investment rune
+pub const WEALTH_BONUS: i32 = 3;
+pub const MIDAS_PERCENT: i32 = 25;
+pub const RECYCLER_PER_DISCARD: i32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FightOutcome {
+    Won,
+    Lost,
+}
+
+// What the payout depends on, copied out of the run
+#[derive(Debug, Clone, Default)]
+pub struct PayoutInput {
+    pub base: i32,            // By node kind: combat / elite / boss
+    pub money: i32,           // Held before the payout; interest and Midas work off this
+    pub hands_left: i32,
+    pub cards_discarded: i32, // This fight
+    pub interest_cap: i32,
+    pub investment: bool,     // Runes
+    pub wealth: bool,
+    pub midas: bool,
+    pub recycler: bool,       // Relics
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LedgerLine {
+    pub label: String,
+    pub amount: i32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Ledger {
+    pub lines: Vec<LedgerLine>,
+}
+
+impl Ledger {
+    fn add(&mut self, label: String, amount: i32) {
+        if amount != 0 {
+            self.lines.push(LedgerLine { label, amount });
+        }
+    }
+
+    pub fn total(&self) -> i32 {
+        self.lines.iter().map(|l| l.amount).sum()
+    }
+
+    // Money after paying out. No debt: losses stop at $0.
+    pub fn apply(&self, money: i32) -> i32 {
+        (money + self.total()).max(0)
+    }
+}
+
+// Line order: base, hands, discards, interest, then rune and relic bonuses. Lines worth $0
+// are left out. A lost fight only runs the rules that take money.
+pub fn payout(input: &PayoutInput, outcome: FightOutcome) -> Ledger {
+    let mut ledger = Ledger::default();
+    let held = input.money.max(0);
+
+    if outcome == FightOutcome::Lost {
+        if input.midas {
+            ledger.add(format!("Midas (-{}%)", MIDAS_PERCENT), -(held * MIDAS_PERCENT / 100));
+        }
+        return ledger;
+    }
+
+    ledger.add("Victory".to_string(), input.base);
+    ledger.add(format!("Hands left ({})", input.hands_left), input.hands_left.max(0) * MONEY_PER_HAND);
+    if input.recycler {
+        ledger.add(format!("Recycler ({} discarded)", input.cards_discarded), input.cards_discarded.max(0) * RECYCLER_PER_DISCARD);
+    }
+    let interest = (held / INTEREST_STEP).min(input.interest_cap.max(0) / INTEREST_STEP);
+    ledger.add(format!("Interest (max ${})", input.interest_cap / INTEREST_STEP), interest);
+    if input.investment {
+        ledger.add(format!("Investment (+{}%)", INVESTMENT_PERCENT), held * INVESTMENT_PERCENT / 100);
+    }
+    if input.wealth {
+        ledger.add("Wealth".to_string(), WEALTH_BONUS);
+    }
+    if input.midas {
+        ledger.add(format!("Midas (+{}%)", MIDAS_PERCENT), held * MIDAS_PERCENT / 100);
+    }
+    ledger
+}
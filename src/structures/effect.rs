@@ -0,0 +1,97 @@
+// Data-side description of "something that happens to the run". Consumables, heirlooms
+// and event choices all store their effects as this enum; logic::effects applies them.
+use serde::Deserialize;
+use crate::structures::card_data::{parse_card_name, Enhancement, Seal, SUIT_NAMES};
+use crate::structures::hand::HandRank;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", deny_unknown_fields)]
+pub enum Effect {
+    GainMoney { amount: i32 },
+    LoseMoney { amount: i32 },
+    Heal { amount: i32 },
+    LoseHp { amount: i32 },
+    GainRelic {
+        #[serde(default)]
+        relic: Option<String>, // Specific relic id; random unowned one if not given
+    },
+    LevelUp {
+        #[serde(default)]
+        target: Option<String>, // Hand name, e.g. "Pair"
+        chips: i32,
+        mult: i32,
+    },
+    ConvertSuit { to: String, count: i32 }, // Random cards in the deck become `to`
+    ModifyCard { enhancement: Enhancement }, // Random card in the deck gets the enhancement
+    AddSeal { seal: Seal }, // Random unsealed card in the deck gets the seal
+    CreateCard { card: String },
+    ModifyPlayerStats { stat: PlayerStat, amount: i32 },
+    D20 { outcomes: Vec<RollOutcome> }, // Gamble: first outcome whose range holds the roll
+    None,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RollOutcome {
+    pub min: i32,
+    pub max: i32,
+    pub text: String,
+    #[serde(default)]
+    pub effects: Vec<Effect>,
+}
+
+// BaseModifiers fields ModifyPlayerStats can touch (see BaseModifiers::modify_stat),
+// written as the field name in the data files ("hands_per_fight")
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlayerStat {
+    MaxHp,
+    HandSize,
+    HandsPerFight,
+    DiscardsPerFight,
+    InterestCap,
+    StatPoints,
+}
+
+impl Effect {
+    // Checks the parts serde can't (names that must match something in the game).
+    // data_validation runs this on every effect in the data files.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Effect::LevelUp { target: Some(name), .. } if HandRank::from_name(name).is_none() => {
+                Err(format!("unknown hand \"{}\"", name))
+            }
+            Effect::ConvertSuit { to, .. } if !SUIT_NAMES.contains(&to.as_str()) => Err(format!("unknown suit \"{}\"", to)),
+            Effect::ConvertSuit { count, .. } if *count <= 0 => Err("ConvertSuit count must be positive".to_string()),
+            Effect::CreateCard { card } if parse_card_name(card).is_none() => {
+                Err(format!("unknown card \"{}\" (expected e.g. \"Queen of Hearts\")", card))
+            }
+            Effect::D20 { outcomes } => {
+                let mut covered = [false; 20];
+                for o in outcomes {
+                    if o.min < 1 || o.max > 20 || o.min > o.max {
+                        return Err(format!("bad D20 range {}-{}", o.min, o.max));
+                    }
+                    (o.min..=o.max).for_each(|roll| covered[roll as usize - 1] = true);
+                    for effect in &o.effects {
+                        effect.validate()?;
+                    }
+                }
+                match covered.iter().position(|c| !c) {
+                    Some(roll) => Err(format!("D20 outcomes don't cover a roll of {}", roll + 1)),
+                    None => Ok(()),
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
+    // Relic ids this hands out, D20 outcomes included (GainRelic without one picks at random)
+    pub fn named_relics(&self) -> Vec<&str> {
+        match self {
+            Effect::GainRelic { relic: Some(id) } => vec![id.as_str()],
+            Effect::D20 { outcomes } => outcomes.iter().flat_map(|o| &o.effects).flat_map(Effect::named_relics).collect(),
+            _ => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+// Endless mode: the run carries on past FINAL_ANTE after a victory. Enemy HP gets an extra
+// multiplier on top of ante_scaling that grows faster every ante, and late bosses stack a
+// second ability. Tuned by the optional "endless" object in enemies.json (mods can override
+// it).
+use serde::Deserialize;
+
+pub const MAX_BOSS_ABILITIES: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct EndlessCurve {
+    pub hp_growth: f64,   // HP x hp_growth ^ (antes past the final one ^ hp_exponent)
+    pub hp_exponent: f64, // 1.0 = plain exponential; higher pulls away from ante_scaling
+    pub stacked_ability_after: i32, // Bosses this many antes past the final one get two abilities
+}
+
+impl Default for EndlessCurve {
+    fn default() -> Self {
+        Self { hp_growth: 1.6, hp_exponent: 1.4, stacked_ability_after: 3 }
+    }
+}
+
+impl EndlessCurve {
+    // `depth` = antes past the final one; 0 (a normal run) leaves HP alone
+    pub fn hp_mult(&self, depth: i32) -> f64 {
+        if depth <= 0 {
+            return 1.0;
+        }
+        self.hp_growth.max(1.0).powf((depth as f64).powf(self.hp_exponent.max(1.0)))
+    }
+
+    pub fn boss_abilities(&self, depth: i32) -> usize {
+        if depth > 0 && depth >= self.stacked_ability_after { MAX_BOSS_ABILITIES } else { 1 }
+    }
+}
+
+pub fn depth(ante: i32, final_ante: i32) -> i32 {
+    (ante - final_ante).max(0)
+}
@@ -1,18 +1,121 @@
 use serde::Deserialize;
+use crate::structures::score::Score;
+use crate::structures::card_data::SUIT_NAMES;
+use crate::structures::endless::EndlessCurve;
 
-#[derive(Debug, Clone, Deserialize)]
+// Base HP / hit damage per tier (index 0 = tier 1, last = bosses), before ante scaling
+const TIER_HP: [i32; 4] = [300, 450, 600, 800];
+const TIER_DAMAGE: [i32; 4] = [8, 12, 16, 20];
+pub const BOSS_TIER: u8 = 4;
+
+#[derive(Debug, Clone)]
 pub struct Enemy {
     pub id: String,
     pub name: String,
-    pub hp: i32,
-    pub ability: EnemyAbility,
+    pub hp: Score,
+    pub tier: u8, // 1-3, or BOSS_TIER
+    pub damage: i32, // HP the player loses each hand that doesn't finish the fight
+    pub ability: BossAbility,
+}
+
+impl Enemy {
+    fn new(name: &str, tier: u8, ability: BossAbility) -> Self {
+        let index = (tier as usize).clamp(1, TIER_HP.len()) - 1;
+        Self {
+            id: name.to_lowercase().replace(' ', "_"),
+            name: name.to_string(),
+            hp: Score::from(TIER_HP[index]),
+            tier,
+            damage: TIER_DAMAGE[index],
+            ability,
+        }
+    }
+
+    pub fn is_boss(&self) -> bool {
+        self.tier == BOSS_TIER
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(tag = "type")]
-pub enum EnemyAbility {
+#[derive(Debug, Clone, PartialEq)]
+pub enum BossAbility {
     None,
-    // Add more enemy abilities here as needed
-    DealDamage { damage: i32 },
-    Heal { amount: i32 },
-}
\ No newline at end of file
+    SilenceSuit(i32),
+    HandSizeMinusOne,
+    DoubleTarget,
+    PayToDiscard,
+}
+
+impl BossAbility {
+    // Name + parameter as written in enemies.json
+    pub fn from_data(name: &str, param: i32) -> Self {
+        match name {
+            "SilenceSuit" => BossAbility::SilenceSuit(param),
+            "HandSizeMinusOne" => BossAbility::HandSizeMinusOne,
+            "DoubleTarget" => BossAbility::DoubleTarget,
+            "PayToDiscard" => BossAbility::PayToDiscard,
+            _ => BossAbility::None,
+        }
+    }
+
+    // Short tag shown on the enemy monitor
+    pub fn label(&self) -> &'static str {
+        match self {
+            BossAbility::SilenceSuit(_) => "SILENCE",
+            BossAbility::HandSizeMinusOne => "HAND -1",
+            BossAbility::DoubleTarget => "HP x2",
+            BossAbility::PayToDiscard => "TAX $1",
+            BossAbility::None => "",
+        }
+    }
+
+    // Full rules text for the tooltip
+    pub fn description(&self) -> String {
+        match self {
+            BossAbility::SilenceSuit(suit) => {
+                let name = SUIT_NAMES.get(*suit as usize).unwrap_or(&"?");
+                format!("{} are silenced: they can't form a Flush or Straight Flush.", name)
+            }
+            BossAbility::HandSizeMinusOne => "Hand size is reduced by 1 for this fight.".to_string(),
+            BossAbility::DoubleTarget => "Enemy HP is doubled.".to_string(),
+            BossAbility::PayToDiscard => "Every discard costs $1.".to_string(),
+            BossAbility::None => String::new(),
+        }
+    }
+}
+
+// --- enemies.json ---
+// Regular enemies are just names grouped by tier; bosses carry their ability.
+#[derive(Debug, Deserialize)]
+pub struct BossData {
+    pub name: String,
+    pub ability: String,
+    #[serde(default)]
+    pub param: i32,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct EnemyFile {
+    pub tier_1: Vec<String>,
+    pub tier_2: Vec<String>,
+    pub tier_3: Vec<String>,
+    pub bosses: Vec<BossData>,
+    pub endless: Option<EndlessCurve>,
+}
+
+impl EnemyFile {
+    pub fn into_enemies(self) -> Vec<Enemy> {
+        let mut enemies = Vec::new();
+        for (tier, names) in [(1, &self.tier_1), (2, &self.tier_2), (3, &self.tier_3)] {
+            enemies.extend(names.iter().map(|name| Enemy::new(name, tier, BossAbility::None)));
+        }
+        for boss in &self.bosses {
+            let ability = BossAbility::from_data(&boss.ability, boss.param);
+            if ability == BossAbility::None {
+                println!("Warning: Unknown boss ability '{}' on {}", boss.ability, boss.name);
+            }
+            enemies.push(Enemy::new(&boss.name, BOSS_TIER, ability));
+        }
+        enemies
+    }
+}
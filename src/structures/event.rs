@@ -0,0 +1,41 @@
+use serde::Deserialize;
+use crate::structures::effect::Effect;
+
+// events.json: a short story beat with a few choices. Costs are paid first and the
+// choice is greyed out if the player can't cover them; outcomes then run in order.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EventData {
+    pub id: String,
+    pub title: String,
+    pub text: String,
+    pub choices: Vec<EventChoice>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EventChoice {
+    pub label: String,
+    #[serde(default)]
+    pub costs: Vec<Effect>,
+    #[serde(default)]
+    pub outcomes: Vec<Effect>,
+}
+
+impl EventData {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.choices.is_empty() {
+            return Err("event has no choices".to_string());
+        }
+        for choice in &self.choices {
+            for effect in choice.costs.iter().chain(&choice.outcomes) {
+                effect.validate().map_err(|e| format!("choice \"{}\": {}", choice.label, e))?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn effects(&self) -> impl Iterator<Item = &Effect> {
+        self.choices.iter().flat_map(|c| c.costs.iter().chain(&c.outcomes))
+    }
+}
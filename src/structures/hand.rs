@@ -1,4 +1,4 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum HandRank {
     HighCard,
     Pair,
@@ -9,4 +9,41 @@ pub enum HandRank {
     FullHouse,
     FourOfAKind,
     StraightFlush,
+    RoyalFlush, // Ten to Ace straight flush; levels separately from Straight Flush
+}
+
+impl HandRank {
+    pub const ALL: [HandRank; 10] = [
+        HandRank::HighCard, HandRank::Pair, HandRank::TwoPair, HandRank::ThreeOfAKind, HandRank::Straight,
+        HandRank::Flush, HandRank::FullHouse, HandRank::FourOfAKind, HandRank::StraightFlush,
+        HandRank::RoyalFlush,
+    ];
+
+    // Display name, also used to refer to hands in the data files ("Two Pair")
+    pub fn name(&self) -> &'static str {
+        match self {
+            HandRank::HighCard => "High Card",
+            HandRank::Pair => "Pair",
+            HandRank::TwoPair => "Two Pair",
+            HandRank::ThreeOfAKind => "Three of a Kind",
+            HandRank::Straight => "Straight",
+            HandRank::Flush => "Flush",
+            HandRank::FullHouse => "Full House",
+            HandRank::FourOfAKind => "Four of a Kind",
+            HandRank::StraightFlush => "Straight Flush",
+            HandRank::RoyalFlush => "Royal Flush",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<HandRank> {
+        HandRank::ALL.iter().copied().find(|rank| rank.name().eq_ignore_ascii_case(name))
+    }
+}
+
+// Bonus a hand has picked up from level-ups (added on top of its base chips/mult)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HandLevel {
+    pub level: i32, // Level-ups taken; the hand shows as level + 1
+    pub chips: i32,
+    pub mult: i32,
 }
@@ -1,18 +1,43 @@
 use serde::Deserialize;
+use crate::structures::effect::Effect;
+use crate::structures::profile::Profile;
 
+// Meta-progression item: unlocked by a milestone across runs, kept in profile.json and
+// equipped on the rune select screen. Its effect is applied once at run start.
 #[derive(Debug, Clone, Deserialize)]
 pub struct Heirloom {
     pub id: String,
     pub name: String,
-    pub cost: i32,
-    pub effect: HeirloomEffect,
+    #[serde(default)]
+    pub description: String,
+    pub unlock: Unlock,
+    pub effect: Effect,
 }
 
+// Lifetime milestones, counted in Profile
 #[derive(Debug, Clone, Deserialize)]
 #[serde(tag = "type")]
-pub enum HeirloomEffect {
-    None,
-    // Add more heirloom effects here as needed
-    ModifyPlayerStats { stat: String, amount: i32 },
-    GainMoney { amount: i32 },
-}
\ No newline at end of file
+pub enum Unlock {
+    ReachAnte { ante: i32 },
+    WinFights { count: i32 },
+    DefeatBosses { count: i32 },
+}
+
+impl Unlock {
+    pub fn is_met(&self, profile: &Profile) -> bool {
+        match self {
+            Unlock::ReachAnte { ante } => profile.best_ante >= *ante,
+            Unlock::WinFights { count } => profile.fights_won >= *count,
+            Unlock::DefeatBosses { count } => profile.bosses_defeated >= *count,
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            Unlock::ReachAnte { ante } => format!("Reach Ante {}", ante),
+            Unlock::WinFights { count } => format!("Win {} fights", count),
+            Unlock::DefeatBosses { count } if *count == 1 => "Defeat a boss".to_string(),
+            Unlock::DefeatBosses { count } => format!("Defeat {} bosses", count),
+        }
+    }
+}
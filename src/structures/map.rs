@@ -0,0 +1,141 @@
+// Per-ante run map: rows of encounter nodes, each linked to one or two nodes in the
+// next row, ending in a single boss. Generated purely from (run seed, ante) so the same
+// seed always gives the same dungeon.
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+pub const MAP_ROWS: usize = 6; // Rows before the boss
+pub const MAX_ROW_WIDTH: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeKind {
+    Combat,
+    Elite,    // Tougher enemy, bigger payout
+    Rest,     // Heal
+    Treasure, // Free relic
+    Event,
+    Shop,
+    Boss,
+}
+
+impl NodeKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            NodeKind::Combat => "Combat",
+            NodeKind::Elite => "Elite",
+            NodeKind::Rest => "Rest",
+            NodeKind::Treasure => "Treasure",
+            NodeKind::Event => "Event",
+            NodeKind::Shop => "Shop",
+            NodeKind::Boss => "Boss",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MapNode {
+    pub kind: NodeKind,
+    pub row: usize,
+    pub col: usize,       // Position within the row
+    pub row_width: usize, // Nodes in this row (for layout)
+    pub next: Vec<usize>, // Indices of linked nodes in the next row
+    pub visited: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RunMap {
+    pub nodes: Vec<MapNode>,
+    pub current: Option<usize>, // Last node entered (None = haven't picked the first yet)
+}
+
+// Rolled kind for a middle-row node
+fn roll_kind(rng: &mut StdRng, row: usize) -> NodeKind {
+    let roll = rng.gen_range(0..100);
+    match roll {
+        0..=44 => NodeKind::Combat,
+        45..=59 if row >= 2 => NodeKind::Elite,
+        45..=59 => NodeKind::Combat,
+        60..=74 => NodeKind::Event,
+        75..=86 => NodeKind::Shop,
+        87..=94 => NodeKind::Treasure,
+        _ => NodeKind::Rest,
+    }
+}
+
+impl RunMap {
+    pub fn generate(seed: u64, ante: i32) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed ^ (ante as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        let mut rows: Vec<Vec<usize>> = Vec::new();
+        let mut nodes: Vec<MapNode> = Vec::new();
+
+        for row in 0..=MAP_ROWS {
+            let width = if row == MAP_ROWS { 1 } else { rng.gen_range(2..=MAX_ROW_WIDTH) };
+            let mut ids = Vec::new();
+            for col in 0..width {
+                let kind = match row {
+                    0 => NodeKind::Combat,                 // Always open with a plain fight
+                    r if r == MAP_ROWS => NodeKind::Boss,
+                    r if r == MAP_ROWS - 1 => NodeKind::Rest, // Breather before the boss
+                    r => roll_kind(&mut rng, r),
+                };
+                ids.push(nodes.len());
+                nodes.push(MapNode { kind, row, col, row_width: width, next: Vec::new(), visited: false });
+            }
+            rows.push(ids);
+        }
+
+        // Link each node to the node(s) at the same relative position in the next row,
+        // then make sure nothing in the next row is left unreachable.
+        for row in 0..MAP_ROWS {
+            let (here, below) = (&rows[row], &rows[row + 1]);
+            for (i, &id) in here.iter().enumerate() {
+                let rel = if here.len() > 1 { i as f32 / (here.len() - 1) as f32 } else { 0.5 };
+                let target = (rel * (below.len() - 1) as f32).round() as usize;
+                nodes[id].next.push(below[target]);
+
+                // Sometimes branch to a neighbour as well
+                let neighbour = if rng.gen_bool(0.5) { target.checked_sub(1) } else { Some(target + 1) };
+                if let Some(n) = neighbour.filter(|n| *n < below.len()) && rng.gen_bool(0.4) && !nodes[id].next.contains(&below[n]) {
+                    nodes[id].next.push(below[n]);
+                }
+            }
+            for (j, &id) in below.iter().enumerate() {
+                if !here.iter().any(|&h| nodes[h].next.contains(&id)) {
+                    let rel = if below.len() > 1 { j as f32 / (below.len() - 1) as f32 } else { 0.5 };
+                    let from = here[(rel * (here.len() - 1) as f32).round() as usize];
+                    nodes[from].next.push(id);
+                }
+            }
+            for &id in here {
+                nodes[id].next.sort();
+            }
+        }
+
+        Self { nodes, current: None }
+    }
+
+    // Nodes the player may enter next
+    pub fn available(&self) -> Vec<usize> {
+        match self.current {
+            Some(current) => self.nodes[current].next.clone(),
+            None => (0..self.nodes.len()).filter(|&i| self.nodes[i].row == 0).collect(),
+        }
+    }
+
+    pub fn enter(&mut self, index: usize) {
+        self.nodes[index].visited = true;
+        self.current = Some(index);
+    }
+
+    pub fn current_kind(&self) -> Option<NodeKind> {
+        self.current.map(|i| self.nodes[i].kind)
+    }
+
+    // No-shop challenges: same layout, every shop becomes a fight
+    pub fn without_shops(mut self) -> Self {
+        for node in self.nodes.iter_mut().filter(|n| n.kind == NodeKind::Shop) {
+            node.kind = NodeKind::Combat;
+        }
+        self
+    }
+}
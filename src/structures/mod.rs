@@ -1,12 +1,28 @@
 pub mod assets;
+pub mod bindings;     // New (Rebinding without overlaps)
 pub mod card;
+pub mod card_data;    // New (Card names the data files use)
+pub mod challenge;    // New (Challenge runs)
 pub mod consumable;   // New (Scrolls/Books)
+pub mod daily;        // New (Daily runs, leaderboard.json)
 pub mod data_loader;  // New (JSON Loader)
+pub mod deck;         // New (Starting decks)
+pub mod economy;      // New (End-of-fight payouts)
+pub mod effect;       // New (Shared effect data)
+pub mod endless;      // New (Endless mode curve)
 pub mod enemy;        // New (Bosses)
+pub mod event;        // New (Random events)
 pub mod hand;
-pub mod heirloom;     // New (Vouchers)
+pub mod heirloom;     // New (Meta-progression)
 pub mod joker_stats;
+pub mod map;          // New (Run map)
+pub mod mods;         // New (Data packs in mods/)
+pub mod profile;      // New (profile.json)
 pub mod relic;
+pub mod save;         // New (save.json, the run in progress)
+pub mod score;        // New (Big-number score type)
+pub mod settings;     // New (settings.json)
 pub mod state;
 pub mod stats;
+pub mod trigger;      // New (Scoring trigger queue)
 pub mod tween;
\ No newline at end of file
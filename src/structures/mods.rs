@@ -0,0 +1,154 @@
+// Data packs under mods/<folder>/: a mod.json manifest plus any of the files in
+// assets/data (relics, runes, books, scrolls, enemies, heirlooms, events, decks,
+// challenges), and optional relic_icons/ and rune_icons/ folders laid out like the
+// ones in assets/.
+//
+// Entries merge by id on top of the base game in load order: a new id is appended, a
+// known id is overridden and the later mod wins. Two mods touching the same id is
+// reported as a conflict. `disable` in the manifest removes entries by id.
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use crate::data_validation::{MODS_DIR, MOD_MANIFEST};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ModManifest {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub version: String,
+    #[serde(default)]
+    pub author: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub load_order: i32, // Lower loads first; ties go by folder name
+    #[serde(default)]
+    pub disable: Vec<String>, // Entry ids (base game or earlier mods) to remove
+}
+
+// One folder under mods/ and what loading it did, for the Mods screen
+#[derive(Debug, Clone, Default)]
+pub struct ModInfo {
+    pub folder: String, // e.g. "mods/more_relics"
+    pub manifest: Option<ModManifest>, // None if mod.json is missing or broken
+    pub enabled: bool,
+    pub added: Vec<String>,
+    pub overridden: Vec<String>,
+    pub disabled: Vec<String>,
+    pub conflicts: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+impl ModInfo {
+    pub fn id(&self) -> String {
+        match &self.manifest {
+            Some(m) => m.id.clone(),
+            None => self.folder.trim_start_matches(MODS_DIR).trim_start_matches('/').to_string(),
+        }
+    }
+
+    pub fn name(&self) -> String {
+        self.manifest.as_ref().map(|m| m.name.clone()).unwrap_or_else(|| self.id())
+    }
+
+    pub fn version(&self) -> String {
+        self.manifest.as_ref().map(|m| m.version.clone()).unwrap_or_default()
+    }
+
+    // Actually loaded into the game (a mod without a readable manifest never is)
+    pub fn is_active(&self) -> bool {
+        self.enabled && self.manifest.is_some()
+    }
+}
+
+// The part of a mod that saves and replays remember, to spot a different mod set later
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModStamp {
+    pub id: String,
+    pub version: String,
+}
+
+// Every folder under `root`/mods, in load order. Mods are on unless their id is in `disabled`.
+pub fn discover(root: &Path, disabled: &[String]) -> Vec<ModInfo> {
+    let Ok(dir) = fs::read_dir(root.join(MODS_DIR)) else { return Vec::new(); };
+    let mut mods: Vec<ModInfo> = dir.flatten()
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| {
+            let folder = format!("{}/{}", MODS_DIR, entry.file_name().to_string_lossy());
+            let mut info = ModInfo { folder, ..Default::default() };
+            match fs::read_to_string(entry.path().join(MOD_MANIFEST)) {
+                Ok(text) => match serde_json::from_str::<ModManifest>(&text) {
+                    Ok(manifest) => info.manifest = Some(manifest),
+                    Err(e) => info.errors.push(format!("{}/{}: {}", info.folder, MOD_MANIFEST, e)),
+                },
+                Err(_) => info.errors.push(format!("{}/{} is missing", info.folder, MOD_MANIFEST)),
+            }
+            info.enabled = !disabled.contains(&info.id());
+            info
+        })
+        .collect();
+
+    mods.sort_by(|a, b| {
+        let order = |m: &ModInfo| m.manifest.as_ref().map_or(0, |m| m.load_order);
+        order(a).cmp(&order(b)).then_with(|| a.folder.cmp(&b.folder))
+    });
+    mods
+}
+
+// Where each merged entry came from, keyed "kind:id": "" for the base game, else a mod id
+pub type Origins = HashMap<String, String>;
+
+// Puts one entry into `db`. `source` is the mod being loaded (None for the base game);
+// `kind` keeps ids from different tables apart in `origins`.
+pub fn merge<T>(db: &mut HashMap<String, T>, origins: &mut Origins, kind: &str, id: String, item: T, source: Option<&mut ModInfo>) {
+    let key = format!("{}:{}", kind, id);
+    match source {
+        None => {
+            origins.insert(key, String::new());
+        }
+        Some(info) => match origins.insert(key, info.id()) {
+            None => info.added.push(id.clone()),
+            Some(previous) => {
+                if !previous.is_empty() && previous != info.id() {
+                    info.conflicts.push(format!("{} {} (also changed by {})", kind, id, previous));
+                }
+                info.overridden.push(id.clone());
+            }
+        },
+    }
+    db.insert(id, item);
+}
+
+// Removes `id` from `db` for a manifest's `disable` list. True if it was there.
+pub fn disable<T>(db: &mut HashMap<String, T>, origins: &mut Origins, kind: &str, id: &str) -> bool {
+    origins.remove(&format!("{}:{}", kind, id));
+    db.remove(id).is_some()
+}
+
+pub fn stamps(mods: &[ModInfo]) -> Vec<ModStamp> {
+    mods.iter()
+        .filter(|m| m.is_active())
+        .map(|m| ModStamp { id: m.id(), version: m.version() })
+        .collect()
+}
+
+// Differences between the mods something was saved with and the ones loaded now, one
+// line each. Empty means they match.
+pub fn mismatches(saved: &[ModStamp], loaded: &[ModStamp]) -> Vec<String> {
+    let mut lines = Vec::new();
+    for stamp in saved {
+        match loaded.iter().find(|m| m.id == stamp.id) {
+            None => lines.push(format!("missing mod {} {}", stamp.id, stamp.version)),
+            Some(m) if m.version != stamp.version => {
+                lines.push(format!("{} is {} (saved with {})", m.id, m.version, stamp.version));
+            }
+            Some(_) => {}
+        }
+    }
+    for m in loaded.iter().filter(|m| !saved.iter().any(|s| s.id == m.id)) {
+        lines.push(format!("extra mod {} {}", m.id, m.version));
+    }
+    lines
+}
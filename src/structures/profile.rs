@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use crate::structures::mods::ModStamp;
+use crate::structures::score::Score;
+
+pub const PROFILE_PATH: &str = "profile.json";
+pub const HEIRLOOM_SLOTS: usize = 2;
+
+// Progress that outlives a run: lifetime milestones and the heirlooms they unlocked.
+// Same load/save rules as Settings (missing keys fall back to defaults).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Profile {
+    pub best_ante: i32,
+    pub fights_won: i32,
+    pub bosses_defeated: i32,
+    pub endless_best_ante: i32, // Endless antes don't count towards best_ante
+    pub endless_high_score: Score, // Most total damage in one endless run
+    pub completed_challenges: Vec<String>, // Challenge ids won at least once
+    pub unlocked_heirlooms: Vec<String>,
+    pub equipped_heirlooms: Vec<String>, // At most HEIRLOOM_SLOTS, remembered between runs
+    pub selected_deck: String, // Starting deck id, remembered between runs; empty = standard
+    pub mods: Vec<ModStamp>, // Mods the last run started with, to warn when they change
+    #[serde(skip)]
+    pub detached: bool, // Replays play on a throwaway copy that never writes the file
+}
+
+impl Profile {
+    pub fn load() -> Self {
+        match fs::read_to_string(PROFILE_PATH) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+                println!("Warning: Could not parse {}: {} (starting a new profile)", PROFILE_PATH, e);
+                Profile::default()
+            }),
+            Err(_) => Profile::default(),
+        }
+    }
+
+    pub fn save(&self) {
+        if self.detached {
+            return;
+        }
+        match serde_json::to_string_pretty(self) {
+            Ok(json) => {
+                if let Err(e) = fs::write(PROFILE_PATH, json) {
+                    println!("Warning: Could not write {}: {}", PROFILE_PATH, e);
+                }
+            }
+            Err(e) => println!("Warning: Could not serialize profile: {}", e),
+        }
+    }
+
+    pub fn is_unlocked(&self, id: &str) -> bool {
+        self.unlocked_heirlooms.iter().any(|h| h == id)
+    }
+
+    pub fn completed(&self, challenge_id: &str) -> bool {
+        self.completed_challenges.iter().any(|c| c == challenge_id)
+    }
+
+    pub fn is_equipped(&self, id: &str) -> bool {
+        self.equipped_heirlooms.iter().any(|h| h == id)
+    }
+}
@@ -1,8 +1,11 @@
 
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use crate::structures::stats::{BaseModifiers};
 use crate::structures::data_loader::RelicData;
 use crate::structures::card::Card;
 use crate::structures::hand::HandRank;
+use crate::scripting::{self, RelicScript};
 
 #[derive(Clone, Debug)]
 pub enum RelicEffect {
@@ -12,6 +15,32 @@ pub enum RelicEffect {
     None, // For passive utility relics
 }
 
+// Per-relic memory for the run, updated by the relic's hooks and saved with the run.
+// Scripts see it as `this`: the named fields plus any extra whole-number keys they add.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RelicState {
+    pub counter: i64,  // Times something happened (hands, rounds...)
+    pub mult: i64,     // Accumulated Mult
+    pub chips: i64,    // Accumulated Chips
+    pub charges: i64,  // Uses left
+    pub destroyed: bool, // Removed from the relic bar after the hook that set it
+    pub vars: BTreeMap<String, i64>,
+}
+
+impl RelicState {
+    // Tooltip lines for whatever isn't at zero
+    pub fn summary(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        if self.mult != 0 { lines.push(format!("{:+} Mult", self.mult)); }
+        if self.chips != 0 { lines.push(format!("{:+} Chips", self.chips)); }
+        if self.charges != 0 { lines.push(format!("Charges: {}", self.charges)); }
+        if self.counter != 0 { lines.push(format!("Count: {}", self.counter)); }
+        lines
+    }
+}
+
+// What relic hooks see. current_* are the running totals at the moment the hook fires.
 pub struct ScoringContext<'a> {
     pub current_chips: i32,
     pub current_mult: i32,
@@ -25,16 +54,48 @@ pub struct ScoringContext<'a> {
     pub stats_snapshot: &'a BaseModifiers, // Immutable snapshot of game state
 }
 
+impl<'a> ScoringContext<'a> {
+    pub fn new(played_cards: &'a [Card], held_cards: &'a [Card], all_cards_in_play: &'a [Card], hand_rank: Option<HandRank>, base_chips: i32, base_mult: i32, stats: &'a BaseModifiers) -> Self {
+        Self {
+            current_chips: base_chips,
+            current_mult: base_mult,
+            current_score: base_chips.saturating_mul(base_mult),
+            base_chips,
+            base_mult,
+            played_cards,
+            held_cards,
+            all_cards_in_play,
+            hand_rank,
+            stats_snapshot: stats,
+        }
+    }
+
+    pub fn set_current(&mut self, chips: i32, mult: f64) {
+        self.current_chips = chips;
+        self.current_mult = mult as i32;
+        self.current_score = (chips as f64 * mult) as i32; // Saturates
+    }
+}
+
 pub trait Relic {
     fn id(&self) -> String;
     fn name(&self) -> String;
 
-    // Hooks for different triggers
-    fn on_hand_scored(&self, _context: &ScoringContext) -> RelicEffect { RelicEffect::None }
-    fn on_played_card_scored(&self, _context: &ScoringContext, _card: &Card) -> RelicEffect { RelicEffect::None }
-    fn on_hand_end(&self, _context: &ScoringContext) -> RelicEffect { RelicEffect::None }
-    fn on_discard(&self, _context: &ScoringContext, _card: &Card) -> RelicEffect { RelicEffect::None }
-    // Add other hooks as needed, e.g., on_boss_start, on_shop_enter, etc.
+    // Hooks for different triggers. &mut so relics (scripted ones for now) can keep state.
+    fn on_hand_scored(&mut self, _context: &ScoringContext) -> RelicEffect { RelicEffect::None }
+    fn on_played_card_scored(&mut self, _context: &ScoringContext, _card: &Card) -> RelicEffect { RelicEffect::None }
+    fn on_held_card(&mut self, _context: &ScoringContext, _card: &Card) -> RelicEffect { RelicEffect::None }
+    // Extra times a card scores this hand, asked once per played / held card before scoring
+    fn retriggers(&mut self, _context: &ScoringContext, _card: &Card, _held: bool) -> u32 { 0 }
+    fn on_hand_end(&mut self, _context: &ScoringContext) -> RelicEffect { RelicEffect::None }
+    fn on_discard(&mut self, _context: &ScoringContext, _card: &Card) -> RelicEffect { RelicEffect::None }
+
+    // Run lifecycle (see logic::relics::fire); only state changes matter here
+    fn on_round_start(&mut self, _stats: &BaseModifiers) {}
+    fn on_round_end(&mut self, _stats: &BaseModifiers) {}
+    fn on_shop_enter(&mut self, _stats: &BaseModifiers) {}
+    fn on_shop_exit(&mut self, _stats: &BaseModifiers) {}
+    fn on_boss_defeated(&mut self, _stats: &BaseModifiers) {}
 }
 
 // A wrapper struct to implement the Relic trait for RelicData
@@ -42,15 +103,75 @@ pub trait Relic {
 pub struct GameRelic {
     pub data: RelicData,
     pub effect: RelicEffect, // New field for the relic's effect
+    pub script: Option<RelicScript>, // relics.json "script"; replaces `effect` when present
+    pub state: RelicState,
+}
+
+impl GameRelic {
+    // Current value of the relic's effect, e.g. "+17 Mult"
+    pub fn effect_text(&self) -> Option<String> {
+        if let Some(script) = &self.script {
+            return scripting::describe(script, &self.state);
+        }
+        match self.effect {
+            RelicEffect::PlusMult(m) => Some(format!("+{} Mult", m)),
+            RelicEffect::PlusChips(c) => Some(format!("+{} Chips", c)),
+            RelicEffect::XMult(x) => Some(format!("X{} Mult", x)),
+            RelicEffect::None => None,
+        }
+    }
+
+    // Shown under the description in tooltips. Scripts with a describe() speak for their
+    // own state; otherwise any non-zero state is listed.
+    pub fn live_text(&self) -> Option<String> {
+        if let Some(text) = self.effect_text() {
+            return Some(format!("Currently: {}", text));
+        }
+        let lines = self.state.summary();
+        (!lines.is_empty()).then(|| lines.join("\n"))
+    }
+
+    fn run_script(&mut self, hook: &str, context: &ScoringContext, card: Option<&Card>) -> Option<RelicEffect> {
+        let script = self.script.as_ref()?;
+        Some(scripting::call_hook(script, hook, &mut self.state, context, card))
+    }
+
+    fn run_lifecycle(&mut self, hook: &str, stats: &BaseModifiers) {
+        if let Some(script) = &self.script {
+            scripting::call_lifecycle(script, hook, &mut self.state, stats);
+        }
+    }
 }
 
 impl Relic for GameRelic {
     fn id(&self) -> String { self.data.id.clone() }
     fn name(&self) -> String { self.data.name.clone() }
 
-    // These methods will now simply return None as the effect is stored directly
-    fn on_hand_scored(&self, _context: &ScoringContext) -> RelicEffect { RelicEffect::None }
-    fn on_played_card_scored(&self, _context: &ScoringContext, _card: &Card) -> RelicEffect { RelicEffect::None }
-    fn on_hand_end(&self, _context: &ScoringContext) -> RelicEffect { RelicEffect::None }
-    fn on_discard(&self, _context: &ScoringContext, _card: &Card) -> RelicEffect { RelicEffect::None }
+    // Scripted relics run their script; the rest apply their static effect once per hand
+    fn on_hand_scored(&mut self, context: &ScoringContext) -> RelicEffect {
+        self.run_script("on_hand_scored", context, None).unwrap_or_else(|| self.effect.clone())
+    }
+    fn on_played_card_scored(&mut self, context: &ScoringContext, card: &Card) -> RelicEffect {
+        self.run_script("on_played_card_scored", context, Some(card)).unwrap_or(RelicEffect::None)
+    }
+    fn on_hand_end(&mut self, context: &ScoringContext) -> RelicEffect {
+        self.run_script("on_hand_end", context, None).unwrap_or(RelicEffect::None)
+    }
+    fn on_held_card(&mut self, context: &ScoringContext, card: &Card) -> RelicEffect {
+        self.run_script("on_held_card", context, Some(card)).unwrap_or(RelicEffect::None)
+    }
+    fn retriggers(&mut self, context: &ScoringContext, card: &Card, held: bool) -> u32 {
+        match &self.script {
+            Some(script) => scripting::call_retriggers(script, &mut self.state, context, card, held),
+            None => 0,
+        }
+    }
+    fn on_discard(&mut self, context: &ScoringContext, card: &Card) -> RelicEffect {
+        self.run_script("on_discard", context, Some(card)).unwrap_or(RelicEffect::None)
+    }
+    fn on_round_start(&mut self, stats: &BaseModifiers) { self.run_lifecycle("on_round_start", stats); }
+    fn on_round_end(&mut self, stats: &BaseModifiers) { self.run_lifecycle("on_round_end", stats); }
+    fn on_shop_enter(&mut self, stats: &BaseModifiers) { self.run_lifecycle("on_shop_enter", stats); }
+    fn on_shop_exit(&mut self, stats: &BaseModifiers) { self.run_lifecycle("on_shop_exit", stats); }
+    fn on_boss_defeated(&mut self, stats: &BaseModifiers) { self.run_lifecycle("on_boss_defeated", stats); }
 }
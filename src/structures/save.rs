@@ -0,0 +1,233 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use crate::structures::stats::BaseModifiers;
+use crate::structures::card::{Card, Enhancement, Seal};
+use crate::structures::hand::{HandLevel, HandRank};
+use crate::logic::map;
+use crate::structures::mods::ModStamp;
+use crate::structures::relic::RelicState;
+use crate::structures::score::Score;
+use crate::structures::daily::DailyRun;
+use crate::consts::DECK_X;
+
+pub const SAVE_PATH: &str = "save.json";
+
+// The run in progress, written every time the player is back on the map and deleted
+// when the run ends. Items are stored by id and looked up in the databases again on
+// load, so data edits apply to continued runs. Relics keep their RelicState.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RunSave {
+    pub mods: Vec<ModStamp>, // Continue is refused if the loaded mods differ
+    pub seed: u64,
+    pub ante: i32,
+    pub round: i32,
+    pub level: i32,
+    pub xp: i32,
+    pub xp_target: i32,
+    pub stat_points: i32,
+    pub enemies_defeated: i32,
+    pub current_hp: i32,
+    pub max_hp: i32,
+    pub money: i32,
+    pub hand_size: i32,
+    pub hands_per_fight: i32,
+    pub discards_per_fight: i32,
+    pub interest_cap: i32,
+    pub chips: i32,
+    pub mult: f64,
+    pub crit_chance: f32,
+    pub crit_mult: f32,
+    pub shop_price_mult: f32,
+    pub ante_scaling: f32,
+    pub endless: bool,
+    pub challenge: Option<String>, // Id; its rules and bans come back from the database
+    pub daily: Option<DailyRun>, // With the decision log up to this save
+    pub total_score: Score,
+    pub relics: Vec<SavedRelic>,
+    pub runes: Vec<String>,
+    pub heirlooms: Vec<String>,
+    pub consumables: Vec<String>,
+    pub deck: Vec<SavedCard>,
+    pub hand_levels: Vec<SavedHandLevel>,
+    pub map_visited: Vec<usize>, // The map itself comes back from (seed, ante)
+    pub map_current: Option<usize>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SavedRelic {
+    pub id: String,
+    #[serde(default)]
+    pub state: RelicState,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedCard {
+    pub suit: i32,
+    pub value: i32,
+    #[serde(default)]
+    pub enhancement: Option<Enhancement>,
+    #[serde(default)]
+    pub seal: Option<Seal>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedHandLevel {
+    pub hand: String, // HandRank::name()
+    pub level: i32,
+    pub chips: i32,
+    pub mult: i32,
+}
+
+impl RunSave {
+    pub fn capture(stats: &BaseModifiers) -> Self {
+        let mut hand_levels: Vec<SavedHandLevel> = stats.hand_levels.iter()
+            .map(|(rank, l)| SavedHandLevel { hand: rank.name().to_string(), level: l.level, chips: l.chips, mult: l.mult })
+            .collect();
+        hand_levels.sort_by(|a, b| a.hand.cmp(&b.hand));
+
+        Self {
+            mods: stats.active_mods.clone(),
+            seed: stats.run_seed,
+            ante: stats.ante,
+            round: stats.round,
+            level: stats.level,
+            xp: stats.xp,
+            xp_target: stats.xp_target,
+            stat_points: stats.stat_points,
+            enemies_defeated: stats.enemies_defeated,
+            current_hp: stats.current_hp,
+            max_hp: stats.max_hp,
+            money: stats.money,
+            hand_size: stats.hand_size,
+            hands_per_fight: stats.hands_per_fight,
+            discards_per_fight: stats.discards_per_fight,
+            interest_cap: stats.interest_cap,
+            chips: stats.chips,
+            mult: stats.mult,
+            crit_chance: stats.crit_chance,
+            crit_mult: stats.crit_mult,
+            shop_price_mult: stats.shop_price_mult,
+            ante_scaling: stats.ante_scaling,
+            endless: stats.endless,
+            challenge: stats.challenge.as_ref().map(|c| c.id.clone()),
+            daily: stats.daily.clone(),
+            total_score: stats.total_score,
+            relics: stats.equipped_relics.iter().map(|r| SavedRelic { id: r.data.id.clone(), state: r.state.clone() }).collect(),
+            runes: stats.equipped_runes.iter().map(|r| r.id.clone()).collect(),
+            heirlooms: stats.heirlooms.iter().map(|h| h.id.clone()).collect(),
+            consumables: stats.consumables.iter().map(|c| c.id.clone()).collect(),
+            deck: stats.owned_deck.iter().map(|c| SavedCard { suit: c.suit, value: c.value, enhancement: c.enhancement, seal: c.seal }).collect(),
+            hand_levels,
+            map_visited: stats.run_map.nodes.iter().enumerate().filter(|(_, n)| n.visited).map(|(i, _)| i).collect(),
+            map_current: stats.run_map.current,
+        }
+    }
+
+    // Puts the run back into `stats`, which should already hold the databases. Ids that
+    // no longer exist are skipped with a warning.
+    pub fn restore(&self, stats: &mut BaseModifiers) {
+        // The rng stream itself isn't saved; carry on from one derived from the round
+        stats.run_seed = self.seed;
+        stats.rng = StdRng::seed_from_u64(self.seed ^ (self.round as u64).wrapping_mul(0xA24B_AED4_963E_E407));
+        stats.ante = self.ante;
+        stats.round = self.round;
+        stats.level = self.level;
+        stats.xp = self.xp;
+        stats.xp_target = self.xp_target;
+        stats.stat_points = self.stat_points;
+        stats.enemies_defeated = self.enemies_defeated;
+        stats.current_hp = self.current_hp;
+        stats.max_hp = self.max_hp;
+        stats.money = self.money;
+        stats.hand_size = self.hand_size;
+        stats.hands_per_fight = self.hands_per_fight;
+        stats.discards_per_fight = self.discards_per_fight;
+        stats.interest_cap = self.interest_cap;
+        stats.chips = self.chips;
+        stats.mult = self.mult;
+        stats.crit_chance = self.crit_chance;
+        stats.crit_mult = self.crit_mult;
+        stats.shop_price_mult = self.shop_price_mult;
+        stats.ante_scaling = self.ante_scaling;
+        stats.endless = self.endless;
+        stats.challenge = self.challenge.as_ref().and_then(|id| stats.all_challenges.get(id).cloned().or_else(|| missing("challenge", id)));
+        if let Some(challenge) = &stats.challenge {
+            stats.all_relics.retain(|id, _| challenge.allows_relic(id));
+        }
+        stats.daily = self.daily.clone();
+        stats.total_score = self.total_score;
+
+        stats.equipped_relics = self.relics.iter().filter_map(|saved| {
+            let mut relic = stats.all_relics.get(&saved.id).cloned().or_else(|| missing("relic", &saved.id))?;
+            relic.state = saved.state.clone();
+            Some(relic)
+        }).collect();
+        stats.equipped_runes = self.runes.iter()
+            .filter_map(|id| stats.available_runes.iter().find(|r| r.id == *id).cloned().or_else(|| missing("rune", id)))
+            .collect();
+        stats.heirlooms = self.heirlooms.iter()
+            .filter_map(|id| stats.all_heirlooms.get(id).cloned().or_else(|| missing("heirloom", id)))
+            .collect();
+        stats.consumables = self.consumables.iter()
+            .filter_map(|id| stats.all_consumables.get(id).cloned().or_else(|| missing("consumable", id)))
+            .collect();
+
+        stats.owned_deck = self.deck.iter().enumerate().map(|(i, saved)| {
+            let mut card = Card::new(i as i32, DECK_X);
+            card.suit = saved.suit;
+            card.value = saved.value;
+            card.enhancement = saved.enhancement;
+            card.seal = saved.seal;
+            card
+        }).collect();
+        stats.hand_levels = self.hand_levels.iter()
+            .filter_map(|l| {
+                let rank = HandRank::from_name(&l.hand)?;
+                Some((rank, HandLevel { level: l.level, chips: l.chips, mult: l.mult }))
+            })
+            .collect();
+
+        stats.run_map = map::generate_map(stats);
+        for &i in &self.map_visited {
+            if let Some(node) = stats.run_map.nodes.get_mut(i) {
+                node.visited = true;
+            }
+        }
+        stats.run_map.current = self.map_current.filter(|&i| i < stats.run_map.nodes.len());
+    }
+
+    pub fn load() -> Option<Self> {
+        let content = fs::read_to_string(SAVE_PATH).ok()?;
+        serde_json::from_str(&content)
+            .map_err(|e| println!("Warning: Could not parse {}: {}", SAVE_PATH, e))
+            .ok()
+    }
+
+    pub fn save(&self) {
+        match serde_json::to_string_pretty(self) {
+            Ok(json) => {
+                if let Err(e) = fs::write(SAVE_PATH, json) {
+                    println!("Warning: Could not write {}: {}", SAVE_PATH, e);
+                }
+            }
+            Err(e) => println!("Warning: Could not serialize run save: {}", e),
+        }
+    }
+
+    pub fn exists() -> bool {
+        Path::new(SAVE_PATH).exists()
+    }
+
+    pub fn delete() {
+        fs::remove_file(SAVE_PATH).ok();
+    }
+}
+
+fn missing<T>(kind: &str, id: &str) -> Option<T> {
+    println!("Warning: saved {} {} no longer exists, dropping it", kind, id);
+    None
+}
@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Sub};
+
+// Scores past this point are shown in scientific notation (e.g. "1.234e12")
+const SCIENTIFIC_THRESHOLD: f64 = 1.0e11;
+
+/// Numeric type for everything score-shaped: hand totals, round score and enemy HP.
+/// Backed by an f64 so exponential xMult builds keep their precision instead of
+/// truncating or wrapping like the old `i32` math did. Values are clamped to the
+/// finite f64 range, so an absurd build saturates instead of turning into NaN/inf.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Score(f64);
+
+impl Score {
+    pub const ZERO: Score = Score(0.0);
+
+    pub fn new(value: f64) -> Self {
+        if value.is_nan() {
+            return Score::ZERO;
+        }
+        Score(value.clamp(-f64::MAX, f64::MAX))
+    }
+
+    /// chips * mult, the final step of every hand
+    pub fn from_chips_mult(chips: f64, mult: f64) -> Self {
+        Score::new(chips * mult)
+    }
+
+    pub fn value(&self) -> f64 {
+        self.0
+    }
+
+    /// Difference clamped at zero (used for remaining enemy HP)
+    pub fn saturating_sub(self, other: Score) -> Score {
+        Score::new((self.0 - other.0).max(0.0))
+    }
+
+    /// self / other as a 0..1 fraction, for progress bars
+    pub fn ratio_of(&self, other: Score) -> f32 {
+        if other.0 <= 0.0 {
+            return 0.0;
+        }
+        (self.0 / other.0).clamp(0.0, 1.0) as f32
+    }
+}
+
+impl From<i32> for Score {
+    fn from(v: i32) -> Self {
+        Score(v as f64)
+    }
+}
+
+impl From<f64> for Score {
+    fn from(v: f64) -> Self {
+        Score::new(v)
+    }
+}
+
+impl Add for Score {
+    type Output = Score;
+    fn add(self, rhs: Score) -> Score {
+        Score::new(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Score {
+    fn add_assign(&mut self, rhs: Score) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Score {
+    type Output = Score;
+    fn sub(self, rhs: Score) -> Score {
+        Score::new(self.0 - rhs.0)
+    }
+}
+
+impl Mul<f64> for Score {
+    type Output = Score;
+    fn mul(self, rhs: f64) -> Score {
+        Score::new(self.0 * rhs)
+    }
+}
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let v = self.0.floor();
+        if v.abs() < SCIENTIFIC_THRESHOLD {
+            write!(f, "{}", v as i64)
+        } else {
+            // Rust's {:e} gives "1.234e12" which is exactly the compact look we want
+            write!(f, "{:.3e}", v)
+        }
+    }
+}
+
+impl Score {
+    /// Short form for tight spots like the enemy HP bar: "45.2K", "3.10B", then
+    /// scientific past the named suffixes. Saturated scores just read "MAX".
+    pub fn compact(&self) -> String {
+        const SUFFIXES: [(f64, &str); 4] = [(1.0e12, "T"), (1.0e9, "B"), (1.0e6, "M"), (1.0e3, "K")];
+        let v = self.0.floor();
+        if v.abs() >= f64::MAX {
+            return "MAX".to_string();
+        }
+        if v.abs() < 1.0e5 {
+            return format!("{}", v as i64);
+        }
+        // Round to 3 significant digits first so 999_999 becomes "1.00M", not "1000K"
+        let step = 10f64.powi(v.abs().log10().floor() as i32 - 2);
+        let v = (v / step).round() * step;
+        if v.abs() >= 1.0e15 {
+            return format!("{:.2e}", v);
+        }
+        let (size, suffix) = SUFFIXES.into_iter().find(|(size, _)| v.abs() >= *size).unwrap_or((1.0, ""));
+        let n = v / size;
+        let decimals = 2 - (n.abs().log10().floor() as usize).min(2);
+        format!("{:.*}{}", decimals, n, suffix)
+    }
+}
+
+/// Shared formatter for mult values, which can be fractional after xMult relics
+pub fn format_mult(mult: f64) -> String {
+    if mult.abs() >= SCIENTIFIC_THRESHOLD {
+        format!("{:.3e}", mult)
+    } else if mult.fract().abs() < 0.05 {
+        format!("{}", mult.round() as i64)
+    } else {
+        format!("{:.1}", mult)
+    }
+}
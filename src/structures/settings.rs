@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use crate::input::InputBindings;
+
+pub const SETTINGS_PATH: &str = "settings.json";
+
+pub const RESOLUTIONS: [(i32, i32); 5] = [(1280, 720), (1600, 900), (1920, 1080), (2560, 1080), (2560, 1440)];
+pub const FPS_CAPS: [u32; 5] = [30, 60, 120, 144, 240];
+// (min, max) of the sliders on the settings screen; load() clamps hand-edited files to these
+pub const GAME_SPEED_RANGE: (f32, f32) = (0.5, 2.0);
+pub const SCREEN_SHAKE_RANGE: (f32, f32) = (0.0, 1.0);
+pub const ANIMATION_SPEED_RANGE: (f32, f32) = (0.5, 3.0);
+
+// Persisted player options. Missing keys fall back to defaults so old files keep loading.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub window_width: i32,
+    pub window_height: i32,
+    pub fullscreen: bool,
+    pub vsync: bool,
+    pub fps_cap: u32,
+    pub game_speed: f32,      // Scales the whole frame dt
+    pub screen_shake: f32,    // 0.0 = off, 1.0 = full trauma
+    pub animation_speed: f32, // Scoring sequence speed
+    pub skip_scoring_on_click: bool,
+    pub high_contrast: bool,
+    pub debug_logging: bool,
+    pub bindings: InputBindings, // Keyboard / gamepad map, edited on the Controls screen
+    pub disabled_mods: Vec<String>, // Mod ids switched off on the Mods screen (new mods start on)
+    pub player_name: String, // On daily results; empty = the login name
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            window_width: 1600,
+            window_height: 900,
+            fullscreen: false,
+            vsync: true,
+            fps_cap: 144,
+            game_speed: 1.0,
+            screen_shake: 1.0,
+            animation_speed: 1.0,
+            skip_scoring_on_click: true,
+            high_contrast: false,
+            debug_logging: false,
+            bindings: InputBindings::default(),
+            disabled_mods: Vec::new(),
+            player_name: String::new(),
+        }
+    }
+}
+
+impl Settings {
+    pub fn load() -> Self {
+        let mut settings = match fs::read_to_string(SETTINGS_PATH) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+                println!("Warning: Could not parse {}: {} (using defaults)", SETTINGS_PATH, e);
+                Settings::default()
+            }),
+            Err(_) => Settings::default(),
+        };
+        // A game_speed of 0 would freeze every frame, a huge shake throws the screen around
+        settings.game_speed = settings.game_speed.clamp(GAME_SPEED_RANGE.0, GAME_SPEED_RANGE.1);
+        settings.screen_shake = settings.screen_shake.clamp(SCREEN_SHAKE_RANGE.0, SCREEN_SHAKE_RANGE.1);
+        settings.animation_speed = settings.animation_speed.clamp(ANIMATION_SPEED_RANGE.0, ANIMATION_SPEED_RANGE.1);
+        settings
+    }
+
+    pub fn save(&self) {
+        match serde_json::to_string_pretty(self) {
+            Ok(json) => {
+                if let Err(e) = fs::write(SETTINGS_PATH, json) {
+                    println!("Warning: Could not write {}: {}", SETTINGS_PATH, e);
+                }
+            }
+            Err(e) => println!("Warning: Could not serialize settings: {}", e),
+        }
+    }
+
+    pub fn resolution_index(&self) -> usize {
+        RESOLUTIONS.iter()
+            .position(|&(w, h)| w == self.window_width && h == self.window_height)
+            .unwrap_or(1)
+    }
+
+    pub fn fps_index(&self) -> usize {
+        FPS_CAPS.iter().position(|&f| f == self.fps_cap).unwrap_or(3)
+    }
+}
@@ -1,13 +1,19 @@
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum GameState {
     Menu,
+    ChallengeSelect, // Standard run or a challenge, before rune select
     RuneSelect, // NEW: Before playing
+    Map, // Pick the next node between encounters
+    Event, // Random event from a map node
     Playing,
     BattleResult,
     Shop,
     StatsMenu,
     Settings,
-    GameOver,
+    Controls, // Key / pad rebinding, opened from Settings
+    Mods, // Data packs found in mods/, opened from the menu
+    RunEnd, // Defeat or victory summary, with restart options
+    Daily, // Daily run leaderboard, opened from the menu
     Exit,
 }
 
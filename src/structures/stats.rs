@@ -2,14 +2,31 @@ use raylib::prelude::*;
 use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
 use crate::structures::state::GameState;
-use crate::structures::hand::HandRank;
+use crate::structures::hand::{HandRank, HandLevel};
 use crate::logic::metrics::GameMetrics;
-use rand::{self, Rng};
+use rand::{self, Rng, SeedableRng};
+use rand::rngs::StdRng;
 
 use crate::structures::relic::GameRelic;
 use crate::structures::consumable::Consumable;
 use crate::structures::heirloom::Heirloom;
-use crate::structures::enemy::Enemy;
+use crate::structures::enemy::{BossAbility, Enemy};
+use crate::structures::score::{self, Score};
+use crate::score_manager::ScoreResult;
+use crate::structures::settings::Settings;
+use crate::structures::profile::Profile;
+use crate::structures::map::RunMap;
+use crate::structures::card::Card;
+use crate::structures::event::EventData;
+use crate::structures::effect::PlayerStat;
+use crate::structures::mods::ModStamp;
+use crate::structures::assets::GameData;
+use crate::structures::data_loader;
+use crate::structures::endless::EndlessCurve;
+use crate::structures::challenge::{Challenge, ChallengeRule};
+use crate::structures::deck::StartingDeck;
+use crate::structures::daily::{DailyResult, DailyRun, Leaderboard};
+use crate::input::{Action, ActionState};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum RuneType {
@@ -25,18 +42,10 @@ pub struct Rune {
     pub cost: i32,
 }
 
-#[derive(Debug, Clone, PartialEq)]
-pub enum BossAbility {
-    None,
-    SilenceSuit(i32),
-    HandSizeMinusOne,
-    DoubleTarget,
-    PayToDiscard,
-}
-
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SortMode {
     Rank, Suit,
+    Manual, // Player dragged cards into their own order; kept until a sort button is pressed
 }
 
 #[derive(Debug)]
@@ -50,6 +59,61 @@ pub struct FloatingText {
     pub max_life: f32,
 }
 
+// What happened in the current fight, for the payout and the battle result screen.
+// Replaced with a fresh one by start_next_round.
+#[derive(Debug, Clone, Default)]
+pub struct FightRecord {
+    pub cards_discarded: i32,
+    pub best_hand: Option<(HandRank, Score)>,
+    pub relic_triggers: Vec<(String, u32)>, // Relic name, scoring steps it added (first-seen order)
+    pub xp_gained: i32,
+    pub levels_gained: i32,
+}
+
+impl FightRecord {
+    pub fn record_hand(&mut self, rank: HandRank, total: Score) {
+        if self.best_hand.is_none_or(|(_, best)| total > best) {
+            self.best_hand = Some((rank, total));
+        }
+    }
+
+    pub fn record_relic(&mut self, name: &str) {
+        match self.relic_triggers.iter_mut().find(|(n, _)| n == name) {
+            Some((_, count)) => *count += 1,
+            None => self.relic_triggers.push((name.to_string(), 1)),
+        }
+    }
+}
+
+// The run as it ended, for the run-end screen
+#[derive(Debug, Clone, Default)]
+pub struct RunSummary {
+    pub victory: bool,
+    pub seed: u64,
+    pub ante: i32,
+    pub round: i32,
+    pub level: i32,
+    pub enemies_defeated: i32,
+    pub total_score: Score,
+    pub best_hand: Option<(HandRank, Score)>,
+    pub money: i32,
+    pub relics: Vec<String>,
+    pub enemy: String, // Who won the last fight, or the final boss on a victory
+    pub endless: bool,
+    pub new_high_score: bool, // Endless only: beat profile.endless_high_score
+    pub challenge: Option<String>, // Challenge name, if the run was one
+    pub daily: Option<DailyResult>, // Daily runs: the shareable result
+    pub daily_rank: Option<usize>, // Its place on that date's leaderboard
+}
+
+// Screen-corner notice (data reloads, errors). Drawn on top of every state.
+#[derive(Debug)]
+pub struct Toast {
+    pub text: String,
+    pub color: Color,
+    pub life: f32,
+}
+
 #[derive(Debug)]
 pub struct Particle {
     pub pos: Vector2,
@@ -79,13 +143,15 @@ pub struct BaseModifiers {
     pub hands_remaining: i32,
     pub discards_remaining: i32,
     pub hand_size: i32,
+    pub hands_per_fight: i32, // What hands/discards reset to at the start of each fight
+    pub discards_per_fight: i32,
 
     pub chips: i32,
-    pub mult: i32,
-    pub total_score: i32,
-    pub round_score: i32,
-    pub display_score: f32,
-    pub target_score: i32,
+    pub mult: f64,
+    pub total_score: Score,
+    pub round_score: Score,
+    pub display_score: Score,
+    pub target_score: Score,
     pub crit_chance: f32,
     pub crit_mult: f32,
     pub is_crit_active: bool,
@@ -97,7 +163,7 @@ pub struct BaseModifiers {
     pub current_enemy: Option<Enemy>,
     pub enemy_name: String,
     pub enemy_damage: i32,
-    pub active_ability: BossAbility,
+    pub active_abilities: Vec<BossAbility>, // Empty for regular enemies; deep endless bosses stack two
 
     pub consumables: Vec<Consumable>,
     pub heirlooms: Vec<Heirloom>,
@@ -112,9 +178,14 @@ pub struct BaseModifiers {
     pub all_relics: HashMap<String, GameRelic>,
     pub all_consumables: HashMap<String, Consumable>,
     pub all_heirlooms: HashMap<String, Heirloom>,
+    pub all_events: HashMap<String, EventData>,
+    pub all_challenges: HashMap<String, Challenge>,
+    pub all_decks: HashMap<String, StartingDeck>,
+    pub active_mods: Vec<ModStamp>, // Enabled mods the databases were built with
 
     pub floating_texts: Vec<FloatingText>,
     pub particles: Vec<Particle>,
+    pub toasts: Vec<Toast>,
     pub previous_state: GameState,
     pub current_sort: SortMode,
 
@@ -123,9 +194,17 @@ pub struct BaseModifiers {
     pub discard_index: usize,
     pub discard_timer: f32,
     pub score_delay: f32,
+    pub score_sequence: Option<ScoreResult>, // Breakdown currently being animated
+    pub chips_pulse: f32,
+    pub mult_pulse: f32,
 
     pub shop_price_mult: f32,
     pub ante_scaling: f32,
+    pub endless: bool, // Kept going after beating the final boss
+    pub challenge: Option<Challenge>, // Picked before rune select; None = standard run
+    pub daily: Option<DailyRun>, // Daily runs log their decisions here (see logic::daily)
+    pub daily_board: Leaderboard, // leaderboard.json, read when the daily screen opens
+    pub endless_curve: EndlessCurve, // From enemies.json
     pub shop_y_offset: f32,
 
     pub trauma: f32,
@@ -148,8 +227,33 @@ pub struct BaseModifiers {
 
     pub damage_flash_timer: f32,
     pub window_y_offset: f32,
+    pub loadout_scroll: f32, // Rune select loadout list
+    pub inspected_card: Option<i32>, // Card id shown in the right-click inspect panel
+    pub actions: ActionState, // Keyboard / pad actions pressed this frame
+    pub focus_row: usize, // Keyboard focus on menu-like screens (reset on state change)
+    pub focus_col: usize,
+    pub card_cursor: Option<usize>, // Hand index under the keyboard cursor
+    pub drag_origin: Vector2, // Mouse position when the current card / relic press started
+    pub dragging_relic: Option<usize>, // Index into equipped_relics being dragged
+    pub rebinding: Option<Action>, // Controls screen is waiting for a key
     pub input_consumed: bool,
+
+    // Run
+    pub run_seed: u64,
+    pub rng: StdRng, // Seeded from run_seed; use this (not thread_rng) for anything run-related
+    pub run_map: RunMap,
+    pub owned_deck: Vec<Card>, // Cards the run owns; each fight shuffles a copy
+    pub hand_levels: HashMap<HandRank, HandLevel>,
+    pub current_event: Option<EventData>,
+    pub event_result: Option<Vec<String>>, // Log of the picked choice, shown until Continue
+    pub fight: FightRecord,
+    pub best_hand: Option<(HandRank, Score)>, // Whole run
+    pub run_summary: Option<RunSummary>, // Set when the run ends
+    pub result_timer: f32, // Seconds on the battle result screen, drives the tally
+    pub viewing_deck: bool, // Deck overlay open on the battle result screen
     pub game_metrics: GameMetrics,
+    pub settings: Settings,
+    pub profile: Profile,
 }
 
 impl Default for BaseModifiers {
@@ -163,8 +267,9 @@ impl Default for BaseModifiers {
             interest_cap: 25,
 
             hands_remaining: 4, discards_remaining: 5, hand_size: 8,
+            hands_per_fight: 4, discards_per_fight: 5,
 
-            chips: 0, mult: 0, total_score: 0, round_score: 0, display_score: 0.0, target_score: 300,
+            chips: 0, mult: 0.0, total_score: Score::ZERO, round_score: Score::ZERO, display_score: Score::ZERO, target_score: Score::from(300),
             crit_chance: 0.10, crit_mult: 1.5, is_crit_active: false,
 
             deck_count: 52, hand_rank: None,
@@ -173,7 +278,7 @@ impl Default for BaseModifiers {
             current_enemy: None,
             enemy_name: "Giant Rat".to_string(),
             enemy_damage: 10,
-            active_ability: BossAbility::None,
+            active_abilities: Vec::new(),
 
             consumables: Vec::new(),
             heirlooms: Vec::new(),
@@ -186,12 +291,19 @@ impl Default for BaseModifiers {
             all_relics: HashMap::new(),
             all_consumables: HashMap::new(),
             all_heirlooms: HashMap::new(),
+            all_events: HashMap::new(),
+            all_challenges: HashMap::new(),
+            all_decks: HashMap::new(),
+            active_mods: Vec::new(),
 
-            floating_texts: Vec::new(), particles: Vec::new(),
+            floating_texts: Vec::new(), particles: Vec::new(), toasts: Vec::new(),
             previous_state: GameState::Menu, current_sort: SortMode::Rank,
 
             score_index: 0, score_timer: 0.0, discard_index: 0, discard_timer: 0.0, score_delay: 0.0,
+            score_sequence: None,
+            chips_pulse: 0.0, mult_pulse: 0.0,
             shop_price_mult: 1.0, ante_scaling: 1.5,
+            endless: false, endless_curve: EndlessCurve::default(), challenge: None, daily: None, daily_board: Leaderboard::default(),
             shop_y_offset: 0.0,
 
             trauma: 0.0, shake_offset: Vector2::zero(), shake_rotation: 0.0,
@@ -212,14 +324,95 @@ impl Default for BaseModifiers {
 
             damage_flash_timer: 0.0,
             window_y_offset: 0.0,
+            loadout_scroll: 0.0,
+            inspected_card: None,
+            actions: ActionState::default(),
+            focus_row: 0, focus_col: 0,
+            card_cursor: None,
+            drag_origin: Vector2::zero(),
+            dragging_relic: None,
+            rebinding: None,
             input_consumed: false,
+            run_seed: 0,
+            rng: StdRng::seed_from_u64(0),
+            run_map: RunMap::default(),
+            owned_deck: Vec::new(),
+            hand_levels: HashMap::new(),
+            current_event: None,
+            event_result: None,
+            fight: FightRecord::default(),
+            best_hand: None,
+            run_summary: None,
+            result_timer: 0.0,
+            viewing_deck: false,
             game_metrics: GameMetrics::new(),
+            settings: Settings::default(),
+            profile: Profile::default(),
         }
     }
 }
 
 impl BaseModifiers {
+    // A fresh run: default stats, databases cloned from `assets`, the player's settings and
+    // profile carried over, and the first map for `seed`. Every new run / restart goes here.
+    pub fn new_run(data: &GameData, settings: Settings, profile: Profile, seed: u64) -> Self {
+        let mut stats = BaseModifiers { settings, profile, ..Default::default() };
+        stats.game_metrics.verbose = stats.settings.debug_logging;
+        data_loader::copy_to_stats(data, &mut stats);
+        crate::logic::map::start_run(&mut stats, seed);
+        stats
+    }
+
+    // Re-seeds everything run-related (map layout, enemy picks, shuffles, shop rolls)
+    pub fn seed_run(&mut self, seed: u64) {
+        self.run_seed = seed;
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
+    // Hand size for the current fight; some bosses shrink it
+    pub fn effective_hand_size(&self) -> i32 {
+        if self.has_ability(&BossAbility::HandSizeMinusOne) { self.hand_size - 1 } else { self.hand_size }
+    }
+
+    pub fn has_rule(&self, rule: ChallengeRule) -> bool {
+        self.challenge.as_ref().is_some_and(|c| c.has_rule(rule))
+    }
+
+    pub fn has_ability(&self, ability: &BossAbility) -> bool {
+        self.active_abilities.contains(ability)
+    }
+
+    // Suit the boss has silenced for flushes, if any
+    pub fn silenced_suit(&self) -> Option<i32> {
+        self.active_abilities.iter().find_map(|a| match a {
+            BossAbility::SilenceSuit(suit) => Some(*suit),
+            _ => None,
+        })
+    }
+
+    // e.g. "SILENCE + HP x2"; empty for regular enemies
+    pub fn ability_label(&self) -> String {
+        self.active_abilities.iter().map(|a| a.label()).collect::<Vec<_>>().join(" + ")
+    }
+
+    // Stat change shared by runes, heirlooms and event effects. Returns a display label.
+    pub fn modify_stat(&mut self, stat: PlayerStat, amount: i32) -> &'static str {
+        match stat {
+            PlayerStat::MaxHp => {
+                self.max_hp = (self.max_hp + amount).max(1);
+                self.current_hp = (self.current_hp + amount.max(0)).min(self.max_hp);
+                "Max HP"
+            }
+            PlayerStat::HandSize => { self.hand_size = (self.hand_size + amount).max(1); "Hand Size" }
+            PlayerStat::HandsPerFight => { self.hands_per_fight = (self.hands_per_fight + amount).max(1); "Hands" }
+            PlayerStat::DiscardsPerFight => { self.discards_per_fight = (self.discards_per_fight + amount).max(0); "Discards" }
+            PlayerStat::InterestCap => { self.interest_cap = (self.interest_cap + amount).max(0); "Interest Cap" }
+            PlayerStat::StatPoints => { self.stat_points += amount; "Stat Points" }
+        }
+    }
+
     pub fn add_trauma(&mut self, amount: f32) {
+        let amount = amount * self.settings.screen_shake;
         self.trauma = (self.trauma + amount).clamp(0.0, 1.0);
     }
 
@@ -239,12 +432,18 @@ impl BaseModifiers {
 
     pub fn update_vfx(&mut self, dt: f32) {
         if self.damage_flash_timer > 0.0 { self.damage_flash_timer -= dt; }
+        self.chips_pulse = (self.chips_pulse - dt * 4.0).max(0.0);
+        self.mult_pulse = (self.mult_pulse - dt * 4.0).max(0.0);
         self.floating_texts.retain_mut(|ft| {
             ft.life -= dt;
             ft.pos += ft.vel * dt;
             ft.vel.y *= 0.95;
             ft.life > 0.0
         });
+        self.toasts.retain_mut(|t| {
+            t.life -= dt;
+            t.life > 0.0
+        });
         self.particles.retain_mut(|p| {
             p.life -= dt;
             p.pos += p.vel * dt;
@@ -258,12 +457,12 @@ impl BaseModifiers {
         self.hands_remaining_text = format!("Hands: {}", self.hands_remaining);
         self.discards_remaining_text = format!("Disc: {}", self.discards_remaining);
         self.chips_text = format!("{}", self.chips);
-        self.mult_text = format!("{}", self.mult);
+        self.mult_text = score::format_mult(self.mult);
         self.hp_text = format!("{}/{}", self.current_hp, self.max_hp);
         self.money_text = format!("$ {}", self.money);
         self.level_text = format!("{}", self.level);
-        let remaining_hp = (self.target_score - self.display_score as i32).max(0);
-        self.enemy_hp_text = format!("{} / {}", remaining_hp, self.target_score);
+        let remaining_hp = self.target_score.saturating_sub(self.display_score);
+        self.enemy_hp_text = format!("{} / {}", remaining_hp.compact(), self.target_score.compact());
         self.current_round_text = format!("Round {}", self.round);
         self.stat_points_text = format!("Points Available: {}", self.stat_points);
         self.max_hp_stat_text = format!("{}", self.max_hp);
@@ -306,6 +505,12 @@ pub fn spawn_floating_text(stats: &mut BaseModifiers, text: String, pos: Vector2
     });
 }
 
+pub const TOAST_LIFE: f32 = 5.0;
+
+pub fn push_toast(stats: &mut BaseModifiers, text: String, color: Color) {
+    stats.toasts.push(Toast { text, color, life: TOAST_LIFE });
+}
+
 pub fn spawn_particle_burst(stats: &mut BaseModifiers, pos: Vector2, color: Color) {
     for _ in 0..15 {
         let angle = unsafe { raylib::ffi::GetRandomValue(0, 360) } as f32 * 0.0174533;
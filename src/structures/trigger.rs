@@ -0,0 +1,39 @@
+// Scoring order for one hand. score_manager walks this queue front to back:
+//
+//   1. each played card, left to right, immediately followed by its retriggers
+//   2. each held card (held-card effects), also followed by its retriggers
+//   3. each relic's hand effect, left to right
+//
+// Retrigger counts come from seals and relics (see score_manager::retriggers).
+
+pub const MAX_RETRIGGERS: u32 = 8; // Per card per hand, whatever stacks up
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trigger {
+    Played { index: usize, repeat: u32 }, // Index into the played cards; repeat 0 is the first pass
+    Held { index: usize, repeat: u32 },   // Index into the held cards
+    Relic(usize),                        // Index into equipped_relics
+}
+
+impl Trigger {
+    pub fn is_retrigger(&self) -> bool {
+        matches!(self, Trigger::Played { repeat, .. } | Trigger::Held { repeat, .. } if *repeat > 0)
+    }
+}
+
+// `played` / `held` hold the retrigger count of each card, in order
+pub fn build_queue(played: &[u32], held: &[u32], relics: usize) -> Vec<Trigger> {
+    let mut queue = Vec::new();
+    for (index, &count) in played.iter().enumerate() {
+        for repeat in 0..=count.min(MAX_RETRIGGERS) {
+            queue.push(Trigger::Played { index, repeat });
+        }
+    }
+    for (index, &count) in held.iter().enumerate() {
+        for repeat in 0..=count.min(MAX_RETRIGGERS) {
+            queue.push(Trigger::Held { index, repeat });
+        }
+    }
+    queue.extend((0..relics).map(Trigger::Relic));
+    queue
+}
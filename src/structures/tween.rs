@@ -1,6 +1,23 @@
 use raylib::prelude::*;
 use crate::utils::easing;
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Easing {
+    Linear,
+    OutCubic, // Quick start, soft landing (deals, discards)
+    OutBack,  // Slight overshoot (hand re-layout)
+}
+
+impl Easing {
+    pub fn apply(&self, t: f32) -> f32 {
+        match self {
+            Easing::Linear => t,
+            Easing::OutCubic => easing::ease_out_cubic(t),
+            Easing::OutBack => easing::ease_out_back(t),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Tween {
     start: Vector2,
@@ -8,33 +25,73 @@ pub struct Tween {
     control: Vector2,
     elapsed: f32,
     duration: f32,
+    delay: f32, // Seconds to wait before moving (staggered deals)
+    easing: Easing,
+    // Optional extra channels, interpolated with the same eased t
+    scale: Option<(Vector2, Vector2)>,
+    rotation: Option<(f32, f32)>, // Radians, like Card::rotation
 }
 
 impl Tween {
     pub fn new(start: Vector2, end: Vector2, duration: f32) -> Self {
-        let control = Vector2::new(
-            (start.x + end.x) / 2.0,
-            (start.y + end.y) / 2.0 - 150.0,
-        );
         Self {
             start,
             end,
-            control,
+            control: Self::arc_control(start, end, 150.0),
             elapsed: 0.0,
             duration,
+            delay: 0.0,
+            easing: Easing::OutBack,
+            scale: None,
+            rotation: None,
         }
     }
 
+    // Bezier control point `height` px above the midpoint (0 = straight line)
+    fn arc_control(start: Vector2, end: Vector2, height: f32) -> Vector2 {
+        Vector2::new((start.x + end.x) / 2.0, (start.y + end.y) / 2.0 - height)
+    }
+
+    pub fn with_delay(mut self, delay: f32) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    pub fn with_arc(mut self, height: f32) -> Self {
+        self.control = Self::arc_control(self.start, self.end, height);
+        self
+    }
+
+    pub fn with_scale(mut self, from: Vector2, to: Vector2) -> Self {
+        self.scale = Some((from, to));
+        self
+    }
+
+    pub fn with_rotation(mut self, from: f32, to: f32) -> Self {
+        self.rotation = Some((from, to));
+        self
+    }
+
     pub fn update(&mut self, dt: f32) {
         self.elapsed += dt;
-        if self.elapsed > self.duration {
-            self.elapsed = self.duration;
+        if self.elapsed > self.delay + self.duration {
+            self.elapsed = self.delay + self.duration;
         }
     }
 
+    // Eased progress, 0.0 while still waiting out the delay
+    fn progress(&self) -> f32 {
+        let t = ((self.elapsed - self.delay) / self.duration).clamp(0.0, 1.0);
+        self.easing.apply(t)
+    }
+
     pub fn solve(&self) -> Vector2 {
-        let t = (self.elapsed / self.duration).clamp(0.0, 1.0);
-        let eased_t = easing::ease_out_back(t);
+        let eased_t = self.progress();
 
         let one_minus_eased_t = 1.0 - eased_t;
         self.start * (one_minus_eased_t * one_minus_eased_t)
@@ -42,7 +99,25 @@ impl Tween {
             + self.end * (eased_t * eased_t)
     }
 
+    pub fn solve_scale(&self) -> Option<Vector2> {
+        let t = self.progress();
+        self.scale.map(|(from, to)| from + (to - from) * t)
+    }
+
+    pub fn solve_rotation(&self) -> Option<f32> {
+        let t = self.progress();
+        self.rotation.map(|(from, to)| from + (to - from) * t)
+    }
+
+    pub fn end_scale(&self) -> Option<Vector2> {
+        self.scale.map(|(_, to)| to)
+    }
+
+    pub fn end_rotation(&self) -> Option<f32> {
+        self.rotation.map(|(_, to)| to)
+    }
+
     pub fn is_finished(&self) -> bool {
-        self.elapsed >= self.duration
+        self.elapsed >= self.delay + self.duration
     }
 }
@@ -0,0 +1,207 @@
+// Small immediate-mode widget layer.
+//
+// Hit-testing lives in `interact()` and is shared by both paths: the logic side calls
+// `clicked()` / `clicked_circle()` with a layout:: rect, the drawing side calls `button()`
+// etc. with the SAME rect, which renders hover/press states and reports the click too.
+use raylib::prelude::*;
+use crate::consts::*;
+use crate::layout::Viewport;
+
+const LINE_SPACING: f32 = 1.2; // Line height as a multiple of font size
+
+// --- HIT-TESTING ---
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Interaction {
+    pub hovered: bool,
+    pub down: bool,    // Held over the widget
+    pub clicked: bool, // Pressed this frame over the widget
+}
+
+impl Interaction {
+    // Press offset and shadow offset for the "raised button" look
+    pub fn offsets(&self) -> (f32, f32) {
+        if self.hovered {
+            if self.down { (4.0, 0.0) } else { (-2.0, 6.0) }
+        } else {
+            (0.0, 3.0)
+        }
+    }
+}
+
+fn interaction(rl: &RaylibHandle, hovered: bool) -> Interaction {
+    Interaction {
+        hovered,
+        down: hovered && rl.is_mouse_button_down(MouseButton::MOUSE_BUTTON_LEFT),
+        clicked: hovered && rl.is_mouse_button_pressed(MouseButton::MOUSE_BUTTON_LEFT),
+    }
+}
+
+pub fn interact(rl: &RaylibHandle, rect: Rectangle) -> Interaction {
+    interaction(rl, rect.check_collision_point_rec(rl.get_mouse_position()))
+}
+
+pub fn interact_circle(rl: &RaylibHandle, center: Vector2, radius: f32) -> Interaction {
+    interaction(rl, center.distance_to(rl.get_mouse_position()) < radius)
+}
+
+pub fn clicked(rl: &RaylibHandle, rect: Rectangle) -> bool {
+    interact(rl, rect).clicked
+}
+
+pub fn clicked_circle(rl: &RaylibHandle, center: Vector2, radius: f32) -> bool {
+    interact_circle(rl, center, radius).clicked
+}
+
+// --- BUTTONS ---
+// Rounded button with a drop shadow and a centred label. Disabled buttons draw grey and never click.
+pub fn button(d: &mut RaylibDrawHandle, font: &Font, rect: Rectangle, label: &str, color: Color, enabled: bool) -> bool {
+    let state = interact(d, rect);
+    let (off, shad) = if enabled { state.offsets() } else { (0.0, 3.0) };
+    let fill = if enabled { color } else { Color::GRAY };
+
+    d.draw_rectangle_rounded(Rectangle::new(rect.x, rect.y + shad, rect.width, rect.height), 0.2, 4, Color::BLACK.alpha(0.5));
+    d.draw_rectangle_rounded(Rectangle::new(rect.x, rect.y + off, rect.width, rect.height), 0.2, 4, fill);
+
+    let font_size = (rect.height * 0.45).min(30.0);
+    let dim = font.measure_text(label, font_size, 1.0);
+    let pos = Vector2::new(rect.x + rect.width / 2.0 - dim.x / 2.0, rect.y + rect.height / 2.0 - dim.y / 2.0 + off);
+    d.draw_text_ex(font, label, pos, font_size, 1.0, Color::BLACK);
+
+    enabled && state.clicked
+}
+
+// Button skinned with a texture (play/discard). Same press feel as button().
+pub fn texture_button(d: &mut RaylibDrawHandle, font: &Font, rect: Rectangle, texture: &Texture2D, label: &str, enabled: bool) -> bool {
+    let state = interact(d, rect);
+    let (off, shad) = state.offsets();
+    let tint = if enabled { Color::WHITE } else { Color::GRAY };
+    let src = Rectangle::new(0.0, 0.0, texture.width as f32, texture.height as f32);
+
+    d.draw_texture_pro(texture, src, Rectangle::new(rect.x, rect.y + shad, rect.width, rect.height), Vector2::zero(), 0.0, Color::BLACK.alpha(0.5));
+    d.draw_texture_pro(texture, src, Rectangle::new(rect.x, rect.y + off, rect.width, rect.height), Vector2::zero(), 0.0, tint);
+
+    let dim = font.measure_text(label, 24.0, 1.0);
+    let pos = Vector2::new(rect.x + rect.width / 2.0 - dim.x / 2.0, rect.y + 15.0 + off);
+    d.draw_text_ex(font, label, pos, 24.0, 1.0, tint);
+
+    enabled && state.clicked
+}
+
+// Round icon button (runes). Falls back to the first letter of `name` when there's no icon.
+pub fn icon_button(d: &mut RaylibDrawHandle, font: &Font, center: Vector2, radius: f32, icon: Option<&Texture2D>, name: &str, color: Color, selected: bool) -> Interaction {
+    let state = interact_circle(d, center, radius);
+    let (cx, cy) = (center.x as i32, center.y as i32);
+
+    if selected {
+        d.draw_circle_lines(cx, cy, radius + 4.0, NEU_ORANGE);
+        d.draw_circle(cx, cy, radius + 2.0, color.alpha(0.2));
+    } else if state.hovered {
+        d.draw_circle_lines(cx, cy, radius + 2.0, Color::WHITE);
+    } else {
+        d.draw_circle_lines(cx, cy, radius, color.alpha(0.5));
+    }
+
+    if let Some(tex) = icon {
+        let dest = Rectangle::new(center.x - radius, center.y - radius, radius * 2.0, radius * 2.0);
+        let src = Rectangle::new(0.0, 0.0, tex.width as f32, tex.height as f32);
+        let tint = if selected || state.hovered { Color::WHITE } else { Color::GRAY };
+        d.draw_texture_pro(tex, src, dest, Vector2::zero(), 0.0, tint);
+    } else {
+        d.draw_circle(cx, cy, radius, color.alpha(0.2));
+        let letter = name.get(0..1).unwrap_or("?");
+        d.draw_text_ex(font, letter, Vector2::new(center.x - 10.0, center.y - 15.0), 30.0, 1.0, PARCHMENT);
+    }
+
+    state
+}
+
+// --- KEYBOARD FOCUS ---
+// Outline around the widget that Confirm would activate. Only drawn while the player
+// is driving the UI with keys / pad (see input::ActionState::using_keys).
+pub fn focus_ring(d: &mut RaylibDrawHandle, rect: Rectangle) {
+    let ring = Rectangle::new(rect.x - 5.0, rect.y - 5.0, rect.width + 10.0, rect.height + 10.0);
+    d.draw_rectangle_rounded_lines_ex(ring, 0.2, 4, 3.0, NEU_YELLOW);
+}
+
+pub fn focus_ring_circle(d: &mut RaylibDrawHandle, center: Vector2, radius: f32) {
+    d.draw_ring(center, radius + 6.0, radius + 9.0, 0.0, 360.0, 32, NEU_YELLOW);
+}
+
+// --- CONTAINERS ---
+pub fn panel(d: &mut RaylibDrawHandle, rect: Rectangle, border: Color) {
+    d.draw_rectangle_rounded(rect, 0.05, 4, NEU_BLACK.alpha(0.9));
+    d.draw_rectangle_rounded_lines_ex(rect, 0.05, 4, 2.0, border);
+}
+
+// Floating info box next to `anchor`, kept inside the canvas
+pub fn tooltip(d: &mut RaylibDrawHandle, font: &Font, anchor: Vector2, title: &str, body: &str, color: Color) {
+    let width = 300.0;
+    let body_h = wrap_text(font, body, 20.0, width - 40.0).len() as f32 * 20.0 * LINE_SPACING;
+    let height = 70.0 + body_h;
+
+    let x = (anchor.x + 20.0).min(SCREEN_WIDTH - width - 10.0).max(10.0);
+    // Prefer above the anchor, flip below it when there's no room
+    let above = anchor.y - height - 10.0;
+    let y = if above >= 10.0 { above } else { (anchor.y + 20.0).min(SCREEN_HEIGHT - height - 10.0) };
+    let rect = Rectangle::new(x, y, width, height);
+
+    panel(d, rect, color);
+    d.draw_text_ex(font, title, Vector2::new(x + 20.0, y + 15.0), 26.0, 1.0, color);
+    label_wrapped(d, font, body, Vector2::new(x + 20.0, y + 55.0), width - 40.0, 20.0, PARCHMENT);
+}
+
+// Vertical list clipped to `rect`. `scroll` is owned by the caller (see scroll()) so it
+// survives between frames; `draw_item` gets each visible row's rect.
+pub fn scroll_list<F>(d: &mut RaylibDrawHandle, rect: Rectangle, item_h: f32, count: usize, scroll: f32, mut draw_item: F)
+where
+    F: FnMut(&mut RaylibDrawHandle, usize, Rectangle),
+{
+    // Scissor works in window pixels, not canvas units
+    let clip = Viewport::current(d).to_screen(rect);
+    let mut d_clip = d.begin_scissor_mode(clip.x as i32, clip.y as i32, clip.width as i32, clip.height as i32);
+
+    for i in 0..count {
+        let row = Rectangle::new(rect.x, rect.y + i as f32 * item_h - scroll, rect.width, item_h);
+        if row.y + row.height < rect.y || row.y > rect.y + rect.height { continue; }
+        draw_item(&mut d_clip, i, row);
+    }
+}
+
+// Mouse-wheel scrolling for a scroll_list over `rect` holding `content_h` worth of rows
+pub fn scroll(rl: &RaylibHandle, rect: Rectangle, scroll: &mut f32, content_h: f32) {
+    if rect.check_collision_point_rec(rl.get_mouse_position()) {
+        *scroll -= rl.get_mouse_wheel_move() * 30.0;
+    }
+    *scroll = scroll.clamp(0.0, (content_h - rect.height).max(0.0));
+}
+
+// --- TEXT ---
+// Greedy word wrap; '\n' forces a new line
+pub fn wrap_text(font: &Font, text: &str, font_size: f32, max_width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n') {
+        let mut current_line = String::new();
+        for word in paragraph.split_whitespace() {
+            let test_line = if current_line.is_empty() { word.to_string() } else { format!("{} {}", current_line, word) };
+            if !current_line.is_empty() && font.measure_text(&test_line, font_size, 1.0).x > max_width {
+                lines.push(std::mem::replace(&mut current_line, word.to_string()));
+            } else {
+                current_line = test_line;
+            }
+        }
+        if !current_line.is_empty() {
+            lines.push(current_line);
+        }
+    }
+    lines
+}
+
+// Draws wrapped text and returns the height it used
+pub fn label_wrapped(d: &mut RaylibDrawHandle, font: &Font, text: &str, pos: Vector2, max_width: f32, font_size: f32, color: Color) -> f32 {
+    let line_h = font_size * LINE_SPACING;
+    let lines = wrap_text(font, text, font_size, max_width);
+    for (i, line) in lines.iter().enumerate() {
+        d.draw_text_ex(font, line, Vector2::new(pos.x, pos.y + i as f32 * line_h), font_size, 1.0, color);
+    }
+    lines.len() as f32 * line_h
+}
@@ -1,17 +1,38 @@
 use raylib::prelude::*;
-use crate::consts::*;
+use crate::structures::settings::Settings;
 
-pub fn initialize_window() -> (RaylibHandle, RaylibThread) {
-    let (mut rl, thread) = raylib::init()
-        .size(SCREEN_WIDTH as i32, SCREEN_HEIGHT as i32)
+pub fn initialize_window(settings: &Settings) -> (RaylibHandle, RaylibThread) {
+    let mut builder = raylib::init();
+    builder
+        .size(settings.window_width, settings.window_height)
         .title("One Night Balatro")
-        .msaa_4x()
-        .vsync() // NEW: Enables VSync
-        .build();
+        .resizable()
+        .msaa_4x();
 
-    // With VSync enabled, this acts as a limiter cap,
-    // but the monitor refresh rate usually takes priority.
-    rl.set_target_fps(144);
+    // VSync can only be chosen when the window is created
+    if settings.vsync {
+        builder.vsync();
+    }
+
+    let (mut rl, thread) = builder.build();
+
+    // ESC is used by the game itself (settings / closing menus), not as a quit key
+    rl.set_exit_key(None);
+    apply_display_settings(&mut rl, settings);
 
     (rl, thread)
-}
\ No newline at end of file
+}
+
+// Pushes the window-related settings to raylib. Safe to call every time a value changes.
+pub fn apply_display_settings(rl: &mut RaylibHandle, settings: &Settings) {
+    if rl.is_window_fullscreen() != settings.fullscreen {
+        rl.toggle_fullscreen();
+    }
+    if !settings.fullscreen {
+        rl.set_window_size(settings.window_width, settings.window_height);
+    }
+
+    // With VSync enabled, this acts as a limiter cap,
+    // but the monitor refresh rate usually takes priority.
+    rl.set_target_fps(settings.fps_cap);
+}
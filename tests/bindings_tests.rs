@@ -0,0 +1,39 @@
+// Rebinding keys on the controls screen
+#[path = "../src/structures/bindings.rs"]
+mod bindings;
+
+use bindings::{rebind, Rebind};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Act { Confirm, Back }
+
+fn keys(pairs: &[(Act, &[&str])]) -> BTreeMap<Act, Vec<String>> {
+    pairs.iter().map(|(a, names)| (*a, names.iter().map(|n| n.to_string()).collect())).collect()
+}
+
+#[test]
+fn test_free_key_just_binds() {
+    let mut list = keys(&[(Act::Confirm, &["ENTER"]), (Act::Back, &["ESCAPE"])]);
+    assert_eq!(rebind(&mut list, Act::Confirm, "SPACE"), Rebind::Bound);
+    assert_eq!(list, keys(&[(Act::Confirm, &["SPACE"]), (Act::Back, &["ESCAPE"])]));
+}
+
+#[test]
+fn test_taken_key_swaps() {
+    let mut list = keys(&[(Act::Confirm, &["ENTER"]), (Act::Back, &["ESCAPE", "BACKSPACE"])]);
+    assert_eq!(rebind(&mut list, Act::Confirm, "ESCAPE"), Rebind::Swapped(Act::Back));
+    assert_eq!(list, keys(&[(Act::Confirm, &["ESCAPE"]), (Act::Back, &["ENTER", "BACKSPACE"])]));
+}
+
+#[test]
+fn test_nothing_to_give_back_is_refused() {
+    // Confirm has no binding, so taking Back's only key would leave Back unbound
+    let mut list = keys(&[(Act::Confirm, &[]), (Act::Back, &["ESCAPE"])]);
+    assert_eq!(rebind(&mut list, Act::Confirm, "ESCAPE"), Rebind::Refused(Act::Back));
+    assert_eq!(list, keys(&[(Act::Confirm, &[]), (Act::Back, &["ESCAPE"])]));
+
+    // Same when Back already has everything Confirm would hand over
+    let mut list = keys(&[(Act::Confirm, &["ENTER"]), (Act::Back, &["ESCAPE", "ENTER"])]);
+    assert_eq!(rebind(&mut list, Act::Confirm, "ESCAPE"), Rebind::Refused(Act::Back));
+}
@@ -0,0 +1,44 @@
+// How tests reach the game's code: the game is one binary crate linking raylib, so there
+// is no library to import. Each test file includes the modules it needs with #[path]
+// instead, which works for any module that doesn't touch raylib. Modules that reach
+// others through crate::structures also need a `structures` module at the test's root
+// re-exporting those.
+//
+// This file does that for the data side (the validator, and the structures modules it
+// and the loaders use), shared by the data / mods / daily tests:
+// `mod common;` then `use common::{data_validation, structures};`.
+#![allow(dead_code)]
+
+#[path = "../../src/data_validation.rs"]
+pub mod data_validation;
+
+#[path = "../../src/structures/card_data.rs"]
+pub mod card_data;
+
+#[path = "../../src/structures/daily.rs"]
+pub mod daily;
+
+#[path = "../../src/structures/effect.rs"]
+pub mod effect;
+
+#[path = "../../src/structures/endless.rs"]
+pub mod endless;
+
+#[path = "../../src/structures/enemy.rs"]
+pub mod enemy;
+
+#[path = "../../src/structures/event.rs"]
+pub mod event;
+
+#[path = "../../src/structures/hand.rs"]
+pub mod hand;
+
+#[path = "../../src/structures/mods.rs"]
+pub mod mods;
+
+#[path = "../../src/structures/score.rs"]
+pub mod score;
+
+pub mod structures {
+    pub use super::{card_data, effect, endless, enemy, event, hand, mods, score};
+}
@@ -0,0 +1,138 @@
+// Daily dates / seeds, result strings and the leaderboard
+mod common;
+use common::{daily, data_validation, mods, score, structures};
+
+use daily::{date_from_days, decode_log, encode_log, is_date, player_name, seed_for, DailyResult, Leaderboard, RunAction, LEADERBOARD_KEEP};
+use mods::ModStamp;
+use score::Score;
+
+fn result(player: &str, score: f64) -> DailyResult {
+    DailyResult {
+        date: "2026-10-19".to_string(),
+        player: player.to_string(),
+        score: Score::new(score),
+        ante: 3,
+        victory: false,
+        deck: "deck_standard".to_string(),
+        runes: vec!["r1".to_string(), "b2".to_string()],
+        relics: vec!["Twin Daggers".to_string()],
+        mods: vec![ModStamp { id: "more_relics".to_string(), version: "1.2.0".to_string() }],
+        log: vec![RunAction::Node(1), RunAction::Play(vec![4, 17, 30]), RunAction::Resume],
+    }
+}
+
+#[test]
+fn test_dates_from_days() {
+    assert_eq!(date_from_days(0), "1970-01-01");
+    assert_eq!(date_from_days(10_956), "1999-12-31");
+    assert_eq!(date_from_days(11_017), "2000-03-01"); // 2000 is a leap year
+    assert_eq!(date_from_days(19_782), "2024-02-29");
+    assert_eq!(date_from_days(20_745), "2026-10-19");
+    assert!(is_date("2026-10-19"));
+    assert!(!is_date("2026-10-9"));
+    assert!(!is_date("today"));
+}
+
+#[test]
+fn test_seed_is_fixed_per_date() {
+    // Pinned: a different value means everyone's dailies changed
+    assert_eq!(seed_for("2026-10-19"), 0x32DF_7AC0_D45B_DACC);
+    assert_ne!(seed_for("2026-10-19"), seed_for("2026-10-20"));
+    assert_ne!(seed_for("2026-10-19"), seed_for("2025-10-19"));
+}
+
+#[test]
+fn test_log_round_trip() {
+    let log = vec![
+        RunAction::Node(2),
+        RunAction::SortSuit,
+        RunAction::MoveCard(0, 6),
+        RunAction::Discard(vec![5]),
+        RunAction::Play(vec![1, 14, 27, 40, 52]),
+        RunAction::MoveRelic(2, 0),
+        RunAction::Upgrade(1),
+        RunAction::Choose(0),
+        RunAction::Resume,
+        RunAction::SortRank,
+    ];
+    let text = encode_log(&log);
+    assert_eq!(text, "n2.ss.m0-6.d5.p1,14,27,40,52.r2-0.u1.e0.c.sr");
+    assert_eq!(decode_log(&text).unwrap(), log);
+    assert_eq!(decode_log("").unwrap(), Vec::new());
+
+    let err = decode_log("n2.p.x9").unwrap_err();
+    assert!(err.contains("action 2"), "{}", err);
+    assert!(decode_log("m1").is_err());
+    assert!(decode_log("n-1").is_err());
+}
+
+#[test]
+fn test_result_string_round_trip() {
+    let mut original = result("Sam", 1234.5);
+    assert_eq!(DailyResult::parse(&original.to_string()).unwrap(), original);
+
+    // Exact scores survive even when the shown one is compact; empty lists come back empty
+    original.score = Score::new(1.5e20 + 3.0);
+    original.victory = true;
+    original.runes.clear();
+    original.relics.clear();
+    original.mods.clear();
+    original.log.clear();
+    let text = original.to_string();
+    assert!(text.starts_with("ONB-DAILY-1 | 2026-10-19 | Sam | "), "{}", text);
+    assert_eq!(DailyResult::parse(&text).unwrap(), original);
+    // Pasted from chat with stray whitespace around it
+    assert_eq!(DailyResult::parse(&format!("  {}\n", text)).unwrap(), original);
+
+    assert_eq!(player_name("a | b"), "a / b");
+}
+
+#[test]
+fn test_bad_result_strings() {
+    let good = result("Sam", 900.0).to_string();
+    let err = |text: &str| DailyResult::parse(text).unwrap_err();
+
+    assert!(err("hello").contains("not a daily result"));
+    assert!(err(&good.replacen(" | Ante 3", "", 1)).contains("11 fields"));
+    assert!(err(&good.replace("2026-10-19", "yesterday")).contains("not a date"));
+    assert!(err(&good.replace("(900.0)", "(lots)")).contains("bad score"));
+    assert!(err(&good.replace("Defeat", "Draw")).contains("bad outcome"));
+    assert!(err(&good.replace("more_relics@1.2.0", "more_relics")).contains("bad mod"));
+    assert!(err(&good.replace("n1.", "z1.")).contains("action 1"));
+}
+
+#[test]
+fn test_leaderboard_orders_and_dedupes() {
+    let mut board = Leaderboard::default();
+    assert_eq!(board.add(result("Ana", 500.0), false), Some(1));
+    assert_eq!(board.add(result("Bo", 900.0), true), Some(1));
+    assert_eq!(board.add(result("Cy", 700.0), true), Some(2));
+    // Same result again (e.g. pasted twice) isn't added
+    assert_eq!(board.add(result("Cy", 700.0), true), None);
+    // A tie goes below the earlier result
+    assert_eq!(board.add(result("Di", 700.0), false), Some(3));
+
+    let players: Vec<&str> = board.day("2026-10-19").iter().map(|e| e.result.player.as_str()).collect();
+    assert_eq!(players, ["Bo", "Cy", "Di", "Ana"]);
+    assert!(board.day("2026-10-18").is_empty());
+
+    let mut other_day = result("Ana", 100.0);
+    other_day.date = "2026-10-18".to_string();
+    assert_eq!(board.add(other_day, false), Some(1));
+    assert_eq!(board.day("2026-10-19").len(), 4);
+}
+
+#[test]
+fn test_leaderboard_keeps_the_best() {
+    let mut board = Leaderboard::default();
+    for i in 0..LEADERBOARD_KEEP {
+        board.add(result(&format!("p{}", i), 100.0 + i as f64), false);
+    }
+    // Below the cut: not added
+    assert_eq!(board.add(result("low", 1.0), false), None);
+    // Above it: the lowest drops off
+    assert_eq!(board.add(result("high", 1000.0), false), Some(1));
+    let day = board.day("2026-10-19");
+    assert_eq!(day.len(), LEADERBOARD_KEEP);
+    assert!(!day.iter().any(|e| e.result.player == "p0"));
+}
@@ -0,0 +1,204 @@
+// The validator against the shipped data and against broken copies of it
+mod common;
+use common::{data_validation, structures};
+
+use data_validation::{validate, Severity};
+use structures::card_data::{Enhancement, Seal};
+use structures::effect::Effect;
+use structures::enemy::BossAbility;
+use structures::hand::HandRank;
+use serde_json::json;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn copy_dir(from: &Path, to: &Path) {
+    fs::create_dir_all(to).unwrap();
+    for entry in fs::read_dir(from).unwrap() {
+        let path = entry.unwrap().path();
+        let target = to.join(path.file_name().unwrap());
+        if path.is_dir() {
+            copy_dir(&path, &target);
+        } else {
+            fs::copy(&path, target).unwrap();
+        }
+    }
+}
+
+// Copies the shipped data (scripts included) into a scratch dir so a test can break one file
+fn scratch_copy(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("data_tests_{}_{}", name, std::process::id()));
+    copy_dir(Path::new("assets/data"), &root.join("assets/data"));
+    root
+}
+
+#[test]
+fn test_shipped_data_has_no_errors() {
+    let report = validate(Path::new("."));
+    let errors: Vec<String> = report.issues.iter()
+        .filter(|i| i.severity == Severity::Error)
+        .map(|i| i.to_string())
+        .collect();
+    assert!(errors.is_empty(), "Data errors:\n{}", errors.join("\n"));
+}
+
+#[test]
+fn test_level_up_accepts_every_hand() {
+    for rank in HandRank::ALL {
+        let effect = Effect::LevelUp { target: Some(rank.name().to_uppercase()), chips: 1, mult: 1 };
+        assert!(effect.validate().is_ok(), "{}", rank.name());
+        assert_eq!(HandRank::from_name(rank.name()), Some(rank));
+    }
+    let effect = Effect::LevelUp { target: Some("Five of a Kind".to_string()), chips: 1, mult: 1 };
+    assert!(effect.validate().is_err());
+}
+
+// The validator has no name tables of its own for these; the data names must parse
+// as the game's types
+#[test]
+fn test_data_names_parse_as_game_types() {
+    let parses = |effect: serde_json::Value| serde_json::from_value::<Effect>(effect).is_ok();
+    // Every stat BaseModifiers::modify_stat handles, by its field name
+    for stat in ["max_hp", "hand_size", "hands_per_fight", "discards_per_fight", "interest_cap", "stat_points"] {
+        assert!(parses(json!({ "type": "ModifyPlayerStats", "stat": stat, "amount": 1 })), "{}", stat);
+    }
+    assert!(!parses(json!({ "type": "ModifyPlayerStats", "stat": "luck", "amount": 1 })));
+
+    for enhancement in [Enhancement::Bonus, Enhancement::Mult, Enhancement::Glass] {
+        assert!(parses(json!({ "type": "ModifyCard", "enhancement": enhancement })), "{:?}", enhancement);
+    }
+    assert!(parses(json!({ "type": "AddSeal", "seal": Seal::Red })));
+    assert!(!parses(json!({ "type": "AddSeal", "seal": "Gold" })));
+
+    for ability in ["SilenceSuit", "HandSizeMinusOne", "DoubleTarget", "PayToDiscard"] {
+        assert_ne!(BossAbility::from_data(ability, 1), BossAbility::None, "{}", ability);
+    }
+    assert_eq!(BossAbility::from_data("Teleport", 1), BossAbility::None);
+}
+
+#[test]
+fn test_unknown_boss_ability_is_an_error() {
+    let root = scratch_copy("boss");
+    let enemies = r#"{
+  "tier_1": ["Goblin"], "tier_2": [], "tier_3": [],
+  "bosses": [{ "name": "The Mime", "ability": "StealCards", "param": 0 }]
+}"#;
+    fs::write(root.join("assets/data/enemies.json"), enemies).unwrap();
+
+    let report = validate(&root);
+    let issue = report.issues.iter().find(|i| i.entry.as_deref() == Some("The Mime")).expect("ability not reported");
+    assert!(issue.message.contains("\"StealCards\""), "{}", issue.message);
+    assert_eq!(issue.line, 3);
+    fs::remove_dir_all(root).ok();
+}
+
+#[test]
+fn test_duplicate_id_reports_its_line() {
+    let root = scratch_copy("dup");
+    let runes = r#"[
+  { "id": "r1", "name": "Paladin", "description": "", "rune_type": "Red", "cost": 0 },
+  { "id": "r1", "name": "Reaper", "description": "", "rune_type": "Red", "cost": 0 }
+]"#;
+    fs::write(root.join("assets/data/runes.json"), runes).unwrap();
+
+    let report = validate(&root);
+    let dup = report.issues.iter().find(|i| i.message.contains("duplicate id")).expect("duplicate not reported");
+    assert_eq!(dup.file, "assets/data/runes.json");
+    assert_eq!(dup.line, 3);
+    fs::remove_dir_all(root).ok();
+}
+
+#[test]
+fn test_unknown_names_are_errors() {
+    let root = scratch_copy("names");
+    let books = r#"[
+  {
+    "id": "book_bad",
+    "name": "Bad Book",
+    "rarity": "Epic",
+    "cost": 3,
+    "effect": { "type": "LevelUp", "target": "Five of a Kind", "chips": 1, "mult": 1 },
+    "description": ""
+  },
+  {
+    "id": "book_worse",
+    "name": "Worse Book",
+    "rarity": "Common",
+    "cost": 3,
+    "effect": { "type": "Teleport" },
+    "description": ""
+  }
+]"#;
+    fs::write(root.join("assets/data/books.json"), books).unwrap();
+
+    let report = validate(&root);
+    let errors = |id: &str| report.issues.iter().filter(|i| i.entry.as_deref() == Some(id)).map(|i| i.message.clone()).collect::<Vec<_>>();
+    let bad = errors("book_bad");
+    assert!(bad.iter().any(|m| m.contains("rarity \"Epic\"")), "{:?}", bad);
+    assert!(bad.iter().any(|m| m.contains("hand \"Five of a Kind\"")), "{:?}", bad);
+    assert!(errors("book_worse").iter().any(|m| m.contains("unknown variant `Teleport`")));
+    assert!(report.entry_has_errors("books.json", "book_bad"));
+    fs::remove_dir_all(root).ok();
+}
+
+#[test]
+fn test_missing_relic_script_is_an_error() {
+    let root = scratch_copy("script");
+    fs::remove_file(root.join("assets/data/scripts/twin_daggers.rhai")).unwrap();
+
+    let report = validate(&root);
+    assert!(report.entry_has_errors("relics.json", "relic_daggers"));
+    fs::remove_dir_all(root).ok();
+}
+
+#[test]
+fn test_challenge_references_are_checked() {
+    let root = scratch_copy("challenges");
+    let challenges = r#"[
+  {
+    "id": "challenge_bad",
+    "name": "Bad",
+    "runes": ["r1", "z9"],
+    "banned_relics": ["relic_nope"],
+    "deck": { "suits": ["Spades", "Cups"], "no_face_cards": true },
+    "rules": ["NoShop", "NoFun"]
+  }
+]"#;
+    fs::write(root.join("assets/data/challenges.json"), challenges).unwrap();
+
+    let report = validate(&root);
+    let errors = |id: &str| report.issues.iter().filter(|i| i.entry.as_deref() == Some(id)).map(|i| i.message.clone()).collect::<Vec<_>>();
+    let bad = errors("challenge_bad");
+    assert!(bad.iter().any(|m| m.contains("rune id \"z9\"")), "{:?}", bad);
+    assert!(!bad.iter().any(|m| m.contains("\"r1\"")), "{:?}", bad);
+    assert!(bad.iter().any(|m| m.contains("relic id \"relic_nope\"")), "{:?}", bad);
+    assert!(bad.iter().any(|m| m.contains("suit \"Cups\"")), "{:?}", bad);
+    assert!(bad.iter().any(|m| m.contains("rule \"NoFun\"")), "{:?}", bad);
+    fs::remove_dir_all(root).ok();
+}
+
+#[test]
+fn test_deck_entries_are_checked() {
+    let root = scratch_copy("decks");
+    let decks = r#"[
+  { "id": "deck_ok", "name": "Fine", "extra_cards": ["Ace of Spades"], "enhance": [{ "enhancement": "Glass", "value": 14 }] },
+  {
+    "id": "deck_bad",
+    "name": "Bad",
+    "extra_cards": ["Ace of Spades", "Joker of Cups"],
+    "enhance": [{ "enhancement": "Shiny", "suit": "Hearts" }, { "enhancement": "Bonus", "value": 15 }]
+  },
+  { "id": "deck_ok", "name": "Again" }
+]"#;
+    fs::write(root.join("assets/data/decks.json"), decks).unwrap();
+
+    let report = validate(&root);
+    let errors = |id: &str| report.issues.iter().filter(|i| i.entry.as_deref() == Some(id)).map(|i| i.message.clone()).collect::<Vec<_>>();
+    let bad = errors("deck_bad");
+    assert!(bad.iter().any(|m| m.contains("\"Joker of Cups\"")), "{:?}", bad);
+    assert!(!bad.iter().any(|m| m.starts_with("extra card \"Ace of Spades\"")), "{:?}", bad);
+    assert!(bad.iter().any(|m| m.contains("\"Shiny\"")), "{:?}", bad);
+    assert!(bad.iter().any(|m| m.contains("2-14")), "{:?}", bad);
+    let ok = errors("deck_ok");
+    assert_eq!(ok.len(), 1, "only the duplicate id: {:?}", ok);
+    fs::remove_dir_all(root).ok();
+}
@@ -0,0 +1,65 @@
+// End-of-fight payout ledger
+#[path = "../src/structures/economy.rs"]
+mod economy;
+
+use economy::{payout, FightOutcome, PayoutInput};
+
+fn plain(money: i32) -> PayoutInput {
+    PayoutInput { base: 4, money, hands_left: 2, interest_cap: 25, ..Default::default() }
+}
+
+fn amount(ledger: &economy::Ledger, label_start: &str) -> Option<i32> {
+    ledger.lines.iter().find(|l| l.label.starts_with(label_start)).map(|l| l.amount)
+}
+
+#[test]
+fn test_base_hands_and_capped_interest() {
+    let ledger = payout(&plain(12), FightOutcome::Won);
+    assert_eq!(amount(&ledger, "Victory"), Some(4));
+    assert_eq!(amount(&ledger, "Hands left"), Some(2));
+    assert_eq!(amount(&ledger, "Interest"), Some(2));
+    assert_eq!(ledger.total(), 8);
+
+    // $100 held still only earns the cap's worth
+    let rich = payout(&plain(100), FightOutcome::Won);
+    assert_eq!(amount(&rich, "Interest"), Some(5));
+}
+
+#[test]
+fn test_zero_lines_are_left_out_and_order_is_fixed() {
+    let input = PayoutInput { hands_left: 0, ..plain(3) };
+    let labels: Vec<String> = payout(&input, FightOutcome::Won).lines.into_iter().map(|l| l.label).collect();
+    assert_eq!(labels, vec!["Victory"]);
+
+    let all = PayoutInput { cards_discarded: 3, investment: true, wealth: true, midas: true, recycler: true, ..plain(40) };
+    let labels: Vec<String> = payout(&all, FightOutcome::Won).lines.into_iter().map(|l| l.label).collect();
+    let order = ["Victory", "Hands left", "Recycler", "Interest", "Investment", "Wealth", "Midas"];
+    assert_eq!(labels.len(), order.len());
+    for (label, start) in labels.iter().zip(order) {
+        assert!(label.starts_with(start), "{} should start with {}", label, start);
+    }
+}
+
+#[test]
+fn test_rune_and_relic_bonuses() {
+    let input = PayoutInput { cards_discarded: 7, investment: true, wealth: true, midas: true, recycler: true, ..plain(40) };
+    let ledger = payout(&input, FightOutcome::Won);
+    assert_eq!(amount(&ledger, "Recycler"), Some(7));
+    assert_eq!(amount(&ledger, "Investment"), Some(2)); // 5% of 40
+    assert_eq!(amount(&ledger, "Wealth"), Some(3));
+    assert_eq!(amount(&ledger, "Midas"), Some(10)); // 25% of 40
+    assert_eq!(ledger.total(), 4 + 2 + 7 + 5 + 2 + 3 + 10);
+}
+
+#[test]
+fn test_losses_only_take_money_and_never_go_into_debt() {
+    let input = PayoutInput { midas: true, wealth: true, ..plain(10) };
+    let ledger = payout(&input, FightOutcome::Lost);
+    assert_eq!(ledger.lines.len(), 1, "only Midas applies on a loss");
+    assert_eq!(ledger.total(), -2);
+    assert_eq!(ledger.apply(10), 8);
+    assert!(payout(&plain(10), FightOutcome::Lost).lines.is_empty());
+
+    let broke = economy::Ledger { lines: vec![economy::LedgerLine { label: "Fine".to_string(), amount: -5 }] };
+    assert_eq!(broke.apply(3), 0);
+}
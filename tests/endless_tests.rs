@@ -0,0 +1,48 @@
+// Endless HP curve and boss ability stacking
+#[path = "../src/structures/endless.rs"]
+mod endless;
+
+use endless::{depth, EndlessCurve, MAX_BOSS_ABILITIES};
+
+#[test]
+fn test_normal_antes_are_untouched() {
+    let curve = EndlessCurve::default();
+    assert_eq!(depth(8, 8), 0);
+    assert_eq!(depth(3, 8), 0);
+    assert_eq!(curve.hp_mult(0), 1.0);
+    assert_eq!(curve.boss_abilities(0), 1);
+}
+
+#[test]
+fn test_hp_curve_outgrows_ante_scaling() {
+    let curve = EndlessCurve { hp_growth: 2.0, hp_exponent: 1.5, stacked_ability_after: 3 };
+    assert_eq!(curve.hp_mult(1), 2.0);
+    assert_eq!(curve.hp_mult(4), 256.0); // 2^(4^1.5) = 2^8
+    // Each ante multiplies by more than the last, unlike plain ante_scaling
+    let steps: Vec<f64> = (1..6).map(|d| curve.hp_mult(d + 1) / curve.hp_mult(d)).collect();
+    assert!(steps.windows(2).all(|w| w[1] > w[0]), "Got {:?}", steps);
+    // Deep runs overflow f64; Score clamps that, the curve itself just goes to inf
+    assert!(curve.hp_mult(400).is_infinite());
+}
+
+#[test]
+fn test_bad_curve_values_never_shrink_hp() {
+    let curve = EndlessCurve { hp_growth: 0.5, hp_exponent: 0.2, stacked_ability_after: 1 };
+    assert_eq!(curve.hp_mult(5), 1.0);
+}
+
+#[test]
+fn test_bosses_stack_abilities_late() {
+    let curve = EndlessCurve::default();
+    let first = curve.stacked_ability_after;
+    assert_eq!(curve.boss_abilities(first - 1), 1);
+    assert_eq!(curve.boss_abilities(first), MAX_BOSS_ABILITIES);
+    assert_eq!(curve.boss_abilities(first + 20), MAX_BOSS_ABILITIES);
+}
+
+#[test]
+fn test_curve_reads_partial_json() {
+    let curve: EndlessCurve = serde_json::from_str(r#"{ "hp_growth": 3.0 }"#).unwrap();
+    assert_eq!(curve.hp_growth, 3.0);
+    assert_eq!(curve.hp_exponent, EndlessCurve::default().hp_exponent);
+}
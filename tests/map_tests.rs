@@ -0,0 +1,74 @@
+// Run map generation
+#[path = "../src/structures/map.rs"]
+#[allow(dead_code)] // Layout/UI helpers aren't exercised here
+mod map;
+
+use map::{NodeKind, RunMap, MAP_ROWS};
+
+#[test]
+fn test_same_seed_same_map() {
+    let a = RunMap::generate(1234, 1);
+    let b = RunMap::generate(1234, 1);
+    assert_eq!(a.nodes.len(), b.nodes.len());
+    for (x, y) in a.nodes.iter().zip(&b.nodes) {
+        assert_eq!(x.kind, y.kind);
+        assert_eq!(x.next, y.next);
+    }
+}
+
+#[test]
+fn test_antes_differ() {
+    let kinds = |m: &RunMap| m.nodes.iter().map(|n| (n.kind, n.next.clone())).collect::<Vec<_>>();
+    let differs = (1..6).any(|ante| kinds(&RunMap::generate(99, ante)) != kinds(&RunMap::generate(99, ante + 1)));
+    assert!(differs, "Every ante produced the same map");
+}
+
+#[test]
+fn test_shape_and_reachability() {
+    for seed in 0..200 {
+        let map = RunMap::generate(seed, 1);
+        let boss: Vec<_> = map.nodes.iter().filter(|n| n.kind == NodeKind::Boss).collect();
+        assert_eq!(boss.len(), 1, "Seed {}: exactly one boss", seed);
+        assert_eq!(boss[0].row, MAP_ROWS);
+
+        for (i, node) in map.nodes.iter().enumerate() {
+            if node.row == 0 {
+                assert_eq!(node.kind, NodeKind::Combat, "Seed {}: first row is always a fight", seed);
+            } else {
+                let reachable = map.nodes.iter().any(|n| n.next.contains(&i));
+                assert!(reachable, "Seed {}: node {} has no way in", seed, i);
+            }
+            if node.row < MAP_ROWS {
+                assert!(!node.next.is_empty(), "Seed {}: node {} is a dead end", seed, i);
+                assert!(node.next.iter().all(|&n| map.nodes[n].row == node.row + 1));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_walking_the_map() {
+    let mut map = RunMap::generate(7, 1);
+    assert!(map.available().iter().all(|&i| map.nodes[i].row == 0));
+
+    // Always take the first branch; we must end on the boss
+    while let Some(&next) = map.available().first() {
+        map.enter(next);
+    }
+    assert_eq!(map.current_kind(), Some(NodeKind::Boss));
+    assert_eq!(map.nodes.iter().filter(|n| n.visited).count(), MAP_ROWS + 1);
+}
+
+#[test]
+fn test_without_shops_keeps_the_layout() {
+    // Find a seed that rolls at least one shop
+    let map = (0..50).map(|seed| RunMap::generate(seed, 1)).find(|m| m.nodes.iter().any(|n| n.kind == NodeKind::Shop)).unwrap();
+    let plain = map.clone().without_shops();
+    assert!(!plain.nodes.iter().any(|n| n.kind == NodeKind::Shop));
+    for (a, b) in map.nodes.iter().zip(&plain.nodes) {
+        assert_eq!(a.next, b.next);
+        if a.kind != NodeKind::Shop {
+            assert_eq!(a.kind, b.kind);
+        }
+    }
+}
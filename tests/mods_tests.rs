@@ -0,0 +1,120 @@
+// Mod discovery / merging and mod folder validation
+mod common;
+use common::{data_validation, mods, structures};
+
+use data_validation::{validate_with_mods, Severity};
+use mods::{ModInfo, ModStamp, Origins};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("mods_tests_{}_{}", name, std::process::id()));
+    fs::remove_dir_all(&root).ok();
+    fs::create_dir_all(&root).unwrap();
+    root
+}
+
+fn write_mod(root: &Path, folder: &str, manifest: &str, files: &[(&str, &str)]) {
+    let dir = root.join("mods").join(folder);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("mod.json"), manifest).unwrap();
+    for (name, text) in files {
+        fs::write(dir.join(name), text).unwrap();
+    }
+}
+
+fn info(id: &str) -> ModInfo {
+    let manifest = serde_json::from_str(&format!(r#"{{ "id": "{}", "name": "{}" }}"#, id, id)).unwrap();
+    ModInfo { folder: format!("mods/{}", id), manifest: Some(manifest), enabled: true, ..Default::default() }
+}
+
+#[test]
+fn test_merge_appends_overrides_and_reports_conflicts() {
+    let mut db: HashMap<String, i32> = HashMap::new();
+    let mut origins = Origins::new();
+    mods::merge(&mut db, &mut origins, "relic", "relic_a".to_string(), 1, None);
+    mods::merge(&mut db, &mut origins, "relic", "relic_b".to_string(), 2, None);
+
+    let mut first = info("first");
+    mods::merge(&mut db, &mut origins, "relic", "relic_a".to_string(), 10, Some(&mut first));
+    mods::merge(&mut db, &mut origins, "relic", "relic_c".to_string(), 30, Some(&mut first));
+    assert_eq!(first.overridden, vec!["relic_a"]);
+    assert_eq!(first.added, vec!["relic_c"]);
+    assert!(first.conflicts.is_empty(), "overriding the base game is not a conflict");
+
+    let mut second = info("second");
+    mods::merge(&mut db, &mut origins, "relic", "relic_c".to_string(), 300, Some(&mut second));
+    assert_eq!(second.conflicts.len(), 1);
+    assert!(second.conflicts[0].contains("first"));
+    assert_eq!(db["relic_c"], 300, "the later mod wins");
+
+    assert!(mods::disable(&mut db, &mut origins, "relic", "relic_b"));
+    assert!(!mods::disable(&mut db, &mut origins, "relic", "relic_missing"));
+    assert_eq!(db.len(), 2);
+}
+
+#[test]
+fn test_discover_sorts_by_load_order_and_respects_disabled() {
+    let root = scratch_dir("discover");
+    write_mod(&root, "a_late", r#"{ "id": "late", "name": "Late", "load_order": 5 }"#, &[]);
+    write_mod(&root, "b_early", r#"{ "id": "early", "name": "Early", "load_order": -1 }"#, &[]);
+    write_mod(&root, "c_off", r#"{ "id": "off", "name": "Off" }"#, &[]);
+    write_mod(&root, "d_broken", r#"{ "name": "No id" }"#, &[]);
+
+    let found = mods::discover(&root, &["off".to_string()]);
+    let ids: Vec<String> = found.iter().map(|m| m.id()).collect();
+    assert_eq!(ids, vec!["early", "off", "d_broken", "late"]);
+    assert!(!found[1].is_active());
+    assert!(!found[2].is_active(), "a broken manifest never loads");
+    assert!(!found[2].errors.is_empty());
+
+    let stamps: Vec<String> = mods::stamps(&found).into_iter().map(|s| s.id).collect();
+    assert_eq!(stamps, vec!["early", "late"]);
+    fs::remove_dir_all(root).ok();
+}
+
+#[test]
+fn test_mismatches_lists_missing_extra_and_version_changes() {
+    let stamp = |id: &str, version: &str| ModStamp { id: id.to_string(), version: version.to_string() };
+    let saved = vec![stamp("a", "1.0"), stamp("b", "1.0")];
+    assert!(mods::mismatches(&saved, &saved).is_empty());
+
+    let loaded = vec![stamp("a", "1.1"), stamp("c", "2.0")];
+    let lines = mods::mismatches(&saved, &loaded);
+    assert_eq!(lines.len(), 3);
+    assert!(lines.iter().any(|l| l.contains("missing mod b")));
+    assert!(lines.iter().any(|l| l.contains("extra mod c")));
+    assert!(lines.iter().any(|l| l.contains("a is 1.1")));
+}
+
+#[test]
+fn test_mod_folders_are_validated() {
+    let root = scratch_dir("validate");
+    let data = root.join("assets/data");
+    fs::create_dir_all(data.join("scripts")).unwrap();
+    for dir in ["", "scripts"] {
+        for entry in fs::read_dir(Path::new("assets/data").join(dir)).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_file() {
+                fs::copy(&path, data.join(dir).join(path.file_name().unwrap())).unwrap();
+            }
+        }
+    }
+
+    // Overriding a base id is fine, and effects may name base relics
+    let relics = r#"[{ "id": "relic_torch", "name": "Fading Torch", "description": "Brighter", "value": 5 }]"#;
+    let events = r#"[{ "id": "event_x", "title": "X", "text": "", "choices": [{ "label": "Take", "outcomes": [{ "type": "GainRelic", "relic": "relic_recycler" }] }] }]"#;
+    write_mod(&root, "good", r#"{ "id": "good", "name": "Good", "disable": ["relic_banned"] }"#, &[("relics.json", relics), ("events.json", events)]);
+    // Bad manifest and a bad rune
+    let runes = r#"[{ "id": "rune_x", "name": "X", "description": "", "rune_type": "Purple", "cost": 0 }]"#;
+    write_mod(&root, "bad", r#"{ "id": "bad", "name": "Bad", "priority": 1 }"#, &[("runes.json", runes)]);
+
+    let report = validate_with_mods(&root, &data_validation::mod_folders(&root));
+    let errors: Vec<_> = report.issues.iter().filter(|i| i.severity == Severity::Error).collect();
+    assert!(errors.iter().all(|i| i.file.starts_with("mods/bad/")), "unexpected errors: {:?}", errors);
+    assert!(errors.iter().any(|i| i.file == "mods/bad/mod.json" && i.message.contains("priority")));
+    assert!(report.entry_has_errors("mods/bad/runes.json", "rune_x"));
+    assert!(!report.entry_has_errors("runes.json", "r1"));
+    fs::remove_dir_all(root).ok();
+}
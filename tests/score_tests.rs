@@ -0,0 +1,78 @@
+// Big-number Score arithmetic and formatting
+#[path = "../src/structures/score.rs"]
+mod score;
+
+use score::{format_mult, Score};
+
+#[test]
+fn test_total_past_i32_max() {
+    // 100k chips * 50k mult = 5e9, which used to wrap an i32
+    let total = Score::from_chips_mult(100_000.0, 50_000.0);
+    assert!(total > Score::from(i32::MAX), "Total should exceed i32::MAX without wrapping");
+    assert_eq!(total.value(), 5.0e9);
+}
+
+#[test]
+fn test_round_score_accumulates_past_i32_max() {
+    let mut round = Score::from(i32::MAX);
+    round += Score::from(1);
+    assert_eq!(round.value(), i32::MAX as f64 + 1.0);
+}
+
+#[test]
+fn test_repeated_xmult_keeps_fractions() {
+    // 3 mult through five x1.5 relics: i32 truncation gave 4,6,9,13,19 = 19
+    let mut mult = 3.0_f64;
+    for _ in 0..5 {
+        mult *= 1.5;
+    }
+    let total = Score::from_chips_mult(10.0, mult);
+    assert!((total.value() - 227.8125).abs() < 1e-9, "Got {}", total.value());
+}
+
+#[test]
+fn test_saturates_at_f64_max() {
+    let huge = Score::from_chips_mult(f64::MAX, 10.0);
+    assert_eq!(huge.value(), f64::MAX, "Overflow should clamp, not become inf");
+    let sum = huge + huge;
+    assert_eq!(sum.value(), f64::MAX);
+    assert!(Score::new(f64::NAN) == Score::ZERO);
+}
+
+#[test]
+fn test_enemy_hp_scaling_and_remaining() {
+    let base = Score::from(300);
+    let ante_8 = base * 1.5_f64.powi(7);
+    assert!((ante_8.value() - 5125.78125).abs() < 1e-9);
+
+    let remaining = base.saturating_sub(Score::from(450));
+    assert_eq!(remaining, Score::ZERO, "Overkill must not go negative");
+    assert_eq!(Score::from(150).ratio_of(base), 0.5);
+    assert_eq!(Score::from(10).ratio_of(Score::ZERO), 0.0);
+}
+
+#[test]
+fn test_display_switches_to_scientific() {
+    assert_eq!(Score::from(12345).to_string(), "12345");
+    assert_eq!(Score::new(99_999_999_999.0).to_string(), "99999999999");
+    assert_eq!(Score::new(1.0e11).to_string(), "1.000e11");
+    assert_eq!(Score::new(1.2345e15).to_string(), "1.234e15");
+}
+
+#[test]
+fn test_format_mult() {
+    assert_eq!(format_mult(4.0), "4");
+    assert_eq!(format_mult(4.5), "4.5");
+    assert_eq!(format_mult(2.0e12), "2.000e12");
+}
+
+#[test]
+fn test_compact_display() {
+    assert_eq!(Score::from(45_234).compact(), "45234");
+    assert_eq!(Score::from(123_456).compact(), "123K");
+    assert_eq!(Score::new(1.2345e6).compact(), "1.23M");
+    assert_eq!(Score::new(999_999.0).compact(), "1.00M");
+    assert_eq!(Score::new(4.56e12).compact(), "4.56T");
+    assert_eq!(Score::new(2.5e45).compact(), "2.50e45");
+    assert_eq!(Score::new(f64::MAX).compact(), "MAX");
+}
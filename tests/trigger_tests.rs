@@ -0,0 +1,42 @@
+// Scoring trigger order
+#[path = "../src/structures/trigger.rs"]
+mod trigger;
+
+use trigger::{build_queue, Trigger, MAX_RETRIGGERS};
+
+#[test]
+fn test_cards_then_retriggers_then_held_then_relics() {
+    // Second played card retriggers once (e.g. Red Seal), one held card, two relics
+    let queue = build_queue(&[0, 1], &[0], 2);
+    assert_eq!(queue, vec![
+        Trigger::Played { index: 0, repeat: 0 },
+        Trigger::Played { index: 1, repeat: 0 },
+        Trigger::Played { index: 1, repeat: 1 },
+        Trigger::Held { index: 0, repeat: 0 },
+        Trigger::Relic(0),
+        Trigger::Relic(1),
+    ]);
+    let retriggers: Vec<bool> = queue.iter().map(|t| t.is_retrigger()).collect();
+    assert_eq!(retriggers, vec![false, false, true, false, false, false]);
+}
+
+#[test]
+fn test_retriggers_stack_and_are_capped() {
+    // Seal + relic on the same card, and something absurd on another
+    let queue = build_queue(&[2, 1000], &[], 0);
+    let first = queue.iter().filter(|t| matches!(t, Trigger::Played { index: 0, .. })).count();
+    let second = queue.iter().filter(|t| matches!(t, Trigger::Played { index: 1, .. })).count();
+    assert_eq!(first, 3);
+    assert_eq!(second, 1 + MAX_RETRIGGERS as usize);
+}
+
+#[test]
+fn test_held_retriggers_follow_their_card() {
+    let queue = build_queue(&[], &[1, 0], 1);
+    assert_eq!(queue, vec![
+        Trigger::Held { index: 0, repeat: 0 },
+        Trigger::Held { index: 0, repeat: 1 },
+        Trigger::Held { index: 1, repeat: 0 },
+        Trigger::Relic(0),
+    ]);
+}